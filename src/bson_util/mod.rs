@@ -46,6 +46,15 @@ pub(crate) fn get_u64(val: &Bson) -> Option<u64> {
     }
 }
 
+pub(crate) fn get_f64(val: &Bson) -> Option<f64> {
+    match *val {
+        Bson::Int32(i) => Some(f64::from(i)),
+        Bson::Int64(i) => Some(i as f64),
+        Bson::Double(f) => Some(f),
+        _ => None,
+    }
+}
+
 pub(crate) fn to_bson_array(docs: &[Document]) -> Bson {
     Bson::Array(docs.iter().map(|doc| Bson::Document(doc.clone())).collect())
 }
@@ -180,6 +189,50 @@ where
         .ok_or_else(|| D::Error::custom(format!("could not deserialize u64 from {:?}", bson)))
 }
 
+/// Deserialize an optional u64 from any BSON number type if it could be done losslessly.
+pub(crate) fn deserialize_u64_option_from_bson_number<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<u64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Bson>::deserialize(deserializer)?
+        .map(|bson| {
+            get_u64(&bson).ok_or_else(|| {
+                D::Error::custom(format!("could not deserialize u64 from {:?}", bson))
+            })
+        })
+        .transpose()
+}
+
+/// Deserialize an f64 from any BSON number type.
+pub(crate) fn deserialize_f64_from_bson_number<'de, D>(
+    deserializer: D,
+) -> std::result::Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bson = Bson::deserialize(deserializer)?;
+    get_f64(&bson)
+        .ok_or_else(|| D::Error::custom(format!("could not deserialize f64 from {:?}", bson)))
+}
+
+/// Deserialize an optional f64 from any BSON number type.
+pub(crate) fn deserialize_f64_option_from_bson_number<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Bson>::deserialize(deserializer)?
+        .map(|bson| {
+            get_f64(&bson).ok_or_else(|| {
+                D::Error::custom(format!("could not deserialize f64 from {:?}", bson))
+            })
+        })
+        .transpose()
+}
+
 /// The size in bytes of the provided document's entry in a BSON array at the given index.
 pub(crate) fn array_entry_size_bytes(index: usize, doc_len: usize) -> u64 {
     //   * type (1 byte)
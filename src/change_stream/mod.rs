@@ -304,6 +304,11 @@ where
                             .await;
                         new_stream.map(|cs| cs.with_type::<T>())
                     }));
+                    // A timeout is returned to the caller, and the resume is performed by the
+                    // next call so that it doesn't count against the timed-out one.
+                    if e.is_operation_timeout() {
+                        return out;
+                    }
                     // Iterate the loop so the new future gets polled and can register wakers.
                     continue;
                 }
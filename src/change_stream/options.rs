@@ -6,6 +6,7 @@ use typed_builder::TypedBuilder;
 
 use crate::{
     bson::{Bson, Timestamp},
+    bson_util,
     change_stream::event::ResumeToken,
     collation::Collation,
     concern::ReadConcern,
@@ -102,6 +103,22 @@ pub struct ChangeStreamOptions {
     /// the comment must be a [`Bson::String`] value.
     #[builder(default)]
     pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    ///
+    /// The timeout applies separately to each attempt to fetch more events. If it expires, the
+    /// timeout error is returned and the change stream remains usable: the next attempt resumes
+    /// it.
+    #[builder(default)]
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 impl ChangeStreamOptions {
@@ -113,6 +130,7 @@ impl ChangeStreamOptions {
             .read_concern(self.read_concern.clone())
             .selection_criteria(self.selection_criteria.clone())
             .comment_bson(self.comment.clone())
+            .timeout(self.timeout)
             .build()
     }
 }
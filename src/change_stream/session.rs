@@ -45,6 +45,7 @@ where
     cursor: SessionCursor<T>,
     args: WatchArgs,
     data: ChangeStreamData,
+    resume_pending: bool,
 }
 
 impl<T> SessionChangeStream<T>
@@ -52,7 +53,12 @@ where
     T: DeserializeOwned + Unpin + Send + Sync,
 {
     pub(crate) fn new(cursor: SessionCursor<T>, args: WatchArgs, data: ChangeStreamData) -> Self {
-        Self { cursor, args, data }
+        Self {
+            cursor,
+            args,
+            data,
+            resume_pending: false,
+        }
     }
 
     /// Returns the cached resume token that can be used to resume after the most recently returned
@@ -134,12 +140,15 @@ where
     /// ```
     pub async fn next_if_any(&mut self, session: &mut ClientSession) -> Result<Option<T>> {
         loop {
-            let (next, post_batch_token, client) = {
+            if self.resume_pending {
+                self.resume_pending = false;
+                self.resume(session).await?;
+            }
+            let (next, post_batch_token) = {
                 let mut stream = self.cursor.stream(session);
                 let next = NextInBatchFuture::new(&mut stream).await;
                 let post_batch_token = stream.post_batch_resume_token().cloned();
-                let client = stream.client().clone();
-                (next, post_batch_token, client)
+                (next, post_batch_token)
             };
             match next {
                 Ok(bv) => {
@@ -156,27 +165,38 @@ where
                 }
                 Err(e) if e.is_resumable() && !self.data.resume_attempted => {
                     self.data.resume_attempted = true;
-                    let args = self.args.clone();
-                    let new_stream: SessionChangeStream<ChangeStreamEvent<()>> = client
-                        .execute_watch_with_session(
-                            args.pipeline,
-                            args.options,
-                            args.target,
-                            Some(self.data.take()),
-                            session,
-                        )
-                        .await?;
-                    let new_stream = new_stream.with_type::<T>();
-                    self.cursor
-                        .set_drop_address(new_stream.cursor.address().clone());
-                    self.cursor = new_stream.cursor;
-                    self.args = new_stream.args;
-                    // After a successful resume, another resume must be allowed.
-                    self.data.resume_attempted = false;
-                    continue;
+                    // A timeout is returned to the caller, and the resume is performed by the
+                    // next call so that it doesn't count against the timed-out one.
+                    if e.is_operation_timeout() {
+                        self.resume_pending = true;
+                        return Err(e);
+                    }
+                    self.resume(session).await?;
                 }
                 Err(e) => return Err(e),
             }
         }
     }
+
+    async fn resume(&mut self, session: &mut ClientSession) -> Result<()> {
+        let client = self.cursor.client().clone();
+        let args = self.args.clone();
+        let new_stream: SessionChangeStream<ChangeStreamEvent<()>> = client
+            .execute_watch_with_session(
+                args.pipeline,
+                args.options,
+                args.target,
+                Some(self.data.take()),
+                session,
+            )
+            .await?;
+        let new_stream = new_stream.with_type::<T>();
+        self.cursor
+            .set_drop_address(new_stream.cursor.address().clone());
+        self.cursor = new_stream.cursor;
+        self.args = new_stream.args;
+        // After a successful resume, another resume must be allowed.
+        self.data.resume_attempted = false;
+        Ok(())
+    }
 }
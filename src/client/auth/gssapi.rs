@@ -0,0 +1,366 @@
+// The conversation logic is always compiled so that it can be unit tested against a mocked
+// security context, but it is only used for authentication when the `gssapi-auth` feature is
+// enabled.
+#![cfg_attr(not(feature = "gssapi-auth"), allow(dead_code))]
+
+#[cfg(feature = "gssapi-auth")]
+use cross_krb5::{ClientCtx, InitiateFlags, K5Ctx, PendingClientCtx, Step};
+
+use crate::{
+    bson::{Bson, Document},
+    client::{
+        auth::{
+            sasl::{SaslContinue, SaslResponse, SaslStart, SaslTransport},
+            AuthMechanism,
+            Credential,
+            GSSAPI_STR,
+        },
+        options::ServerApi,
+    },
+    error::{Error, ErrorKind, Result},
+};
+#[cfg(feature = "gssapi-auth")]
+use crate::{cmap::Connection, options::ServerAddress, runtime::AsyncResolver};
+
+const SERVICE_NAME: &str = "SERVICE_NAME";
+const CANONICALIZE_HOST_NAME: &str = "CANONICALIZE_HOST_NAME";
+const SERVICE_REALM: &str = "SERVICE_REALM";
+
+const DEFAULT_SERVICE_NAME: &str = "mongodb";
+
+/// How the hostname of the server should be canonicalized before being used to construct the
+/// service principal name.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum CanonicalizeHostName {
+    /// Use the hostname as-is.
+    None,
+
+    /// Use the canonical name returned by a forward DNS lookup of the hostname.
+    Forward,
+
+    /// Use the name returned by a reverse DNS lookup of the address returned by a forward DNS
+    /// lookup of the hostname.
+    ForwardAndReverse,
+}
+
+/// The GSSAPI-specific mechanism properties of a credential.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct GssapiProperties {
+    pub(super) service_name: String,
+    pub(super) canonicalize_host_name: CanonicalizeHostName,
+    pub(super) service_realm: Option<String>,
+}
+
+impl GssapiProperties {
+    /// Parses the mechanism properties of the given credential, returning an error if any of them
+    /// are unrecognized or have invalid values.
+    pub(super) fn from_credential(credential: &Credential) -> Result<Self> {
+        let mut properties = Self {
+            service_name: DEFAULT_SERVICE_NAME.to_string(),
+            canonicalize_host_name: CanonicalizeHostName::None,
+            service_realm: None,
+        };
+
+        let document = match credential.mechanism_properties {
+            Some(ref document) => document,
+            None => return Ok(properties),
+        };
+
+        for (key, value) in document {
+            match (key.as_str(), value) {
+                (SERVICE_NAME, Bson::String(name)) => properties.service_name = name.clone(),
+                (SERVICE_REALM, Bson::String(realm)) => {
+                    properties.service_realm = Some(realm.clone())
+                }
+                // For backwards compatibility, `true` is treated as "forwardAndReverse".
+                (CANONICALIZE_HOST_NAME, Bson::Boolean(canonicalize)) => {
+                    properties.canonicalize_host_name = if *canonicalize {
+                        CanonicalizeHostName::ForwardAndReverse
+                    } else {
+                        CanonicalizeHostName::None
+                    }
+                }
+                (CANONICALIZE_HOST_NAME, Bson::String(s)) => {
+                    properties.canonicalize_host_name = match s.as_str() {
+                        "none" => CanonicalizeHostName::None,
+                        "forward" => CanonicalizeHostName::Forward,
+                        "forwardAndReverse" => CanonicalizeHostName::ForwardAndReverse,
+                        _ => {
+                            return Err(ErrorKind::InvalidArgument {
+                                message: format!(
+                                    "invalid {} value for GSSAPI authentication: {}",
+                                    CANONICALIZE_HOST_NAME, s
+                                ),
+                            }
+                            .into())
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: format!(
+                            "invalid mechanism property for GSSAPI authentication: {}",
+                            key
+                        ),
+                    }
+                    .into())
+                }
+            }
+        }
+
+        Ok(properties)
+    }
+
+    /// Constructs the principal name of the service running on the given host.
+    pub(super) fn service_principal(&self, host: &str) -> String {
+        let mut principal = format!("{}/{}", self.service_name, host);
+        if let Some(ref realm) = self.service_realm {
+            principal.push('@');
+            principal.push_str(realm);
+        }
+        principal
+    }
+}
+
+/// A client-side GSSAPI security context. This is implemented using Kerberos when the
+/// `gssapi-auth` feature is enabled and mocked in tests.
+pub(super) trait GssapiContext: Send {
+    /// Processes a token received from the server, or begins establishing the context if `token`
+    /// is `None`, returning the token to send to the server next, if any.
+    fn step(&mut self, token: Option<&[u8]>) -> Result<Option<Vec<u8>>>;
+
+    /// Whether the security context has been fully established.
+    fn is_complete(&self) -> bool;
+
+    /// Wraps a message to be sent to the server using the established context.
+    fn wrap(&mut self, message: &[u8]) -> Result<Vec<u8>>;
+
+    /// Unwraps a message received from the server using the established context.
+    fn unwrap(&mut self, message: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Performs the GSSAPI SASL conversation described in
+/// [RFC 4752](https://tools.ietf.org/html/rfc4752) over the given transport, authenticating as
+/// `user_principal`.
+pub(super) async fn sasl_conversation(
+    transport: &mut impl SaslTransport,
+    mut context: impl GssapiContext,
+    user_principal: &str,
+    source: &str,
+    server_api: Option<&ServerApi>,
+) -> Result<()> {
+    let payload = context
+        .step(None)?
+        .ok_or_else(|| Error::unknown_authentication_error(GSSAPI_STR))?;
+    let command = SaslStart::new(
+        source.into(),
+        AuthMechanism::Gssapi,
+        payload,
+        server_api.cloned(),
+    )
+    .into_command();
+    let mut response = SaslResponse::parse(
+        GSSAPI_STR,
+        transport.send_sasl_command(command, GSSAPI_STR).await?,
+    )?;
+    let conversation_id = response.conversation_id.clone();
+
+    // Exchange tokens until the security context has been established.
+    while !context.is_complete() {
+        if response.done {
+            return Err(Error::invalid_authentication_response(GSSAPI_STR));
+        }
+        let payload = context.step(Some(&response.payload))?.unwrap_or_default();
+        response = sasl_continue(transport, source, &conversation_id, payload, server_api).await?;
+    }
+
+    // The server then sends a wrapped message containing the security layers it supports and
+    // its maximum message size.
+    let server_message = context.unwrap(&response.payload)?;
+    if server_message.len() != 4 {
+        return Err(Error::invalid_authentication_response(GSSAPI_STR));
+    }
+    if server_message[0] & 1 == 0 {
+        return Err(Error::authentication_error(
+            GSSAPI_STR,
+            "server does not support authentication without a security layer",
+        ));
+    }
+
+    // Request no security layer and a maximum message size of zero, and authorize as the user.
+    let mut client_message = vec![1, 0, 0, 0];
+    client_message.extend_from_slice(user_principal.as_bytes());
+    let payload = context.wrap(&client_message)?;
+    let response = sasl_continue(transport, source, &conversation_id, payload, server_api).await?;
+
+    if !response.done {
+        return Err(Error::invalid_authentication_response(GSSAPI_STR));
+    }
+
+    Ok(())
+}
+
+async fn sasl_continue(
+    transport: &mut impl SaslTransport,
+    source: &str,
+    conversation_id: &Bson,
+    payload: Vec<u8>,
+    server_api: Option<&ServerApi>,
+) -> Result<SaslResponse> {
+    let command = SaslContinue::new(
+        source.into(),
+        conversation_id.clone(),
+        payload,
+        server_api.cloned(),
+    )
+    .into_command();
+    let response: Document = transport.send_sasl_command(command, GSSAPI_STR).await?;
+    SaslResponse::parse(GSSAPI_STR, response)
+}
+
+/// Authenticates the given connection using Kerberos.
+#[cfg(feature = "gssapi-auth")]
+pub(super) async fn authenticate_stream(
+    conn: &mut Connection,
+    credential: &Credential,
+    server_api: Option<&ServerApi>,
+) -> Result<()> {
+    let properties = GssapiProperties::from_credential(credential)?;
+    let user_principal = credential
+        .username
+        .as_deref()
+        .ok_or_else(|| Error::authentication_error(GSSAPI_STR, "no username supplied"))?;
+    let source = credential.source.as_deref().unwrap_or("$external");
+
+    let host = canonicalize_host(conn.address(), properties.canonicalize_host_name).await?;
+    let context = Krb5Context::new(user_principal, &properties.service_principal(&host))?;
+
+    sasl_conversation(conn, context, user_principal, source, server_api).await
+}
+
+/// Determines the hostname to use in the service principal for the given address.
+#[cfg(feature = "gssapi-auth")]
+async fn canonicalize_host(address: &ServerAddress, mode: CanonicalizeHostName) -> Result<String> {
+    let host = address.host().into_owned();
+    if mode == CanonicalizeHostName::None {
+        return Ok(host);
+    }
+
+    let resolver = AsyncResolver::new(None).await?;
+    let lookup = resolver.lookup_ip(host.as_str()).await?;
+
+    if mode == CanonicalizeHostName::ForwardAndReverse {
+        if let Some(ip) = lookup.iter().next() {
+            // If the reverse lookup fails, fall back to the result of the forward lookup.
+            if let Ok(reverse) = resolver.reverse_lookup(ip).await {
+                if let Some(name) = reverse.iter().next() {
+                    return Ok(trim_dns_name(name.to_utf8()));
+                }
+            }
+        }
+    }
+
+    // The address records are listed under the canonical name of the host, after any CNAME
+    // records that were followed to find them.
+    let canonical_name = lookup
+        .as_lookup()
+        .record_iter()
+        .find(|record| record.rr_type().is_ip_addr())
+        .map(|record| trim_dns_name(record.name().to_utf8()));
+    Ok(canonical_name.unwrap_or(host))
+}
+
+#[cfg(feature = "gssapi-auth")]
+fn trim_dns_name(mut name: String) -> String {
+    if name.ends_with('.') {
+        name.pop();
+    }
+    name.to_lowercase()
+}
+
+#[cfg(feature = "gssapi-auth")]
+fn krb5_error(error: impl std::fmt::Display) -> Error {
+    Error::authentication_error(GSSAPI_STR, &error.to_string())
+}
+
+/// A `GssapiContext` backed by the system's Kerberos implementation.
+#[cfg(feature = "gssapi-auth")]
+struct Krb5Context {
+    state: Option<Krb5State>,
+    initial_token: Option<Vec<u8>>,
+}
+
+#[cfg(feature = "gssapi-auth")]
+enum Krb5State {
+    Pending(PendingClientCtx),
+    Established(ClientCtx),
+}
+
+#[cfg(feature = "gssapi-auth")]
+impl Krb5Context {
+    fn new(user_principal: &str, service_principal: &str) -> Result<Self> {
+        let (pending, token) = ClientCtx::new(
+            InitiateFlags::empty(),
+            Some(user_principal),
+            service_principal,
+            None,
+        )
+        .map_err(krb5_error)?;
+
+        Ok(Self {
+            state: Some(Krb5State::Pending(pending)),
+            initial_token: Some(token.to_vec()),
+        })
+    }
+
+    fn established(&mut self) -> Result<&mut ClientCtx> {
+        match self.state {
+            Some(Krb5State::Established(ref mut context)) => Ok(context),
+            _ => Err(Error::unknown_authentication_error(GSSAPI_STR)),
+        }
+    }
+}
+
+#[cfg(feature = "gssapi-auth")]
+impl GssapiContext for Krb5Context {
+    fn step(&mut self, token: Option<&[u8]>) -> Result<Option<Vec<u8>>> {
+        let token = match token {
+            Some(token) => token,
+            None => return Ok(self.initial_token.take()),
+        };
+
+        match self.state.take() {
+            Some(Krb5State::Pending(pending)) => match pending.step(token).map_err(krb5_error)? {
+                Step::Finished((context, token)) => {
+                    self.state = Some(Krb5State::Established(context));
+                    Ok(token.map(|token| token.to_vec()))
+                }
+                Step::Continue((pending, token)) => {
+                    self.state = Some(Krb5State::Pending(pending));
+                    Ok(Some(token.to_vec()))
+                }
+            },
+            state => {
+                self.state = state;
+                Err(Error::unknown_authentication_error(GSSAPI_STR))
+            }
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        matches!(self.state, Some(Krb5State::Established(_)))
+    }
+
+    fn wrap(&mut self, message: &[u8]) -> Result<Vec<u8>> {
+        let wrapped = self
+            .established()?
+            .wrap(false, message)
+            .map_err(krb5_error)?;
+        Ok(wrapped.to_vec())
+    }
+
+    fn unwrap(&mut self, message: &[u8]) -> Result<Vec<u8>> {
+        let unwrapped = self.established()?.unwrap(message).map_err(krb5_error)?;
+        Ok(unwrapped.to_vec())
+    }
+}
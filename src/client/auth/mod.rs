@@ -3,6 +3,8 @@
 
 #[cfg(feature = "aws-auth")]
 mod aws;
+mod gssapi;
+pub mod oidc;
 mod plain;
 mod sasl;
 mod scram;
@@ -12,6 +14,7 @@ mod x509;
 
 use std::{borrow::Cow, fmt::Debug, str::FromStr};
 
+use derivative::Derivative;
 use hmac::{digest::KeyInit, Mac};
 use rand::Rng;
 use serde::Deserialize;
@@ -33,6 +36,7 @@ const GSSAPI_STR: &str = "GSSAPI";
 const MONGODB_AWS_STR: &str = "MONGODB-AWS";
 const MONGODB_X509_STR: &str = "MONGODB-X509";
 const PLAIN_STR: &str = "PLAIN";
+const MONGODB_OIDC_STR: &str = "MONGODB-OIDC";
 
 /// The authentication mechanisms supported by MongoDB.
 ///
@@ -64,7 +68,11 @@ pub enum AuthMechanism {
     ///
     /// See the [MongoDB documentation](https://www.mongodb.com/docs/manual/core/kerberos/) for more information.
     ///
-    /// Note: This mechanism is not currently supported by this driver but will be in the future.
+    /// The `SERVICE_NAME` (defaults to "mongodb"), `CANONICALIZE_HOST_NAME` ("none", "forward",
+    /// or "forwardAndReverse"; defaults to "none") and `SERVICE_REALM` mechanism properties are
+    /// supported.
+    ///
+    /// Note: This mechanism is only supported when the `gssapi-auth` feature flag is enabled.
     Gssapi,
 
     /// The SASL PLAIN mechanism, as defined in [RFC 4616](), is used in MongoDB to perform LDAP
@@ -85,6 +93,14 @@ pub enum AuthMechanism {
     #[cfg(any(feature = "aws-auth", docsrs))]
     #[cfg_attr(docsrs, doc(cfg(feature = "aws-auth")))]
     MongoDbAws,
+
+    /// MONGODB-OIDC authenticates using an access token obtained from an OpenID Connect identity
+    /// provider. The token is retrieved by invoking the
+    /// [`oidc_callback`](Credential::oidc_callback) supplied on the credential and is cached for
+    /// the lifetime of the [`Client`](crate::Client).
+    ///
+    /// Note: Only server versions 7.0+ support OIDC authentication.
+    MongoDbOidc,
 }
 
 impl AuthMechanism {
@@ -165,6 +181,45 @@ impl AuthMechanism {
 
                 Ok(())
             }
+            AuthMechanism::Gssapi => {
+                if credential.username.is_none() {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: "No username provided for GSSAPI authentication".to_string(),
+                    }
+                    .into());
+                }
+
+                if credential.source.as_deref().unwrap_or("$external") != "$external" {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: "only $external may be specified as an auth source for GSSAPI"
+                            .to_string(),
+                    }
+                    .into());
+                }
+
+                gssapi::GssapiProperties::from_credential(credential)?;
+
+                Ok(())
+            }
+            AuthMechanism::MongoDbOidc => {
+                if credential.password.is_some() {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: "A password cannot be specified with MONGODB-OIDC".to_string(),
+                    }
+                    .into());
+                }
+
+                if credential.source.as_deref().unwrap_or("$external") != "$external" {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: "only $external may be specified as an auth source for \
+                                  MONGODB-OIDC"
+                            .to_string(),
+                    }
+                    .into());
+                }
+
+                Ok(())
+            }
             #[cfg(feature = "aws-auth")]
             AuthMechanism::MongoDbAws => {
                 if credential.username.is_some() && credential.password.is_none() {
@@ -193,22 +248,23 @@ impl AuthMechanism {
             AuthMechanism::Plain => PLAIN_STR,
             #[cfg(feature = "aws-auth")]
             AuthMechanism::MongoDbAws => MONGODB_AWS_STR,
+            AuthMechanism::MongoDbOidc => MONGODB_OIDC_STR,
         }
     }
 
     /// Get the default authSource for a given mechanism depending on the database provided in the
     /// connection string.
     pub(crate) fn default_source<'a>(&'a self, uri_db: Option<&'a str>) -> &'a str {
-        // TODO: fill in others as they're implemented
         match self {
             AuthMechanism::ScramSha1 | AuthMechanism::ScramSha256 | AuthMechanism::MongoDbCr => {
                 uri_db.unwrap_or("admin")
             }
-            AuthMechanism::MongoDbX509 => "$external",
+            AuthMechanism::MongoDbX509 | AuthMechanism::Gssapi | AuthMechanism::MongoDbOidc => {
+                "$external"
+            }
             AuthMechanism::Plain => uri_db.unwrap_or("$external"),
             #[cfg(feature = "aws-auth")]
             AuthMechanism::MongoDbAws => "$external",
-            _ => "",
         }
     }
 
@@ -233,7 +289,9 @@ impl AuthMechanism {
             Self::MongoDbX509 => Ok(Some(ClientFirst::X509(Box::new(
                 x509::build_speculative_client_first(credential),
             )))),
-            Self::Plain => Ok(None),
+            Self::MongoDbOidc => Ok(oidc::build_speculative_client_first(credential)?
+                .map(|command| ClientFirst::Oidc(Box::new(command)))),
+            Self::Plain | Self::Gssapi => Ok(None),
             #[cfg(feature = "aws-auth")]
             AuthMechanism::MongoDbAws => Ok(None),
             AuthMechanism::MongoDbCr => Err(ErrorKind::Authentication {
@@ -242,10 +300,6 @@ impl AuthMechanism {
                     .into(),
             }
             .into()),
-            _ => Err(ErrorKind::Authentication {
-                message: format!("Authentication mechanism {:?} not yet implemented.", self),
-            }
-            .into()),
         }
     }
 
@@ -275,18 +329,47 @@ impl AuthMechanism {
             AuthMechanism::Plain => {
                 plain::authenticate_stream(stream, credential, server_api).await
             }
+            #[cfg(feature = "gssapi-auth")]
+            AuthMechanism::Gssapi => {
+                gssapi::authenticate_stream(stream, credential, server_api).await
+            }
+            #[cfg(not(feature = "gssapi-auth"))]
+            AuthMechanism::Gssapi => Err(ErrorKind::Authentication {
+                message: "GSSAPI auth is only supported with the gssapi-auth feature flag".into(),
+            }
+            .into()),
             #[cfg(feature = "aws-auth")]
             AuthMechanism::MongoDbAws => {
                 aws::authenticate_stream(stream, credential, server_api, http_client).await
             }
+            AuthMechanism::MongoDbOidc => {
+                oidc::authenticate_stream(stream, credential, server_api, None).await
+            }
             AuthMechanism::MongoDbCr => Err(ErrorKind::Authentication {
                 message: "MONGODB-CR is deprecated and not supported by this driver. Use SCRAM \
                           for password-based authentication instead"
                     .into(),
             }
             .into()),
+        }
+    }
+
+    /// Reauthenticates an already-established connection after the server has indicated that its
+    /// authentication has expired.
+    pub(crate) async fn reauthenticate_stream(
+        &self,
+        stream: &mut Connection,
+        credential: &Credential,
+        server_api: Option<&ServerApi>,
+    ) -> Result<()> {
+        self.validate_credential(credential)?;
+
+        match self {
+            AuthMechanism::MongoDbOidc => {
+                oidc::reauthenticate_stream(stream, credential, server_api).await
+            }
             _ => Err(ErrorKind::Authentication {
-                message: format!("Authentication mechanism {:?} not yet implemented.", self),
+                message: format!("{} does not support reauthentication", self.as_str()),
             }
             .into()),
         }
@@ -304,6 +387,7 @@ impl FromStr for AuthMechanism {
             MONGODB_X509_STR => Ok(AuthMechanism::MongoDbX509),
             GSSAPI_STR => Ok(AuthMechanism::Gssapi),
             PLAIN_STR => Ok(AuthMechanism::Plain),
+            MONGODB_OIDC_STR => Ok(AuthMechanism::MongoDbOidc),
 
             #[cfg(feature = "aws-auth")]
             MONGODB_AWS_STR => Ok(AuthMechanism::MongoDbAws),
@@ -327,7 +411,8 @@ impl FromStr for AuthMechanism {
 ///
 /// Some fields (mechanism and source) may be omitted and will either be negotiated or assigned a
 /// default value, depending on the values of other fields in the credential.
-#[derive(Clone, Default, Deserialize, TypedBuilder, PartialEq)]
+#[derive(Clone, Default, Deserialize, TypedBuilder, Derivative)]
+#[derivative(PartialEq)]
 #[builder(field_defaults(default, setter(into)))]
 #[non_exhaustive]
 pub struct Credential {
@@ -336,8 +421,8 @@ pub struct Credential {
     pub username: Option<String>,
 
     /// The database used to authenticate. This applies to all mechanisms and defaults to "admin"
-    /// in SCRAM authentication mechanisms, "$external" for GSSAPI and MONGODB-X509, and the
-    /// database name or "$external" for PLAIN.
+    /// in SCRAM authentication mechanisms, "$external" for GSSAPI, MONGODB-X509 and MONGODB-OIDC,
+    /// and the database name or "$external" for PLAIN.
     pub source: Option<String>,
 
     /// The password to authenticate with. This does not apply to all mechanisms.
@@ -349,6 +434,18 @@ pub struct Credential {
 
     /// Additional properties for the given mechanism.
     pub mechanism_properties: Option<Document>,
+
+    /// The callback used to obtain an access token for MONGODB-OIDC authentication.
+    #[serde(skip)]
+    #[derivative(PartialEq = "ignore")]
+    pub oidc_callback: Option<oidc::OidcCallback>,
+
+    /// The access token cached for MONGODB-OIDC authentication. This is shared by all connections
+    /// of a single `Client`.
+    #[serde(skip)]
+    #[derivative(PartialEq = "ignore")]
+    #[builder(setter(skip))]
+    pub(crate) oidc_cache: oidc::Cache,
 }
 
 impl Credential {
@@ -375,6 +472,33 @@ impl Credential {
         doc
     }
 
+    /// Sets the auth source to the default for the credential's mechanism if none was specified.
+    /// `default_database` is the database specified in the connection string, if any.
+    pub(crate) fn set_default_source(&mut self, default_database: Option<&str>) {
+        if self.source.is_none() {
+            self.source = match &self.mechanism {
+                Some(mechanism) => Some(mechanism.default_source(default_database).into()),
+                None => {
+                    // If credentials exist (i.e. username is specified) but no mechanism, the
+                    // default source is chosen from the following list in
+                    // order (skipping null ones): authSource option, connection string db,
+                    // SCRAM default (i.e. "admin").
+                    Some(default_database.unwrap_or("admin").into())
+                }
+            };
+        }
+    }
+
+    /// Validates a credential returned by a `CredentialProvider` in the same way as a credential
+    /// specified in the connection string, and sets its default auth source.
+    pub(crate) fn resolve_provided(mut self, default_database: Option<&str>) -> Result<Self> {
+        if let Some(ref mechanism) = self.mechanism {
+            mechanism.validate_credential(&self)?;
+        }
+        self.set_default_source(default_database);
+        Ok(self)
+    }
+
     pub(crate) fn resolved_source(&self) -> &str {
         self.mechanism
             .as_ref()
@@ -422,6 +546,9 @@ impl Credential {
                 FirstRound::X509(server_first) => {
                     x509::authenticate_stream(conn, self, server_api, server_first).await
                 }
+                FirstRound::Oidc(server_first) => {
+                    oidc::authenticate_stream(conn, self, server_api, server_first).await
+                }
             };
         }
 
@@ -474,13 +601,14 @@ impl Debug for Credential {
 pub(crate) enum ClientFirst {
     Scram(ScramVersion, scram::ClientFirst),
     X509(Box<Command>),
+    Oidc(Box<Command>),
 }
 
 impl ClientFirst {
     pub(crate) fn to_document(&self) -> Document {
         match self {
             Self::Scram(version, client_first) => client_first.to_command(version).body,
-            Self::X509(command) => command.body.clone(),
+            Self::X509(command) | Self::Oidc(command) => command.body.clone(),
         }
     }
 
@@ -494,6 +622,7 @@ impl ClientFirst {
                 },
             ),
             Self::X509(..) => FirstRound::X509(server_first),
+            Self::Oidc(..) => FirstRound::Oidc(server_first),
         }
     }
 }
@@ -504,6 +633,7 @@ impl ClientFirst {
 pub(crate) enum FirstRound {
     Scram(ScramVersion, scram::FirstRound),
     X509(Document),
+    Oidc(Document),
 }
 
 pub(crate) fn generate_nonce_bytes() -> [u8; 32] {
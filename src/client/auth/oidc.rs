@@ -0,0 +1,240 @@
+//! Contains the types needed to configure MONGODB-OIDC authentication.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use futures_util::future::BoxFuture;
+use tokio::sync::Mutex;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    bson::{doc, Document},
+    client::{
+        auth::{
+            sasl::{SaslResponse, SaslStart, SaslTransport},
+            AuthMechanism,
+            Credential,
+        },
+        options::ServerApi,
+    },
+    cmap::Command,
+    error::{Error, Result},
+    runtime,
+};
+
+const MONGODB_OIDC_STR: &str = "MONGODB-OIDC";
+
+/// The amount of time a callback is given to return a token.
+const CALLBACK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The minimum amount of time between two consecutive invocations of a callback.
+const CALLBACK_THROTTLE: Duration = Duration::from_millis(100);
+
+/// The version of the callback API implemented by the driver.
+const CALLBACK_VERSION: u32 = 1;
+
+/// The error code returned by the server when authentication fails.
+const AUTHENTICATION_FAILED_CODE: i32 = 18;
+
+/// A user-supplied function that obtains an access token from an identity provider for
+/// MONGODB-OIDC authentication.
+///
+/// The driver guarantees that at most one invocation of the callback is in progress at a time for
+/// a given [`Client`](crate::Client), and caches the returned token until the server rejects it.
+pub type OidcCallback =
+    Arc<dyn Fn(OidcCallbackContext) -> BoxFuture<'static, Result<IdpResponse>> + Send + Sync>;
+
+/// The information passed to an [`OidcCallback`] when a new access token is needed.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct OidcCallbackContext {
+    /// The time by which the callback should return. If the callback has not returned by this
+    /// time, the driver will fail the authentication attempt.
+    pub timeout: Option<Instant>,
+
+    /// The version of the callback API used by the driver.
+    pub version: u32,
+
+    /// The refresh token returned by the previous invocation of the callback, if any.
+    pub refresh_token: Option<String>,
+}
+
+/// The token information returned by an [`OidcCallback`].
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct IdpResponse {
+    /// The access token to send to the server.
+    pub access_token: String,
+
+    /// The time at which the access token expires, if known.
+    #[builder(default)]
+    pub expires: Option<Instant>,
+
+    /// A token that can be used to obtain a new access token from the identity provider. This
+    /// will be passed to the next invocation of the callback.
+    #[builder(default)]
+    pub refresh_token: Option<String>,
+}
+
+/// The tokens cached for a single `Client`. Cloning a `Cache` yields a handle to the same
+/// underlying state.
+#[derive(Clone, Default)]
+pub(crate) struct Cache {
+    tokens: Arc<Mutex<Tokens>>,
+}
+
+#[derive(Default)]
+struct Tokens {
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    last_call_time: Option<Instant>,
+}
+
+impl Cache {
+    /// Returns the cached access token without blocking, if one is available.
+    pub(crate) fn cached_access_token(&self) -> Option<String> {
+        self.tokens
+            .try_lock()
+            .ok()
+            .and_then(|tokens| tokens.access_token.clone())
+    }
+
+    /// Returns the cached access token, invoking the callback to obtain a new one if none is
+    /// cached. The returned flag indicates whether the token came from the cache.
+    async fn access_token(&self, callback: &OidcCallback) -> Result<(String, bool)> {
+        let mut tokens = self.tokens.lock().await;
+        if let Some(ref access_token) = tokens.access_token {
+            return Ok((access_token.clone(), true));
+        }
+
+        if let Some(last_call_time) = tokens.last_call_time {
+            let elapsed = last_call_time.elapsed();
+            if elapsed < CALLBACK_THROTTLE {
+                runtime::delay_for(CALLBACK_THROTTLE - elapsed).await;
+            }
+        }
+
+        let context = OidcCallbackContext {
+            timeout: Some(Instant::now() + CALLBACK_TIMEOUT),
+            version: CALLBACK_VERSION,
+            refresh_token: tokens.refresh_token.clone(),
+        };
+        tokens.last_call_time = Some(Instant::now());
+        let response = runtime::timeout(CALLBACK_TIMEOUT, callback(context))
+            .await
+            .map_err(|_| {
+                Error::authentication_error(MONGODB_OIDC_STR, "the OIDC callback timed out")
+            })??;
+
+        tokens.access_token = Some(response.access_token.clone());
+        tokens.refresh_token = response.refresh_token;
+
+        Ok((response.access_token, false))
+    }
+
+    /// Removes the cached access token, if any, so that the next authentication invokes the
+    /// callback.
+    async fn clear_access_token(&self) {
+        self.tokens.lock().await.access_token = None;
+    }
+
+    /// Removes the given access token from the cache. If another connection has already replaced
+    /// it, the cache is left untouched.
+    pub(super) async fn invalidate(&self, access_token: &str) {
+        let mut tokens = self.tokens.lock().await;
+        if tokens.access_token.as_deref() == Some(access_token) {
+            tokens.access_token = None;
+        }
+    }
+}
+
+/// Builds the `saslStart` command used for speculative authentication, if a cached access token
+/// is available.
+pub(super) fn build_speculative_client_first(credential: &Credential) -> Result<Option<Command>> {
+    credential
+        .oidc_cache
+        .cached_access_token()
+        .map(|access_token| Ok(sasl_start(credential, None, &access_token)?.into_command()))
+        .transpose()
+}
+
+/// Authenticates the given connection. If `server_first` is provided, it is the server's response
+/// to a speculative `saslStart` sent as part of the handshake.
+pub(super) async fn authenticate_stream<T: SaslTransport>(
+    conn: &mut T,
+    credential: &Credential,
+    server_api: Option<&ServerApi>,
+    server_first: impl Into<Option<Document>>,
+) -> Result<()> {
+    if let Some(server_first) = server_first.into() {
+        let response = SaslResponse::parse(MONGODB_OIDC_STR, server_first)?;
+        if !response.done {
+            return Err(Error::invalid_authentication_response(MONGODB_OIDC_STR));
+        }
+        return Ok(());
+    }
+
+    let callback = credential.oidc_callback.as_ref().ok_or_else(|| {
+        Error::authentication_error(MONGODB_OIDC_STR, "no OIDC callback was supplied")
+    })?;
+
+    let (access_token, cached) = credential.oidc_cache.access_token(callback).await?;
+    match send_access_token(conn, credential, server_api, &access_token).await {
+        // A cached token may have expired; discard it and retry once with a fresh one.
+        Err(error) if cached && error.code() == Some(AUTHENTICATION_FAILED_CODE) => {
+            credential.oidc_cache.invalidate(&access_token).await;
+            let (access_token, _) = credential.oidc_cache.access_token(callback).await?;
+            send_access_token(conn, credential, server_api, &access_token).await
+        }
+        result => result,
+    }
+}
+
+/// Reauthenticates the given connection after the server reported that its authentication
+/// expired. The cached access token is discarded first so that the server is sent a new one from
+/// the callback rather than the token it just rejected.
+pub(super) async fn reauthenticate_stream<T: SaslTransport>(
+    conn: &mut T,
+    credential: &Credential,
+    server_api: Option<&ServerApi>,
+) -> Result<()> {
+    credential.oidc_cache.clear_access_token().await;
+    authenticate_stream(conn, credential, server_api, None).await
+}
+
+async fn send_access_token<T: SaslTransport>(
+    conn: &mut T,
+    credential: &Credential,
+    server_api: Option<&ServerApi>,
+    access_token: &str,
+) -> Result<()> {
+    let command = sasl_start(credential, server_api, access_token)?.into_command();
+    let response = conn.send_sasl_command(command, MONGODB_OIDC_STR).await?;
+    let sasl_response = SaslResponse::parse(MONGODB_OIDC_STR, response)?;
+
+    if !sasl_response.done {
+        return Err(Error::invalid_authentication_response(MONGODB_OIDC_STR));
+    }
+
+    Ok(())
+}
+
+fn sasl_start(
+    credential: &Credential,
+    server_api: Option<&ServerApi>,
+    access_token: &str,
+) -> Result<SaslStart> {
+    let payload = bson::to_vec(&doc! { "jwt": access_token })?;
+    Ok(SaslStart::new(
+        credential
+            .source
+            .clone()
+            .unwrap_or_else(|| "$external".to_string()),
+        AuthMechanism::MongoDbOidc,
+        payload,
+        server_api.cloned(),
+    ))
+}
@@ -1,7 +1,7 @@
 use crate::{
     client::{
         auth::{
-            sasl::{SaslResponse, SaslStart},
+            sasl::{SaslResponse, SaslStart, SaslTransport},
             AuthMechanism,
             Credential,
         },
@@ -35,8 +35,8 @@ pub(crate) async fn authenticate_stream(
     )
     .into_command();
 
-    let response = conn.send_command(sasl_start, None).await?;
-    let sasl_response = SaslResponse::parse("PLAIN", response.auth_response_body("PLAIN")?)?;
+    let response = conn.send_sasl_command(sasl_start, "PLAIN").await?;
+    let sasl_response = SaslResponse::parse("PLAIN", response)?;
 
     if !sasl_response.done {
         return Err(Error::invalid_authentication_response("PLAIN"));
@@ -1,12 +1,40 @@
+use futures_util::future::BoxFuture;
+
 use crate::{
     bson::{doc, spec::BinarySubtype, Binary, Bson, Document},
     bson_util,
     client::{auth::AuthMechanism, options::ServerApi},
-    cmap::Command,
+    cmap::{Command, Connection},
     error::{Error, Result},
     operation::{CommandErrorBody, CommandResponse},
 };
 
+/// A channel over which `saslStart` and `saslContinue` commands can be sent. This is implemented
+/// by `Connection` and allows multi-step SASL conversations to be driven against a mocked server in
+/// tests.
+pub(super) trait SaslTransport: Send {
+    /// Sends the given command, returning the body of the server's response.
+    fn send_sasl_command<'a>(
+        &'a mut self,
+        command: Command,
+        auth_mechanism: &'a str,
+    ) -> BoxFuture<'a, Result<Document>>;
+}
+
+impl SaslTransport for Connection {
+    fn send_sasl_command<'a>(
+        &'a mut self,
+        command: Command,
+        auth_mechanism: &'a str,
+    ) -> BoxFuture<'a, Result<Document>> {
+        Box::pin(async move {
+            self.send_command(command, None)
+                .await?
+                .auth_response_body(auth_mechanism)
+        })
+    }
+}
+
 /// Encapsulates the command building of a `saslStart` command.
 pub(super) struct SaslStart {
     source: String,
@@ -40,6 +68,8 @@ impl SaslStart {
             || self.mechanism == AuthMechanism::ScramSha256
         {
             body.insert("options", doc! { "skipEmptyExchange": true });
+        } else if self.mechanism == AuthMechanism::Gssapi {
+            body.insert("autoAuthorize", 1);
         }
 
         let mut command = Command::new("saslStart".into(), self.source, body);
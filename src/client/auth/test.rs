@@ -1,8 +1,26 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use futures_util::future::BoxFuture;
 use lazy_static::lazy_static;
 
-use crate::{cmap::StreamDescription, options::AuthMechanism};
+use crate::{
+    bson::{doc, spec::BinarySubtype, Binary, Document},
+    cmap::{Command, StreamDescription},
+    error::{ErrorKind, Result},
+    options::{AuthMechanism, Credential},
+};
 
-use super::sasl::SaslStart;
+use super::{
+    gssapi::{sasl_conversation, CanonicalizeHostName, GssapiContext, GssapiProperties},
+    oidc::{self, IdpResponse, OidcCallback, OidcCallbackContext},
+    sasl::{SaslStart, SaslTransport},
+};
 
 lazy_static! {
     static ref MECHS: [String; 2] = [
@@ -99,3 +117,414 @@ fn sasl_first_options_not_specified() {
         "SaslStart should not contain options document for X.509 authentication"
     );
 }
+
+/// A mocked server that records the SASL commands it receives and replies with canned responses.
+struct MockSaslServer {
+    responses: VecDeque<Document>,
+    commands: Vec<Command>,
+}
+
+impl MockSaslServer {
+    fn new(responses: impl IntoIterator<Item = Document>) -> Self {
+        Self {
+            responses: responses.into_iter().collect(),
+            commands: Vec::new(),
+        }
+    }
+}
+
+impl SaslTransport for MockSaslServer {
+    fn send_sasl_command<'a>(
+        &'a mut self,
+        command: Command,
+        _auth_mechanism: &'a str,
+    ) -> BoxFuture<'a, Result<Document>> {
+        self.commands.push(command);
+        let response = self
+            .responses
+            .pop_front()
+            .expect("received more commands than expected");
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+/// A mocked security context that completes after receiving a single token from the server and
+/// "wraps" messages by prefixing them.
+#[derive(Default)]
+struct MockGssapiContext {
+    complete: bool,
+}
+
+const WRAP_PREFIX: &[u8] = b"wrapped:";
+
+impl GssapiContext for MockGssapiContext {
+    fn step(&mut self, token: Option<&[u8]>) -> Result<Option<Vec<u8>>> {
+        match token {
+            None => Ok(Some(b"client-token-1".to_vec())),
+            Some(token) => {
+                assert_eq!(token, b"server-token-1");
+                self.complete = true;
+                Ok(Some(b"client-token-2".to_vec()))
+            }
+        }
+    }
+
+    fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    fn wrap(&mut self, message: &[u8]) -> Result<Vec<u8>> {
+        assert!(self.complete);
+        Ok([WRAP_PREFIX, message].concat())
+    }
+
+    fn unwrap(&mut self, message: &[u8]) -> Result<Vec<u8>> {
+        assert!(self.complete);
+        Ok(message
+            .strip_prefix(WRAP_PREFIX)
+            .expect("message should be wrapped")
+            .to_vec())
+    }
+}
+
+fn sasl_reply(done: bool, payload: &[u8]) -> Document {
+    doc! {
+        "conversationId": 1,
+        "done": done,
+        "payload": Binary { subtype: BinarySubtype::Generic, bytes: payload.to_vec() },
+        "ok": 1,
+    }
+}
+
+fn payload(command: &Command) -> &[u8] {
+    command.body.get_binary_generic("payload").unwrap()
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn gssapi_conversation() {
+    let mut server = MockSaslServer::new(vec![
+        sasl_reply(false, b"server-token-1"),
+        sasl_reply(false, &[WRAP_PREFIX, &[7, 0, 16, 0]].concat()),
+        sasl_reply(true, &[]),
+    ]);
+
+    sasl_conversation(
+        &mut server,
+        MockGssapiContext::default(),
+        "user@EXAMPLE.COM",
+        "$external",
+        None,
+    )
+    .await
+    .unwrap();
+
+    let commands = server.commands;
+    assert_eq!(commands.len(), 3);
+    assert!(commands.iter().all(|c| c.target_db == "$external"));
+
+    assert_eq!(commands[0].name, "saslStart");
+    assert_eq!(commands[0].body.get_str("mechanism"), Ok("GSSAPI"));
+    assert_eq!(commands[0].body.get_i32("autoAuthorize"), Ok(1));
+    assert_eq!(payload(&commands[0]), b"client-token-1");
+
+    assert_eq!(commands[1].name, "saslContinue");
+    assert_eq!(commands[1].body.get_i32("conversationId"), Ok(1));
+    assert_eq!(payload(&commands[1]), b"client-token-2");
+
+    // The final message requests no security layer and authorizes as the user.
+    assert_eq!(commands[2].name, "saslContinue");
+    assert_eq!(
+        payload(&commands[2]),
+        [WRAP_PREFIX, &[1, 0, 0, 0], b"user@EXAMPLE.COM"].concat()
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn gssapi_conversation_server_error() {
+    let mut server = MockSaslServer::new(vec![
+        sasl_reply(false, b"server-token-1"),
+        doc! { "ok": 0, "code": 18, "errmsg": "Authentication failed." },
+    ]);
+
+    let error = sasl_conversation(
+        &mut server,
+        MockGssapiContext::default(),
+        "user@EXAMPLE.COM",
+        "$external",
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(*error.kind, ErrorKind::Authentication { .. }));
+    assert_eq!(server.commands.len(), 2);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn gssapi_conversation_security_layer_required() {
+    // The server only offers the integrity protection security layer.
+    let mut server = MockSaslServer::new(vec![
+        sasl_reply(false, b"server-token-1"),
+        sasl_reply(false, &[WRAP_PREFIX, &[2, 0, 16, 0]].concat()),
+    ]);
+
+    let error = sasl_conversation(
+        &mut server,
+        MockGssapiContext::default(),
+        "user@EXAMPLE.COM",
+        "$external",
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(*error.kind, ErrorKind::Authentication { .. }));
+    assert_eq!(server.commands.len(), 2);
+}
+
+#[test]
+fn gssapi_properties() {
+    let credential = Credential::builder()
+        .username("user@EXAMPLE.COM".to_string())
+        .mechanism(AuthMechanism::Gssapi)
+        .build();
+    let properties = GssapiProperties::from_credential(&credential).unwrap();
+    assert_eq!(
+        properties.canonicalize_host_name,
+        CanonicalizeHostName::None
+    );
+    assert_eq!(
+        properties.service_principal("db.example.com"),
+        "mongodb/db.example.com"
+    );
+
+    let credential = Credential::builder()
+        .username("user@EXAMPLE.COM".to_string())
+        .mechanism(AuthMechanism::Gssapi)
+        .mechanism_properties(doc! {
+            "SERVICE_NAME": "other",
+            "SERVICE_REALM": "OTHER.COM",
+            "CANONICALIZE_HOST_NAME": "forward",
+        })
+        .build();
+    let properties = GssapiProperties::from_credential(&credential).unwrap();
+    assert_eq!(
+        properties.canonicalize_host_name,
+        CanonicalizeHostName::Forward
+    );
+    assert_eq!(
+        properties.service_principal("db.example.com"),
+        "other/db.example.com@OTHER.COM"
+    );
+
+    let credential = Credential::builder()
+        .mechanism_properties(doc! { "CANONICALIZE_HOST_NAME": true })
+        .build();
+    assert_eq!(
+        GssapiProperties::from_credential(&credential)
+            .unwrap()
+            .canonicalize_host_name,
+        CanonicalizeHostName::ForwardAndReverse
+    );
+
+    for invalid in [
+        doc! { "CANONICALIZE_HOST_NAME": "sometimes" },
+        doc! { "SERVICE_NAME": 1 },
+        doc! { "NOT_A_PROPERTY": "value" },
+    ] {
+        let credential = Credential::builder()
+            .username("user@EXAMPLE.COM".to_string())
+            .mechanism_properties(invalid)
+            .build();
+        assert!(AuthMechanism::Gssapi
+            .validate_credential(&credential)
+            .is_err());
+    }
+}
+
+/// Returns an OIDC callback that hands out numbered tokens and counts its invocations.
+fn counting_oidc_callback(calls: Arc<AtomicUsize>) -> OidcCallback {
+    Arc::new(
+        move |context: OidcCallbackContext| -> BoxFuture<'static, Result<IdpResponse>> {
+            let calls = calls.clone();
+            Box::pin(async move {
+                assert_eq!(context.version, 1);
+                assert!(context.timeout.is_some());
+                let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(IdpResponse::builder()
+                    .access_token(format!("token-{}", call))
+                    .build())
+            })
+        },
+    )
+}
+
+fn jwt(command: &Command) -> String {
+    Document::from_reader(payload(command))
+        .unwrap()
+        .get_str("jwt")
+        .unwrap()
+        .to_string()
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn oidc_token_cached() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let credential = Credential::builder()
+        .mechanism(AuthMechanism::MongoDbOidc)
+        .oidc_callback(counting_oidc_callback(calls.clone()))
+        .build();
+
+    // No token is cached yet, so speculative authentication is not attempted.
+    assert!(AuthMechanism::MongoDbOidc
+        .build_speculative_client_first(&credential)
+        .unwrap()
+        .is_none());
+
+    let mut server = MockSaslServer::new(vec![sasl_reply(true, &[]), sasl_reply(true, &[])]);
+    oidc::authenticate_stream(&mut server, &credential, None, None)
+        .await
+        .unwrap();
+    oidc::authenticate_stream(&mut server, &credential, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    let commands = server.commands;
+    assert_eq!(commands.len(), 2);
+    for command in &commands {
+        assert_eq!(command.name, "saslStart");
+        assert_eq!(command.target_db, "$external");
+        assert_eq!(command.body.get_str("mechanism"), Ok("MONGODB-OIDC"));
+        assert_eq!(jwt(command), "token-1");
+    }
+
+    let client_first = AuthMechanism::MongoDbOidc
+        .build_speculative_client_first(&credential)
+        .unwrap()
+        .expect("a cached token should be used for speculative authentication");
+    let speculative = client_first.to_document();
+    assert_eq!(speculative.get_str("mechanism"), Ok("MONGODB-OIDC"));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn oidc_cached_token_rejected() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let credential = Credential::builder()
+        .mechanism(AuthMechanism::MongoDbOidc)
+        .oidc_callback(counting_oidc_callback(calls.clone()))
+        .build();
+
+    let mut server = MockSaslServer::new(vec![
+        sasl_reply(true, &[]),
+        doc! { "ok": 0, "code": 18, "errmsg": "Authentication failed." },
+        sasl_reply(true, &[]),
+    ]);
+    oidc::authenticate_stream(&mut server, &credential, None, None)
+        .await
+        .unwrap();
+    // The server rejects the cached token, so it is discarded and a new one is fetched.
+    oidc::authenticate_stream(&mut server, &credential, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    let tokens: Vec<_> = server.commands.iter().map(jwt).collect();
+    assert_eq!(tokens, ["token-1", "token-1", "token-2"]);
+
+    // A freshly fetched token that is rejected is not retried.
+    let mut server = MockSaslServer::new(vec![
+        doc! { "ok": 0, "code": 18, "errmsg": "Authentication failed." },
+    ]);
+    credential.oidc_cache.invalidate("token-2").await;
+    let error = oidc::authenticate_stream(&mut server, &credential, None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::Authentication { .. }));
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn oidc_reauthentication_fetches_new_token() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let credential = Credential::builder()
+        .mechanism(AuthMechanism::MongoDbOidc)
+        .oidc_callback(counting_oidc_callback(calls.clone()))
+        .build();
+
+    let mut server = MockSaslServer::new(vec![sasl_reply(true, &[]), sasl_reply(true, &[])]);
+    oidc::authenticate_stream(&mut server, &credential, None, None)
+        .await
+        .unwrap();
+    // The server rejected the cached token, so reauthentication must not send it again.
+    oidc::reauthenticate_stream(&mut server, &credential, None)
+        .await
+        .unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    let tokens: Vec<_> = server.commands.iter().map(jwt).collect();
+    assert_eq!(tokens, ["token-1", "token-2"]);
+}
+
+#[test]
+fn oidc_validation() {
+    let mechanism = AuthMechanism::MongoDbOidc;
+    assert!(mechanism
+        .validate_credential(&Credential::builder().username("user".to_string()).build())
+        .is_ok());
+    assert!(mechanism
+        .validate_credential(&Credential::builder().password("pass".to_string()).build())
+        .is_err());
+    assert!(mechanism
+        .validate_credential(&Credential::builder().source("admin".to_string()).build())
+        .is_err());
+}
+
+#[test]
+fn provided_credential_resolution() {
+    let credential = Credential::builder()
+        .username("user".to_string())
+        .password("pencil".to_string())
+        .mechanism(AuthMechanism::ScramSha256)
+        .build()
+        .resolve_provided(Some("app"))
+        .unwrap();
+    assert_eq!(credential.source.as_deref(), Some("app"));
+
+    let credential = Credential::builder()
+        .username("user".to_string())
+        .build()
+        .resolve_provided(None)
+        .unwrap();
+    assert_eq!(credential.source.as_deref(), Some("admin"));
+
+    let credential = Credential::builder()
+        .mechanism(AuthMechanism::MongoDbX509)
+        .build()
+        .resolve_provided(Some("app"))
+        .unwrap();
+    assert_eq!(credential.source.as_deref(), Some("$external"));
+
+    let credential = Credential::builder()
+        .username("user".to_string())
+        .source("other".to_string())
+        .mechanism(AuthMechanism::ScramSha256)
+        .build()
+        .resolve_provided(Some("app"))
+        .unwrap();
+    assert_eq!(credential.source.as_deref(), Some("other"));
+
+    let error = Credential::builder()
+        .password("pencil".to_string())
+        .mechanism(AuthMechanism::ScramSha256)
+        .build()
+        .resolve_provided(None)
+        .unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+}
@@ -70,6 +70,7 @@ impl ClientState {
             aux_clients.key_vault_client,
             opts.key_vault_namespace.clone(),
             opts.kms_providers.tls_options().clone(),
+            client.inner.options.socks5_proxy(),
             mongocryptd_opts,
             mongocryptd_client,
             aux_clients.metadata_client,
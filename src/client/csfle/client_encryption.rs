@@ -1,15 +1,17 @@
 //! Support for explicit encryption.
 
 use crate::{
-    bson::Binary,
+    bson::{Binary, Bson, Document},
     client::options::TlsOptions,
     coll::options::CollectionOptions,
+    db::options::CreateCollectionOptions,
     error::{Error, Result},
-    options::{ReadConcern, WriteConcern},
-    results::DeleteResult,
+    options::{ReadConcern, UpdateModifications, UpdateOneModel, WriteConcern, WriteModel},
+    results::{BulkWriteResult, DeleteResult},
     Client,
     Collection,
     Cursor,
+    Database,
     Namespace,
 };
 use bson::{doc, spec::BinarySubtype, RawBinaryRef, RawDocumentBuf};
@@ -18,6 +20,7 @@ use mongocrypt::{
     Crypt,
 };
 use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
 
 use super::{options::KmsProviders, state_machine::CryptExecutor};
 
@@ -65,6 +68,7 @@ impl ClientEncryption {
             key_vault_client.weak(),
             key_vault_namespace.clone(),
             kms_providers.tls_options().clone(),
+            key_vault_client.inner.options.socks5_proxy(),
         )?;
         let key_vault = key_vault_client
             .database(&key_vault_namespace.db)
@@ -151,9 +155,148 @@ impl ClientEncryption {
         Ok(builder.build_datakey()?)
     }
 
-    // pub async fn rewrap_many_data_key(&self, _filter: Document, _opts: impl
-    // Into<Option<RewrapManyDataKeyOptions>>) -> Result<RewrapManyDataKeyResult> {
-    // todo!("RUST-1441") }
+    /// Decrypts the data keys in the key vault collection matching the given filter and
+    /// re-encrypts them with a new master key. If no options are provided, each key is
+    /// re-encrypted with its current master key.
+    ///
+    /// Returns the result of the bulk write used to update the key documents, or no bulk write
+    /// result if the filter matched no keys.
+    pub async fn rewrap_many_data_key(
+        &self,
+        filter: Document,
+        opts: impl Into<Option<RewrapManyDataKeyOptions>>,
+    ) -> Result<RewrapManyDataKeyResult> {
+        let ctx = self.rewrap_many_data_key_ctx(filter, opts.into().as_ref())?;
+        let result = self.exec.run_ctx(ctx, None).await?;
+
+        let keys = match result.get("v")?.and_then(|v| v.as_array()) {
+            Some(keys) => keys,
+            None => {
+                return Ok(RewrapManyDataKeyResult {
+                    bulk_write_result: None,
+                })
+            }
+        };
+        let mut models: Vec<WriteModel<RawDocumentBuf>> = Vec::new();
+        for key in keys {
+            let key = key?
+                .as_document()
+                .ok_or_else(|| Error::internal("invalid rewrapped data key"))?;
+            let id = key
+                .get_binary("_id")
+                .map_err(|e| Error::internal(format!("invalid data key id: {}", e)))?;
+            let master_key = key
+                .get_document("masterKey")
+                .map_err(|e| Error::internal(format!("invalid data key master key: {}", e)))?;
+            let key_material = key
+                .get_binary("keyMaterial")
+                .map_err(|e| Error::internal(format!("invalid data key material: {}", e)))?;
+            let update = doc! {
+                "$set": {
+                    "masterKey": Document::try_from(master_key)?,
+                    "keyMaterial": key_material.to_binary(),
+                },
+                "$currentDate": { "updateDate": true },
+            };
+            models.push(
+                UpdateOneModel::builder()
+                    .filter(doc! { "_id": id.to_binary() })
+                    .update(UpdateModifications::Document(update))
+                    .build()
+                    .into(),
+            );
+        }
+        if models.is_empty() {
+            return Ok(RewrapManyDataKeyResult {
+                bulk_write_result: None,
+            });
+        }
+
+        let bulk_write_result = self.key_vault.bulk_write(models, None).await?;
+        Ok(RewrapManyDataKeyResult {
+            bulk_write_result: Some(bulk_write_result),
+        })
+    }
+
+    fn rewrap_many_data_key_ctx(
+        &self,
+        filter: Document,
+        opts: Option<&RewrapManyDataKeyOptions>,
+    ) -> Result<Ctx> {
+        let mut builder = self.crypt.ctx_builder();
+        if let Some(opts) = opts {
+            let mut key_doc = doc! { "provider": opts.provider.name() };
+            if let Some(master_key) = &opts.master_key {
+                key_doc.extend(master_key.clone());
+            }
+            builder = builder.key_encryption_key(&key_doc)?;
+        }
+        let filter = RawDocumentBuf::from_document(&filter)?;
+        Ok(builder.build_rewrap_many_datakey(&filter)?)
+    }
+
+    /// Creates a new collection with encrypted fields, automatically creating new data encryption
+    /// keys when needed based on the configured `encrypted_fields` in `options`.
+    ///
+    /// For each entry in the `fields` array of `encrypted_fields` with a `keyId` of `null`, a new
+    /// data key is created using `master_key` and its id is substituted into the document. The
+    /// collection is then created along with its associated state collections and the index on
+    /// `__safeContent__`.
+    ///
+    /// Returns the potentially updated `encrypted_fields` along with the result of creating the
+    /// collection. The updated document is returned even on failure so that any data keys that
+    /// were created can be recovered.
+    pub async fn create_encrypted_collection(
+        &self,
+        db: &Database,
+        name: impl AsRef<str>,
+        mut options: CreateCollectionOptions,
+        master_key: MasterKey,
+    ) -> (Document, Result<()>) {
+        let mut encrypted_fields = match options.encrypted_fields.take() {
+            Some(encrypted_fields) => encrypted_fields,
+            None => {
+                return (
+                    Document::new(),
+                    Err(Error::invalid_argument(
+                        "no encrypted_fields defined for collection",
+                    )),
+                )
+            }
+        };
+        if let Err(e) = self
+            .create_missing_data_keys(&mut encrypted_fields, &master_key)
+            .await
+        {
+            return (encrypted_fields, Err(e));
+        }
+        options.encrypted_fields = Some(encrypted_fields.clone());
+        let result = db.create_collection(name, options).await;
+        (encrypted_fields, result)
+    }
+
+    /// Creates a data key for each field in `encrypted_fields` with a null `keyId`.
+    async fn create_missing_data_keys(
+        &self,
+        encrypted_fields: &mut Document,
+        master_key: &MasterKey,
+    ) -> Result<()> {
+        let fields = match encrypted_fields.get_array_mut("fields") {
+            Ok(fields) => fields,
+            Err(_) => return Ok(()),
+        };
+        for field in fields {
+            let field = match field.as_document_mut() {
+                Some(field) => field,
+                None => continue,
+            };
+            if field.get("keyId") == Some(&Bson::Null) {
+                let key_id = self.create_data_key(master_key.clone()).run().await?;
+                field.insert("keyId", key_id);
+            }
+        }
+        Ok(())
+    }
 
     /// Removes the key document with the given UUID (BSON binary subtype 0x04) from the key vault
     /// collection. Returns the result of the internal deleteOne() operation on the key vault
@@ -423,17 +566,30 @@ impl MasterKey {
     }
 }
 
-// #[non_exhaustive]
-// pub struct RewrapManyDataKeyOptions {
-// pub provider: KmsProvider,
-// pub master_key: Option<Document>,
-// }
-//
-//
-// #[non_exhaustive]
-// pub struct RewrapManyDataKeyResult {
-// pub bulk_write_result: Option<BulkWriteResult>,
-// }
+/// Options for rewrapping data keys with
+/// [`ClientEncryption::rewrap_many_data_key`](ClientEncryption::rewrap_many_data_key).
+#[derive(Debug, Clone, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct RewrapManyDataKeyOptions {
+    /// The KMS provider to use to encrypt the rewrapped keys.
+    pub provider: KmsProvider,
+
+    /// The master key to use to encrypt the rewrapped keys. The format of this document is
+    /// specific to `provider`; see [`MasterKey`] for the fields supported by each provider.
+    #[builder(default)]
+    pub master_key: Option<Document>,
+}
+
+/// The result of a call to
+/// [`ClientEncryption::rewrap_many_data_key`](ClientEncryption::rewrap_many_data_key).
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct RewrapManyDataKeyResult {
+    /// The result of the bulk write used to update the rewrapped keys in the key vault
+    /// collection, or `None` if no keys matched the filter.
+    pub bulk_write_result: Option<BulkWriteResult>,
+}
 
 /// The options for explicit encryption.
 #[derive(Debug, Clone)]
@@ -19,7 +19,7 @@ use crate::{
     error::{Error, Result},
     operation::{RawOutput, RunCommand},
     options::ReadConcern,
-    runtime::{AsyncStream, Process, TlsConfig},
+    runtime::{AsyncStream, Process, Socks5Proxy, TlsConfig},
     Client,
     Namespace,
 };
@@ -31,6 +31,7 @@ pub(crate) struct CryptExecutor {
     key_vault_client: WeakClient,
     key_vault_namespace: Namespace,
     tls_options: Option<KmsProvidersTlsOptions>,
+    proxy: Option<Socks5Proxy>,
     crypto_threads: ThreadPool,
     mongocryptd: Option<Mongocryptd>,
     mongocryptd_client: Option<Client>,
@@ -42,6 +43,7 @@ impl CryptExecutor {
         key_vault_client: WeakClient,
         key_vault_namespace: Namespace,
         tls_options: Option<KmsProvidersTlsOptions>,
+        proxy: Option<Socks5Proxy>,
     ) -> Result<Self> {
         // TODO RUST-1492: Replace num_cpus with std::thread::available_parallelism.
         let crypto_threads = rayon::ThreadPoolBuilder::new()
@@ -52,6 +54,7 @@ impl CryptExecutor {
             key_vault_client,
             key_vault_namespace,
             tls_options,
+            proxy,
             crypto_threads,
             mongocryptd: None,
             mongocryptd_client: None,
@@ -63,6 +66,7 @@ impl CryptExecutor {
         key_vault_client: WeakClient,
         key_vault_namespace: Namespace,
         tls_options: Option<KmsProvidersTlsOptions>,
+        proxy: Option<Socks5Proxy>,
         mongocryptd_opts: Option<MongocryptdOptions>,
         mongocryptd_client: Option<Client>,
         metadata_client: Option<WeakClient>,
@@ -71,7 +75,8 @@ impl CryptExecutor {
             Some(opts) => Some(Mongocryptd::new(opts).await?),
             None => None,
         };
-        let mut exec = Self::new_explicit(key_vault_client, key_vault_namespace, tls_options)?;
+        let mut exec =
+            Self::new_explicit(key_vault_client, key_vault_namespace, tls_options, proxy)?;
         exec.mongocryptd = mongocryptd;
         exec.mongocryptd_client = mongocryptd_client;
         exec.metadata_client = metadata_client;
@@ -190,9 +195,12 @@ impl CryptExecutor {
                                 .and_then(|tls| tls.get(&provider))
                                 .cloned()
                                 .unwrap_or_default();
-                            let mut stream =
-                                AsyncStream::connect(addr, Some(&TlsConfig::new(tls_options)?))
-                                    .await?;
+                            let mut stream = AsyncStream::connect(
+                                addr,
+                                Some(&TlsConfig::new(tls_options)?),
+                                self.proxy.as_ref(),
+                            )
+                            .await?;
                             stream.write_all(kms_ctx.message()?).await?;
                             let mut buf = vec![0];
                             while kms_ctx.bytes_needed() > 0 {
@@ -1,12 +1,14 @@
-#[cfg(feature = "in-use-encryption-unstable")]
-use bson::RawDocumentBuf;
-use bson::{doc, RawBsonRef, RawDocument, Timestamp};
+use bson::{doc, RawBsonRef, RawDocument, RawDocumentBuf, Timestamp};
 #[cfg(feature = "in-use-encryption-unstable")]
 use futures_core::future::BoxFuture;
 use lazy_static::lazy_static;
 use serde::de::DeserializeOwned;
 
-use std::{collections::HashSet, sync::Arc, time::Instant};
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use super::{session::TransactionState, Client, ClientSession};
 use crate::{
@@ -34,11 +36,16 @@ use crate::{
         TRANSIENT_TRANSACTION_ERROR,
         UNKNOWN_TRANSACTION_COMMIT_RESULT,
     },
-    event::command::{
-        CommandEvent,
-        CommandFailedEvent,
-        CommandStartedEvent,
-        CommandSucceededEvent,
+    event::{
+        command::{CommandEvent, CommandFailedEvent, CommandStartedEvent, CommandSucceededEvent},
+        metrics::{
+            operation_attributes,
+            server_attributes,
+            CONNECTION_CHECKOUT_FAILURES,
+            CONNECTION_WAIT_TIME,
+            OPERATION_DURATION,
+            OPERATION_RETRIES,
+        },
     },
     hello::LEGACY_HELLO_COMMAND_NAME_LOWERCASE,
     operation::{
@@ -47,10 +54,12 @@ use crate::{
         ChangeStreamAggregate,
         CommandErrorBody,
         CommitTransaction,
+        GetMore,
         Operation,
         Retryability,
     },
-    options::{ChangeStreamOptions, SelectionCriteria},
+    options::{ChangeStreamOptions, SelectionCriteria, ServerAddress},
+    runtime,
     sdam::{
         HandshakePhase,
         SelectedServer,
@@ -85,7 +94,36 @@ lazy_static! {
     };
 }
 
+/// The point in time by which a logical operation must complete, derived from its client-side
+/// timeout. A logical operation may span several server round trips (e.g. the batches of an
+/// `insert_many` or the `getMore`s of a cursor), each of which is limited to the time remaining.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Deadline {
+    timeout: Duration,
+    expires_at: Instant,
+}
+
+impl Deadline {
+    pub(crate) fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            expires_at: Instant::now() + timeout,
+        }
+    }
+
+    /// The time remaining before the deadline expires.
+    pub(crate) fn remaining(&self) -> Duration {
+        self.expires_at.saturating_duration_since(Instant::now())
+    }
+}
+
 impl Client {
+    /// The deadline for a logical operation with the given timeout, or with the client's default
+    /// timeout if none is given.
+    pub(crate) fn operation_deadline(&self, timeout: Option<Duration>) -> Option<Deadline> {
+        timeout.or(self.inner.options.timeout).map(Deadline::new)
+    }
+
     /// Execute the given operation.
     ///
     /// Server selection will performed using the criteria specified on the operation, if any, and
@@ -96,7 +134,20 @@ impl Client {
         op: T,
         session: impl Into<Option<&mut ClientSession>>,
     ) -> Result<T::O> {
-        self.execute_operation_with_details(op, session)
+        self.execute_operation_with_details(op, session, None)
+            .await
+            .map(|details| details.output)
+    }
+
+    /// Execute the given operation as one step of a logical operation that must complete by
+    /// `deadline`. If no deadline is given, the operation's own timeout is used.
+    pub(crate) async fn execute_operation_with_deadline<T: Operation>(
+        &self,
+        op: T,
+        session: impl Into<Option<&mut ClientSession>>,
+        deadline: Option<Deadline>,
+    ) -> Result<T::O> {
+        self.execute_operation_with_details(op, session, deadline)
             .await
             .map(|details| details.output)
     }
@@ -105,8 +156,25 @@ impl Client {
         &self,
         op: T,
         session: impl Into<Option<&mut ClientSession>>,
+        deadline: Option<Deadline>,
     ) -> Result<ExecutionDetails<T>> {
-        Box::pin(async {
+        let start = Instant::now();
+        // The operation's own timeout takes precedence over the client-wide default. Any timeout
+        // set on a database or collection has already been resolved into the operation's options.
+        let deadline = deadline.or_else(|| self.operation_deadline(op.timeout()));
+        // Operations that only release server-side resources are run while cleaning up after
+        // other operations or shutting down, so they don't wait for event streams to catch up.
+        let wait_for_event_capacity = !op.is_cleanup();
+        let metrics_sink = self.inner.options.metrics_sink.clone();
+        let operation_name = metrics_sink.as_ref().map(|_| op.name().to_string());
+        #[cfg(feature = "tracing-unstable")]
+        let span = crate::trace::operation::operation_span(op.name());
+
+        let execution = Box::pin(async {
+            if self.is_shut_down() {
+                return Err(ErrorKind::Shutdown.into());
+            }
+
             // TODO RUST-9: allow unacknowledged write concerns
             if !op.is_acknowledged() {
                 return Err(ErrorKind::InvalidArgument {
@@ -136,9 +204,51 @@ impl Client {
                     }
                 }
             }
-            self.execute_operation_with_retry(op, session).await
-        })
-        .await
+
+            // Give event streams using the backpressure overflow policy a chance to catch up
+            // before generating any more events. The wait counts against the operation's timeout;
+            // without one, it is bounded by the server selection timeout, after which the
+            // operation proceeds regardless.
+            let event_streams = &self.inner.options.event_streams;
+            match deadline {
+                Some(deadline) => runtime::timeout(deadline.remaining(), async {
+                    if wait_for_event_capacity {
+                        event_streams.wait_for_capacity().await;
+                    }
+                    self.execute_operation_with_retry(op, session, Some(deadline.expires_at))
+                        .await
+                })
+                .await
+                .map_err(|_| Error::operation_timeout(deadline.timeout))?,
+                None => {
+                    if wait_for_event_capacity {
+                        let timeout = self
+                            .inner
+                            .options
+                            .server_selection_timeout
+                            .unwrap_or(super::DEFAULT_SERVER_SELECTION_TIMEOUT);
+                        let _ = runtime::timeout(timeout, event_streams.wait_for_capacity()).await;
+                    }
+                    self.execute_operation_with_retry(op, session, None).await
+                }
+            }
+        });
+        #[cfg(feature = "tracing-unstable")]
+        let result = tracing::Instrument::instrument(execution, span).await;
+        #[cfg(not(feature = "tracing-unstable"))]
+        let result = execution.await;
+
+        if let (Some(sink), Some(operation_name)) = (metrics_sink, operation_name) {
+            let mut attributes = operation_attributes(&operation_name);
+            let outcome = if result.is_ok() { "success" } else { "failure" };
+            attributes.push(("outcome", outcome.to_string()));
+            sink.record_histogram(
+                OPERATION_DURATION,
+                start.elapsed().as_secs_f64(),
+                &attributes,
+            );
+        }
+        result
     }
 
     /// Execute the given operation, returning the cursor created by the operation.
@@ -149,7 +259,12 @@ impl Client {
         Op: Operation<O = CursorSpecification>,
     {
         Box::pin(async {
-            let mut details = self.execute_operation_with_details(op, None).await?;
+            let timeout = op.timeout();
+            let deadline = self.operation_deadline(timeout);
+            let mut details = self
+                .execute_operation_with_details(op, None, deadline)
+                .await?;
+            details.output.info.set_timeout(timeout, deadline);
             let pinned =
                 self.pin_connection_for_cursor(&details.output, &mut details.connection)?;
             Ok(Cursor::new(
@@ -170,9 +285,12 @@ impl Client {
     where
         Op: Operation<O = CursorSpecification>,
     {
+        let timeout = op.timeout();
+        let deadline = self.operation_deadline(timeout);
         let mut details = self
-            .execute_operation_with_details(op, &mut *session)
+            .execute_operation_with_details(op, &mut *session, deadline)
             .await?;
+        details.output.info.set_timeout(timeout, deadline);
 
         let pinned =
             self.pin_connection_for_session(&details.output, &mut details.connection, session)?;
@@ -230,14 +348,16 @@ impl Client {
                 .as_mut()
                 .and_then(|rd| rd.implicit_session.take());
             let op = ChangeStreamAggregate::new(&args, resume_data)?;
+            let timeout = op.timeout();
 
             let mut details = self
-                .execute_operation_with_details(op, implicit_session.as_mut())
+                .execute_operation_with_details(op, implicit_session.as_mut(), None)
                 .await?;
             if let Some(session) = implicit_session {
                 details.implicit_session = Some(session);
             }
-            let (cursor_spec, cs_data) = details.output;
+            let (mut cursor_spec, cs_data) = details.output;
+            cursor_spec.info.timeout = timeout;
             let pinned = self.pin_connection_for_cursor(&cursor_spec, &mut details.connection)?;
             let cursor = Cursor::new(self.clone(), cursor_spec, details.implicit_session, pinned);
 
@@ -265,11 +385,13 @@ impl Client {
                 options,
             };
             let op = ChangeStreamAggregate::new(&args, resume_data)?;
+            let timeout = op.timeout();
 
             let mut details = self
-                .execute_operation_with_details(op, &mut *session)
+                .execute_operation_with_details(op, &mut *session, None)
                 .await?;
-            let (cursor_spec, cs_data) = details.output;
+            let (mut cursor_spec, cs_data) = details.output;
+            cursor_spec.info.timeout = timeout;
             let pinned =
                 self.pin_connection_for_session(&cursor_spec, &mut details.connection, session)?;
             let cursor = SessionCursor::new(self.clone(), cursor_spec, pinned);
@@ -285,6 +407,7 @@ impl Client {
         &self,
         mut op: T,
         mut session: Option<&mut ClientSession>,
+        deadline: Option<Instant>,
     ) -> Result<ExecutionDetails<T>> {
         // If the current transaction has been committed/aborted and it is not being
         // re-committed/re-aborted, reset the transaction's state to TransactionState::None.
@@ -322,7 +445,17 @@ impl Client {
                 }
             };
 
-            let mut conn = match get_connection(&session, &op, &server.pool).await {
+            #[cfg(feature = "tracing-unstable")]
+            crate::trace::operation::record_server(&server.address);
+
+            let checkout_start = Instant::now();
+            let connection = get_connection(&session, &op, &server.pool).await;
+            self.record_connection_checkout(
+                &server.address,
+                checkout_start.elapsed(),
+                connection.is_ok(),
+            );
+            let mut conn = match connection {
                 Ok(c) => c,
                 Err(mut err) => {
                     retry.first_error()?;
@@ -342,6 +475,7 @@ impl Client {
                             prior_txn_number: None,
                             first_error: err,
                         });
+                        self.record_retry(op.name());
                         continue;
                     } else {
                         return Err(err);
@@ -364,16 +498,33 @@ impl Client {
                 .and_then(|r| r.prior_txn_number)
                 .or_else(|| get_txn_number(&mut session, retryability));
 
-            let details = match self
+            let mut result = self
                 .execute_operation_on_connection(
                     &mut op,
                     &mut conn,
                     &mut session,
                     txn_number,
                     retryability,
+                    deadline,
                 )
-                .await
-            {
+                .await;
+            // The server has indicated that the connection's authentication has expired;
+            // reauthenticate it and attempt the operation once more on the same connection.
+            if matches!(result, Err(ref err) if err.is_reauthentication_required()) {
+                self.reauthenticate_connection(&mut conn).await?;
+                result = self
+                    .execute_operation_on_connection(
+                        &mut op,
+                        &mut conn,
+                        &mut session,
+                        txn_number,
+                        retryability,
+                        deadline,
+                    )
+                    .await;
+            }
+
+            let details = match result {
                 Ok(output) => ExecutionDetails {
                     output,
                     connection: conn,
@@ -424,6 +575,7 @@ impl Client {
                             prior_txn_number: txn_number,
                             first_error: err,
                         });
+                        self.record_retry(op.name());
                         continue;
                     } else {
                         return Err(err);
@@ -434,6 +586,30 @@ impl Client {
         }
     }
 
+    /// Records the outcome of an attempt to check out a connection for an operation with the
+    /// metrics sink, if any.
+    fn record_connection_checkout(
+        &self,
+        address: &ServerAddress,
+        wait_time: Duration,
+        succeeded: bool,
+    ) {
+        if let Some(ref sink) = self.inner.options.metrics_sink {
+            let attributes = server_attributes(address);
+            sink.record_histogram(CONNECTION_WAIT_TIME, wait_time.as_secs_f64(), &attributes);
+            if !succeeded {
+                sink.increment_counter(CONNECTION_CHECKOUT_FAILURES, 1, &attributes);
+            }
+        }
+    }
+
+    /// Records that an operation is being retried with the metrics sink, if any.
+    fn record_retry(&self, operation_name: &str) {
+        if let Some(ref sink) = self.inner.options.metrics_sink {
+            sink.increment_counter(OPERATION_RETRIES, 1, &operation_attributes(operation_name));
+        }
+    }
+
     /// Executes an operation on a given connection, optionally using a provided session.
     async fn execute_operation_on_connection<T: Operation>(
         &self,
@@ -442,6 +618,7 @@ impl Client {
         session: &mut Option<&mut ClientSession>,
         txn_number: Option<i64>,
         retryability: Retryability,
+        deadline: Option<Instant>,
     ) -> Result<T::O> {
         if let Some(wc) = op.write_concern() {
             wc.validate()?;
@@ -563,11 +740,31 @@ impl Client {
         }
 
         let should_redact = cmd.should_redact();
+        let in_transaction = session
+            .as_ref()
+            .map_or(false, |session| session.in_transaction());
 
         let cmd_name = cmd.name.clone();
         let target_db = cmd.target_db.clone();
 
-        let serialized = op.serialize_command(cmd)?;
+        let mut serialized = op.serialize_command(cmd)?;
+        let mut max_time_ms_from_deadline = false;
+        if let Some(deadline) = deadline {
+            // Bound the server-side execution of the command by the remaining time budget unless
+            // the user has already specified a limit. getMore interprets maxTimeMS differently
+            // for tailable cursors, and commands within a transaction inherit the transaction's
+            // limit, so neither is modified.
+            if cmd_name != GetMore::NAME && !in_transaction {
+                let (appended, was_appended) = append_max_time_ms(serialized, deadline)?;
+                serialized = appended;
+                max_time_ms_from_deadline = was_appended;
+            }
+        }
+        #[cfg(feature = "tracing-unstable")]
+        crate::trace::operation::record_command_target(
+            &target_db,
+            RawDocument::from_bytes(&serialized)?,
+        );
         #[cfg(feature = "in-use-encryption-unstable")]
         let serialized = {
             let guard = self.inner.csfle.read().await;
@@ -714,6 +911,14 @@ impl Client {
                 }
 
                 err.add_labels_and_update_pin(Some(connection), session, Some(retryability))?;
+
+                // A command that exceeded the maxTimeMS derived from the deadline ran out of the
+                // operation's time budget on the server rather than the client.
+                if max_time_ms_from_deadline
+                    && matches!(*err.kind, ErrorKind::Command(ref e) if e.code == 50)
+                {
+                    err = Error::server_operation_timeout(err);
+                }
                 op.handle_error(err)
             }
             Ok(response) => {
@@ -790,6 +995,43 @@ impl Client {
         })
     }
 
+    /// Reauthenticates the given connection using the client's credential, or the current
+    /// credential from its credential provider if one is configured.
+    async fn reauthenticate_connection(&self, connection: &mut Connection) -> Result<()> {
+        let provided_credential = match self.inner.options.credential_provider {
+            Some(ref provider) => Some(
+                provider
+                    .credential()
+                    .await?
+                    .resolve_provided(self.inner.options.default_database.as_deref())?,
+            ),
+            None => None,
+        };
+        let credential = provided_credential
+            .as_ref()
+            .or(self.inner.options.credential.as_ref())
+            .ok_or_else(|| ErrorKind::Authentication {
+                message: "the server requested reauthentication but no credential was provided"
+                    .to_string(),
+            })?;
+        let mechanism = credential
+            .mechanism
+            .as_ref()
+            .ok_or_else(|| ErrorKind::Authentication {
+                message: "the server requested reauthentication but no auth mechanism was \
+                          specified"
+                    .to_string(),
+            })?;
+
+        mechanism
+            .reauthenticate_stream(
+                connection,
+                credential,
+                self.inner.options.server_api.as_ref(),
+            )
+            .await
+    }
+
     /// Start an implicit session if the operation and write concern are compatible with sessions.
     async fn start_implicit_session<T: Operation>(&self, op: &T) -> Result<Option<ClientSession>> {
         match self.get_session_support_status().await? {
@@ -920,6 +1162,21 @@ impl Client {
     }
 }
 
+/// Appends a `maxTimeMS` field derived from the time remaining before `deadline` to the given
+/// serialized command, unless the command already contains one. Returns the command and whether
+/// the field was appended.
+fn append_max_time_ms(serialized: Vec<u8>, deadline: Instant) -> Result<(Vec<u8>, bool)> {
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    let mut command = RawDocumentBuf::from_bytes(serialized)?;
+    let append = command.get("maxTimeMS")?.is_none();
+    if append {
+        // The server treats a maxTimeMS of 0 as "no limit", so always send at least 1.
+        let max_time_ms = std::cmp::max(remaining.as_millis(), 1) as i64;
+        command.append("maxTimeMS", max_time_ms);
+    }
+    Ok((command.into_bytes(), append))
+}
+
 async fn get_connection<T: Operation>(
     session: &Option<&mut ClientSession>,
     op: &T,
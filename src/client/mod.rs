@@ -6,7 +6,13 @@ pub mod options;
 pub mod session;
 
 use std::{
-    sync::Arc,
+    collections::HashMap,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+        Mutex,
+    },
     time::{Duration, Instant},
 };
 
@@ -14,8 +20,6 @@ use std::{
 pub use self::csfle::client_builder::*;
 use derivative::Derivative;
 
-#[cfg(test)]
-use crate::options::ServerAddress;
 #[cfg(feature = "tracing-unstable")]
 use crate::trace::{
     command::CommandTracingEventEmitter,
@@ -25,17 +29,24 @@ use crate::trace::{
     COMMAND_TRACING_EVENT_TARGET,
 };
 use crate::{
-    bson::Document,
+    bson::{doc, Document},
     change_stream::{
         event::ChangeStreamEvent,
         options::ChangeStreamOptions,
         session::SessionChangeStream,
         ChangeStream,
     },
+    cmap::ConnectionPoolStatus,
     concern::{ReadConcern, WriteConcern},
     db::Database,
     error::{Error, ErrorKind, Result},
-    event::command::{handle_command_event, CommandEvent},
+    event::{
+        cmap::CmapEvent,
+        command::{handle_command_event, CommandEvent},
+        sdam::{SdamEvent, TopologyDescription},
+        EventStream,
+        EventStreamOptions,
+    },
     operation::{AggregateTarget, ListDatabases},
     options::{
         ClientOptions,
@@ -43,14 +54,17 @@ use crate::{
         ListDatabasesOptions,
         ReadPreference,
         SelectionCriteria,
+        ServerAddress,
         SessionOptions,
     },
     results::DatabaseSpecification,
-    sdam::{server_selection, SelectedServer, SessionSupportStatus, Topology},
+    runtime::{self, WorkerHandle, WorkerHandleListener},
+    sdam::{server_selection, SelectedServer, ServerType, SessionSupportStatus, Topology},
     ClientSession,
+    TopologySubscriber,
 };
 
-pub(crate) use executor::{HELLO_COMMAND_NAMES, REDACTED_COMMANDS};
+pub(crate) use executor::{Deadline, HELLO_COMMAND_NAMES, REDACTED_COMMANDS};
 pub(crate) use session::{ClusterTime, SESSIONS_UNSUPPORTED_COMMANDS};
 
 use session::{ServerSession, ServerSessionPool};
@@ -116,10 +130,54 @@ struct ClientInner {
     topology: Topology,
     options: ClientOptions,
     session_pool: ServerSessionPool,
+    shutdown: Shutdown,
     #[cfg(feature = "in-use-encryption-unstable")]
     csfle: tokio::sync::RwLock<Option<csfle::ClientState>>,
 }
 
+/// State used to coordinate shutting down a `Client`.
+#[derive(Debug)]
+struct Shutdown {
+    /// Handle cloned into the [`AsyncDropToken`]s of resources created from the client. This is
+    /// taken when shutting down so that `drop_listener` can observe when all tokens are released.
+    drop_handle: Mutex<Option<WorkerHandle>>,
+
+    drop_listener: WorkerHandleListener,
+
+    /// Whether the client has been shut down.
+    executed: AtomicBool,
+}
+
+impl Shutdown {
+    fn new() -> Self {
+        let (handle, listener) = WorkerHandleListener::channel();
+        Self {
+            drop_handle: Mutex::new(Some(handle)),
+            drop_listener: listener,
+            executed: AtomicBool::new(false),
+        }
+    }
+}
+
+/// A token held by a resource that performs cleanup in a background task when it is dropped (e.g.
+/// a cursor that needs to be killed). [`Client::shutdown`] waits until all outstanding tokens have
+/// been released.
+#[derive(Debug, Default)]
+pub(crate) struct AsyncDropToken {
+    handle: Option<WorkerHandle>,
+}
+
+impl AsyncDropToken {
+    /// Spawns the given cleanup task, releasing this token once the task has completed.
+    pub(crate) fn spawn(&mut self, fut: impl Future<Output = ()> + Send + 'static) {
+        let handle = self.handle.take();
+        runtime::execute(async move {
+            fut.await;
+            drop(handle);
+        });
+    }
+}
+
 impl Client {
     /// Creates a new `Client` connected to the cluster specified by `uri`. `uri` must be a valid
     /// MongoDB connection string.
@@ -133,12 +191,22 @@ impl Client {
     }
 
     /// Creates a new `Client` connected to the cluster specified by `options`.
-    pub fn with_options(options: ClientOptions) -> Result<Self> {
+    pub fn with_options(mut options: ClientOptions) -> Result<Self> {
         options.validate()?;
 
+        // Tokens cached for MONGODB-OIDC must not be shared with other clients created from the
+        // same options.
+        if let Some(ref mut credential) = options.credential {
+            credential.oidc_cache = Default::default();
+        }
+
+        // Likewise, event streams are registered per client.
+        options.event_streams = Default::default();
+
         let inner = Arc::new(ClientInner {
             topology: Topology::new(options.clone())?,
             session_pool: ServerSessionPool::new(),
+            shutdown: Shutdown::new(),
             #[cfg(feature = "in-use-encryption-unstable")]
             csfle: Default::default(),
             options,
@@ -212,9 +280,16 @@ impl Client {
 
     #[cfg(not(feature = "tracing-unstable"))]
     pub(crate) fn emit_command_event(&self, generate_event: impl FnOnce() -> CommandEvent) {
-        if let Some(ref handler) = self.inner.options.command_event_handler {
-            let event = generate_event();
-            handle_command_event(handler.as_ref(), event);
+        let streams = &self.inner.options.event_streams.command;
+        let apm_event_handler = self.inner.options.command_event_handler.as_ref();
+        if !(streams.is_active() || apm_event_handler.is_some()) {
+            return;
+        }
+
+        let event = generate_event();
+        streams.send(&event);
+        if let Some(event_handler) = apm_event_handler {
+            handle_command_event(event_handler.as_ref(), event);
         }
     }
 
@@ -231,12 +306,14 @@ impl Client {
         } else {
             None
         };
+        let streams = &self.inner.options.event_streams.command;
         let apm_event_handler = self.inner.options.command_event_handler.as_ref();
-        if !(tracing_emitter.is_some() || apm_event_handler.is_some()) {
+        if !(tracing_emitter.is_some() || streams.is_active() || apm_event_handler.is_some()) {
             return;
         }
 
         let event = generate_event();
+        streams.send(&event);
         if let (Some(event_handler), Some(ref tracing_emitter)) =
             (apm_event_handler, &tracing_emitter)
         {
@@ -264,6 +341,11 @@ impl Client {
         self.inner.options.write_concern.as_ref()
     }
 
+    /// Gets the default client-side timeout the `Client` uses for operations.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.options.timeout
+    }
+
     /// Gets a handle to a database specified by `name` in the cluster the `Client` is connected to.
     /// The `Database` options (e.g. read preference and write concern) will default to those of the
     /// `Client`.
@@ -428,6 +510,246 @@ impl Client {
             .await
     }
 
+    /// Eagerly establishes [`min_pool_size`](ClientOptions::min_pool_size) connections to every
+    /// selectable server in the topology, returning once they are all ready for use.
+    ///
+    /// Normally, the connection pools are populated to `min_pool_size` gradually in the
+    /// background, so operations executed shortly after the `Client` is created may need to wait
+    /// for connections to be established and authenticated. Calling this method first moves that
+    /// cost up front. It waits for every server in the topology to have been checked at least once,
+    /// including those discovered from other servers' responses, and for the connections to be
+    /// established for at most the client's
+    /// [`server_selection_timeout`](ClientOptions::server_selection_timeout), returning an
+    /// [`ErrorKind::Timeout`] error if that is exceeded. If no `min_pool_size` is configured, this
+    /// only waits for a selectable server to be discovered.
+    pub async fn warm_connection_pool(&self) -> Result<()> {
+        let timeout = self
+            .inner
+            .options
+            .server_selection_timeout
+            .unwrap_or(DEFAULT_SERVER_SELECTION_TIMEOUT);
+        let warm_up = async {
+            let criteria = SelectionCriteria::ReadPreference(ReadPreference::Nearest {
+                options: Default::default(),
+            });
+            self.select_server(Some(&criteria), "warmConnectionPool")
+                .await?;
+
+            // Servers that haven't finished their first check yet are Unknown without an error,
+            // so wait for every known server to have been checked, successfully or not, before
+            // deciding which pools to warm up.
+            let mut watcher = self.inner.topology.watch();
+            let state = loop {
+                let state = watcher.observe_latest();
+                let discovered = state.description.servers.values().all(|description| {
+                    description.server_type != ServerType::Unknown || description.reply.is_err()
+                });
+                if discovered || !watcher.wait_for_update(None).await {
+                    break state;
+                }
+            };
+            let servers = state.servers();
+            let pools = state
+                .description
+                .servers
+                .values()
+                .filter(|description| description.server_type.is_data_bearing())
+                .filter_map(|description| servers.get(&description.address))
+                .map(|server| server.pool.warm_up());
+            futures_util::future::join_all(pools).await;
+            Ok(())
+        };
+
+        match runtime::timeout(timeout, warm_up).await {
+            Ok(result) => result,
+            Err(_) => Err(Error::operation_timeout(timeout)),
+        }
+    }
+
+    /// Gets the most up-to-date description of the topology this `Client` is connected to,
+    /// including the topology type, replica set name, and the latest information known about
+    /// each server (e.g. its type, round trip time, tags, or the error that caused it to be marked
+    /// Unknown).
+    pub fn topology_description(&self) -> TopologyDescription {
+        self.inner
+            .topology
+            .watch()
+            .peek_latest()
+            .description
+            .clone()
+            .into()
+    }
+
+    /// Returns a [`TopologySubscriber`] that can be used to wait for changes to the topology this
+    /// `Client` is connected to.
+    pub fn subscribe_to_topology(&self) -> TopologySubscriber {
+        TopologySubscriber::new(self.inner.topology.watch())
+    }
+
+    /// Returns a stream of the command events emitted by this `Client` from now on. This can be
+    /// used instead of or in addition to a
+    /// [`CommandEventHandler`](crate::event::command::CommandEventHandler) when events need to be
+    /// processed asynchronously. See [`EventStream`] for more details.
+    ///
+    /// Note that monitoring command events may incur a performance penalty.
+    pub fn command_events(
+        &self,
+        options: impl Into<Option<EventStreamOptions>>,
+    ) -> EventStream<CommandEvent> {
+        self.inner
+            .options
+            .event_streams
+            .command
+            .subscribe(options.into())
+    }
+
+    /// Returns a stream of the connection monitoring and pooling events emitted by this `Client`
+    /// from now on. See [`EventStream`] for more details.
+    pub fn cmap_events(
+        &self,
+        options: impl Into<Option<EventStreamOptions>>,
+    ) -> EventStream<CmapEvent> {
+        self.inner
+            .options
+            .event_streams
+            .cmap
+            .subscribe(options.into())
+    }
+
+    /// Returns a stream of the server discovery and monitoring events emitted by this `Client`
+    /// from now on. See [`EventStream`] for more details.
+    pub fn sdam_events(
+        &self,
+        options: impl Into<Option<EventStreamOptions>>,
+    ) -> EventStream<SdamEvent> {
+        self.inner
+            .options
+            .event_streams
+            .sdam
+            .subscribe(options.into())
+    }
+
+    /// Returns the current connection counts of the connection pool for each server in the
+    /// topology.
+    pub fn pool_status(&self) -> HashMap<ServerAddress, ConnectionPoolStatus> {
+        self.inner
+            .topology
+            .watch()
+            .peek_latest()
+            .servers()
+            .into_iter()
+            .map(|(address, server)| (address, server.pool.status()))
+            .collect()
+    }
+
+    /// Shuts down this `Client`, ending its pooled server sessions, stopping its background
+    /// monitoring tasks, and closing its connection pools (emitting a
+    /// [`PoolClosedEvent`](crate::event::cmap::PoolClosedEvent) for each).
+    ///
+    /// Before shutting down, this waits for any `Cursor`s, `SessionCursor`s, `ClientSession`s, and
+    /// `GridFsUploadStream`s created from this `Client` to be dropped and for the cleanup they
+    /// perform when dropped (e.g. killing cursors or aborting transactions) to complete. Any
+    /// such handles that are never dropped will cause this method to wait indefinitely, so it is
+    /// recommended to only use them in scopes shorter-lived than the `Client`. To shut down
+    /// without waiting for them, use [`Client::shutdown_immediate`] instead.
+    ///
+    /// Once this method has completed, any operation executed using this `Client`, its clones, or
+    /// any `Database` or `Collection` created from them will fail with
+    /// [`ErrorKind::Shutdown`].
+    ///
+    /// ```rust
+    /// # use mongodb::{bson::Document, Client, error::Result};
+    /// # async fn func() -> Result<()> {
+    /// let client = Client::with_uri_str("mongodb://example.com").await?;
+    /// {
+    ///     let coll = client.database("db").collection::<Document>("coll");
+    ///     let _cursor = coll.find(None, None).await?;
+    ///     // ... use the cursor ...
+    /// }
+    /// // The cursor was dropped above, so this will not wait indefinitely.
+    /// client.shutdown().await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(self) {
+        // Release the client's own handle so that the listener only waits on the tokens held by
+        // outstanding resources.
+        let handle = self.inner.shutdown.drop_handle.lock().unwrap().take();
+        drop(handle);
+        self.inner
+            .shutdown
+            .drop_listener
+            .wait_for_all_handle_drops()
+            .await;
+
+        self.shutdown_immediate().await;
+    }
+
+    /// Shuts down this `Client` in the same manner as [`Client::shutdown`], but without waiting
+    /// for outstanding cursors, sessions, and upload streams to be dropped. The cleanup normally
+    /// performed when those are dropped will fail once the shutdown completes, which may leave
+    /// server-side resources (e.g. open cursors) to be cleaned up by the server when they time
+    /// out.
+    pub async fn shutdown_immediate(self) {
+        // If another clone of this client has already been shut down, its sessions have already
+        // been ended.
+        if !self.inner.shutdown.executed.load(Ordering::SeqCst) {
+            self.end_all_sessions().await;
+        }
+        self.inner.topology.shutdown().await;
+
+        // This must happen last so that the commands sent above are not rejected.
+        self.inner.shutdown.executed.store(true, Ordering::SeqCst);
+    }
+
+    /// Ends all of the server sessions in the session pool using `endSessions`. This is done on a
+    /// best-effort basis: if no suitable server is immediately available, the sessions are left
+    /// for the server to time out.
+    async fn end_all_sessions(&self) {
+        // The maximum number of session ids that may be sent in a single endSessions command.
+        const MAX_END_SESSIONS_BATCH_SIZE: usize = 10_000;
+
+        let session_ids = self.inner.session_pool.get_session_ids().await;
+        if session_ids.is_empty() {
+            return;
+        }
+
+        let criteria = SelectionCriteria::ReadPreference(ReadPreference::PrimaryPreferred {
+            options: Default::default(),
+        });
+        let state = self.inner.topology.watch().observe_latest();
+        if !matches!(
+            server_selection::attempt_to_select_server(
+                &criteria,
+                &state.description,
+                &state.servers()
+            ),
+            Ok(Some(_))
+        ) {
+            return;
+        }
+
+        let admin = self.database("admin");
+        for chunk in session_ids.chunks(MAX_END_SESSIONS_BATCH_SIZE) {
+            let _ = admin
+                .run_command(doc! { "endSessions": chunk.to_vec() }, criteria.clone())
+                .await;
+        }
+    }
+
+    /// Whether this `Client` has been shut down.
+    pub(crate) fn is_shut_down(&self) -> bool {
+        self.inner.shutdown.executed.load(Ordering::SeqCst)
+    }
+
+    /// Returns a token to be held by a resource that performs cleanup in a background task when
+    /// dropped, allowing [`Client::shutdown`] to wait for that cleanup to complete.
+    pub(crate) fn register_async_drop(&self) -> AsyncDropToken {
+        AsyncDropToken {
+            handle: self.inner.shutdown.drop_handle.lock().unwrap().clone(),
+        }
+    }
+
     /// Check in a server session to the server session pool.
     /// If the session is expired or dirty, or the topology no longer supports sessions, the session
     /// will be discarded.
@@ -590,16 +912,6 @@ impl Client {
         self.inner.topology.sync_workers().await;
     }
 
-    #[cfg(test)]
-    pub(crate) fn topology_description(&self) -> crate::sdam::TopologyDescription {
-        self.inner
-            .topology
-            .watch()
-            .peek_latest()
-            .description
-            .clone()
-    }
-
     #[cfg(test)]
     pub(crate) fn topology(&self) -> &crate::sdam::Topology {
         &self.inner.topology
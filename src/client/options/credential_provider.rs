@@ -0,0 +1,94 @@
+use derivative::Derivative;
+use futures_util::future::BoxFuture;
+use typed_builder::TypedBuilder;
+
+use crate::{
+    client::{auth::Credential, options::TlsData},
+    error::Result,
+};
+
+/// A source of the credential used to authenticate new connections, for deployments whose
+/// credentials rotate over the lifetime of a [`Client`](crate::Client).
+///
+/// When a provider is set via
+/// [`ClientOptions::credential_provider`](crate::options::ClientOptions::credential_provider), it
+/// is consulted each time a new connection is authenticated and takes precedence over
+/// [`ClientOptions::credential`](crate::options::ClientOptions::credential). Connections that
+/// have already been authenticated are unaffected by changes to the returned credential, so
+/// rotating a secret does not interrupt in-progress operations. Providers should cache the current
+/// credential rather than fetching it from a remote store on every call, as new connections may be
+/// created frequently.
+///
+/// ```rust
+/// # use std::sync::{Arc, RwLock};
+/// # use futures_util::future::{BoxFuture, FutureExt};
+/// # use mongodb::{error::Result, options::{ClientOptions, Credential, CredentialProvider}};
+/// struct RotatingPassword {
+///     current: RwLock<String>,
+/// }
+///
+/// impl CredentialProvider for RotatingPassword {
+///     fn credential(&self) -> BoxFuture<'_, Result<Credential>> {
+///         let credential = Credential::builder()
+///             .username("app".to_string())
+///             .password(self.current.read().unwrap().clone())
+///             .build();
+///         async move { Ok(credential) }.boxed()
+///     }
+/// }
+///
+/// # async fn run() -> Result<()> {
+/// let mut options = ClientOptions::parse("mongodb://localhost:27017").await?;
+/// options.credential_provider = Some(Arc::new(RotatingPassword {
+///     current: RwLock::new("initial password".to_string()),
+/// }));
+/// # Ok(())
+/// # }
+/// ```
+pub trait CredentialProvider: Send + Sync {
+    /// Returns the credential to authenticate a new connection with. An error returned from this
+    /// method causes the connection attempt to fail, as does not returning within the client's
+    /// [`connect_timeout`](crate::options::ClientOptions::connect_timeout).
+    ///
+    /// The returned credential is validated the same way as
+    /// [`ClientOptions::credential`](crate::options::ClientOptions::credential), and its auth
+    /// source defaults to the one implied by its mechanism and the connection string's database.
+    fn credential(&self) -> BoxFuture<'_, Result<Credential>>;
+}
+
+/// A source of the certificate and private key that a [`Client`](crate::Client) presents to
+/// servers, for deployments whose client certificates rotate over the lifetime of a `Client`.
+///
+/// When a provider is set via
+/// [`TlsOptions::identity_provider`](crate::options::TlsOptions::identity_provider), it is
+/// consulted each time a new connection is established and takes precedence over
+/// [`TlsOptions::cert_key_file_path`](crate::options::TlsOptions::cert_key_file_path) and
+/// [`TlsOptions::client_certificate`](crate::options::TlsOptions::client_certificate). The TLS
+/// configuration is only rebuilt when the returned identity changes, and existing connections
+/// keep using the identity they were established with.
+pub trait TlsIdentityProvider: Send + Sync {
+    /// Returns the identity to present to the server on a new connection. An error returned from
+    /// this method causes the connection attempt to fail, as does not returning within the client's
+    /// [`connect_timeout`](crate::options::ClientOptions::connect_timeout).
+    fn identity(&self) -> BoxFuture<'_, Result<TlsIdentity>>;
+}
+
+/// The certificate and private key returned by a [`TlsIdentityProvider`].
+#[derive(Clone, Derivative, PartialEq, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[derivative(Debug)]
+#[non_exhaustive]
+pub struct TlsIdentity {
+    /// The certificate chain to present to the server. If this is PEM-encoded, it may also contain
+    /// the private key, in which case `key` can be omitted.
+    pub certificate: TlsData,
+
+    /// The private key corresponding to `certificate`.
+    #[builder(default)]
+    pub key: Option<TlsData>,
+
+    /// The password used to decrypt the private key, if it is encrypted.
+    #[builder(default)]
+    #[derivative(Debug = "ignore")]
+    pub key_password: Option<String>,
+}
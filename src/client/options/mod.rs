@@ -1,9 +1,12 @@
 #[cfg(all(test, not(feature = "sync"), not(feature = "tokio-sync")))]
 mod test;
 
+mod credential_provider;
 mod resolver_config;
+mod srv_resolver;
 
 use std::{
+    borrow::Cow,
     cmp::Ordering,
     collections::HashSet,
     convert::TryFrom,
@@ -31,17 +34,26 @@ use crate::{
     compression::Compressor,
     concern::{Acknowledgment, ReadConcern, WriteConcern},
     error::{Error, ErrorKind, Result},
-    event::{cmap::CmapEventHandler, command::CommandEventHandler, sdam::SdamEventHandler},
+    event::{
+        cmap::CmapEventHandler,
+        command::CommandEventHandler,
+        metrics::MetricsSink,
+        sdam::SdamEventHandler,
+        EventStreams,
+    },
     options::ReadConcernLevel,
+    runtime::{Socks5Proxy, DEFAULT_PROXY_PORT},
     sdam::{verify_max_staleness, DEFAULT_HEARTBEAT_FREQUENCY, MIN_HEARTBEAT_FREQUENCY},
     selection_criteria::{ReadPreference, SelectionCriteria, TagSet},
-    srv::{OriginalSrvInfo, SrvResolver},
+    srv::{OriginalSrvInfo, SeedlistResolver},
 };
 
 #[cfg(any(feature = "sync", feature = "tokio-sync"))]
 use crate::runtime;
 
+pub use credential_provider::{CredentialProvider, TlsIdentity, TlsIdentityProvider};
 pub use resolver_config::ResolverConfig;
+pub use srv_resolver::{SrvRecord, SrvResolver};
 
 pub(crate) const DEFAULT_PORT: u16 = 27017;
 
@@ -56,10 +68,15 @@ const URI_OPTIONS: &[&str] = &[
     "heartbeatfrequencyms",
     "journal",
     "localthresholdms",
+    "maxconnecting",
     "maxidletimems",
     "maxstalenessseconds",
     "maxpoolsize",
     "minpoolsize",
+    "proxyhost",
+    "proxypassword",
+    "proxyport",
+    "proxyusername",
     "readconcernlevel",
     "readpreference",
     "readpreferencetags",
@@ -68,18 +85,38 @@ const URI_OPTIONS: &[&str] = &[
     "retryreads",
     "serverselectiontimeoutms",
     "sockettimeoutms",
+    "timeoutms",
     "tls",
     "ssl",
     "tlsinsecure",
     "tlsallowinvalidcertificates",
     "tlscafile",
     "tlscertificatekeyfile",
+    "tlscertificatekeyfilepassword",
+    "tlscrlfile",
+    "tlsdisablecertificaterevocationcheck",
+    "tlsdisableocspendpointcheck",
     "w",
     "waitqueuetimeoutms",
     "wtimeoutms",
     "zlibcompressionlevel",
 ];
 
+/// Pairs of TLS options that cannot both be specified in a connection string.
+const CONFLICTING_TLS_OPTIONS: &[(&str, &str)] = &[
+    ("tlsInsecure", "tlsDisableOCSPEndpointCheck"),
+    ("tlsInsecure", "tlsDisableCertificateRevocationCheck"),
+    ("tlsAllowInvalidCertificates", "tlsDisableOCSPEndpointCheck"),
+    (
+        "tlsAllowInvalidCertificates",
+        "tlsDisableCertificateRevocationCheck",
+    ),
+    (
+        "tlsDisableOCSPEndpointCheck",
+        "tlsDisableCertificateRevocationCheck",
+    ),
+];
+
 lazy_static! {
     /// Reserved characters as defined by [Section 2.2 of RFC-3986](https://tools.ietf.org/html/rfc3986#section-2.2).
     /// Usernames / passwords that contain these characters must instead include the URL encoded version of them when included
@@ -94,9 +131,6 @@ lazy_static! {
 }
 
 /// An enum representing the address of a MongoDB server.
-///
-/// Currently this just supports addresses that can be connected to over TCP, but alternative
-/// address types may be supported in the future (e.g. Unix Domain Socket paths).
 #[derive(Clone, Debug, Eq, Serialize)]
 #[non_exhaustive]
 pub enum ServerAddress {
@@ -110,6 +144,17 @@ pub enum ServerAddress {
         /// The default is 27017.
         port: Option<u16>,
     },
+
+    /// A Unix Domain Socket path.
+    ///
+    /// In a connection string, the path must be percent-encoded and end in ".sock", e.g.
+    /// `mongodb://%2Ftmp%2Fmongodb-27017.sock`. TLS is never used for connections to a Unix Domain
+    /// Socket.
+    #[cfg(unix)]
+    Unix {
+        /// The path to the Unix Domain Socket.
+        path: PathBuf,
+    },
 }
 
 impl<'de> Deserialize<'de> for ServerAddress {
@@ -142,6 +187,10 @@ impl PartialEq for ServerAddress {
                     port: other_port,
                 },
             ) => host == other_host && port.unwrap_or(27017) == other_port.unwrap_or(27017),
+            #[cfg(unix)]
+            (Self::Unix { path }, Self::Unix { path: other_path }) => path == other_path,
+            #[cfg(unix)]
+            _ => false,
         }
     }
 }
@@ -156,6 +205,8 @@ impl Hash for ServerAddress {
                 host.hash(state);
                 port.unwrap_or(27017).hash(state);
             }
+            #[cfg(unix)]
+            Self::Unix { path } => path.hash(state),
         }
     }
 }
@@ -169,8 +220,19 @@ impl FromStr for ServerAddress {
 
 impl ServerAddress {
     /// Parses an address string into a `ServerAddress`.
+    ///
+    /// On Unix platforms, addresses containing a '/' and ending in ".sock" are parsed as Unix
+    /// Domain Socket paths; any other address is parsed as a hostname with an optional port.
     pub fn parse(address: impl AsRef<str>) -> Result<Self> {
         let address = address.as_ref();
+
+        #[cfg(unix)]
+        if address.contains('/') && address.ends_with(".sock") {
+            return Ok(ServerAddress::Unix {
+                path: PathBuf::from(address),
+            });
+        }
+
         let mut parts = address.split(':');
         let hostname = match parts.next() {
             Some(part) => {
@@ -241,18 +303,30 @@ impl ServerAddress {
                     "port": port.map(|i| Bson::Int32(i.into())).unwrap_or(Bson::Null)
                 }
             }
+            #[cfg(unix)]
+            Self::Unix { path } => {
+                doc! {
+                    "host": path.to_string_lossy(),
+                    "port": Bson::Null,
+                }
+            }
         }
     }
 
-    pub(crate) fn host(&self) -> &str {
+    /// The hostname for a TCP address or the socket path for a Unix Domain Socket address.
+    pub(crate) fn host(&self) -> Cow<'_, str> {
         match self {
-            Self::Tcp { host, .. } => host.as_str(),
+            Self::Tcp { host, .. } => Cow::Borrowed(host.as_str()),
+            #[cfg(unix)]
+            Self::Unix { path } => path.to_string_lossy(),
         }
     }
 
     pub(crate) fn port(&self) -> Option<u16> {
         match self {
             Self::Tcp { port, .. } => *port,
+            #[cfg(unix)]
+            Self::Unix { .. } => None,
         }
     }
 }
@@ -263,6 +337,8 @@ impl fmt::Display for ServerAddress {
             Self::Tcp { host, port } => {
                 write!(fmt, "{}:{}", host, port.unwrap_or(DEFAULT_PORT))
             }
+            #[cfg(unix)]
+            Self::Unix { path } => write!(fmt, "{}", path.display()),
         }
     }
 }
@@ -371,6 +447,9 @@ pub struct ClientOptions {
 
     /// The handler that should process all Connection Monitoring and Pooling events. See the
     /// CmapEventHandler type documentation for more details.
+    ///
+    /// To register multiple handlers, pass a `Vec<Arc<dyn CmapEventHandler>>`, which forwards
+    /// each event to all of them.
     #[derivative(Debug = "ignore", PartialEq = "ignore")]
     #[builder(default)]
     #[serde(skip)]
@@ -379,6 +458,9 @@ pub struct ClientOptions {
     /// The handler that should process all command-related events. See the CommandEventHandler
     /// type documentation for more details.
     ///
+    /// To register multiple handlers, pass a `Vec<Arc<dyn CommandEventHandler>>`, which forwards
+    /// each event to all of them.
+    ///
     /// Note that monitoring command events may incur a performance penalty.
     #[derivative(Debug = "ignore", PartialEq = "ignore")]
     #[builder(default)]
@@ -396,6 +478,15 @@ pub struct ClientOptions {
     #[builder(default)]
     pub credential: Option<Credential>,
 
+    /// A provider consulted for the credential each time a new connection is authenticated,
+    /// allowing rotated secrets to be picked up without recreating the client. If set, this takes
+    /// precedence over `credential`. See the [`CredentialProvider`] type documentation for more
+    /// details.
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[builder(default)]
+    #[serde(skip)]
+    pub credential_provider: Option<Arc<dyn CredentialProvider>>,
+
     /// Specifies whether the Client should directly connect to a single host rather than
     /// autodiscover all servers in the cluster.
     ///
@@ -457,6 +548,58 @@ pub struct ClientOptions {
     #[builder(default)]
     pub min_pool_size: Option<u32>,
 
+    /// The maximum number of connections that a server's connection pool may be establishing
+    /// concurrently. If an operation needs a new connection while `max_connecting` connections are
+    /// already being established, it will wait until one of them is ready or another connection is
+    /// checked back into the pool.
+    ///
+    /// The default value is 2.
+    #[builder(default)]
+    pub max_connecting: Option<u32>,
+
+    /// The maximum amount of time an operation will wait to check out a connection from a server's
+    /// connection pool, e.g. because `max_pool_size` connections are already checked out. If this
+    /// elapses, the operation fails with a timeout error and a `ConnectionCheckoutFailedEvent` is
+    /// emitted with a reason of `ConnectionCheckoutFailedReason::Timeout`.
+    ///
+    /// This does not bound the time spent establishing a new connection, which is governed by
+    /// `connect_timeout` instead.
+    ///
+    /// A value of zero means that operations wait for a connection indefinitely.
+    ///
+    /// By default, operations will wait for a connection until the server selection timeout has
+    /// elapsed.
+    #[builder(default)]
+    pub wait_queue_timeout: Option<Duration>,
+
+    /// The hostname or IP address of a SOCKS5 proxy that all connections made by the Client
+    /// should be tunnelled through, including server monitoring connections and connections to
+    /// KMS providers for client-side field level encryption. Connections to Unix domain sockets
+    /// are never proxied.
+    ///
+    /// By default, connections are made directly.
+    #[builder(default)]
+    pub proxy_host: Option<String>,
+
+    /// The port of the SOCKS5 proxy specified by `proxy_host`.
+    ///
+    /// The default value is 1080.
+    #[builder(default)]
+    pub proxy_port: Option<u16>,
+
+    /// The username to authenticate to the SOCKS5 proxy with. Must be specified together with
+    /// `proxy_password`.
+    ///
+    /// By default, no authentication is performed.
+    #[builder(default)]
+    pub proxy_username: Option<String>,
+
+    /// The password to authenticate to the SOCKS5 proxy with. Must be specified together with
+    /// `proxy_username`.
+    #[derivative(Debug = "ignore")]
+    #[builder(default)]
+    pub proxy_password: Option<String>,
+
     /// Specifies the default read concern for operations performed on the Client. See the
     /// ReadConcern type documentation for more details.
     #[builder(default)]
@@ -480,11 +623,22 @@ pub struct ClientOptions {
 
     /// The handler that should process all Server Discovery and Monitoring events. See the
     /// [`SdamEventHandler`] type documentation for more details.
+    ///
+    /// To register multiple handlers, pass a `Vec<Arc<dyn SdamEventHandler>>`, which forwards
+    /// each event to all of them.
     #[derivative(Debug = "ignore", PartialEq = "ignore")]
     #[builder(default)]
     #[serde(skip)]
     pub sdam_event_handler: Option<Arc<dyn SdamEventHandler>>,
 
+    /// The sink that should receive the metrics recorded by the client, such as operation
+    /// latencies and connection pool wait times. See the [`MetricsSink`] type documentation for
+    /// more details.
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[builder(default)]
+    #[serde(skip)]
+    pub metrics_sink: Option<Arc<dyn MetricsSink>>,
+
     /// The default selection criteria for operations performed on the Client. See the
     /// SelectionCriteria type documentation for more details.
     #[builder(default)]
@@ -521,6 +675,23 @@ pub struct ClientOptions {
     #[derivative(Debug = "ignore")]
     pub(crate) socket_timeout: Option<Duration>,
 
+    /// The default amount of time an operation may take to complete, including server selection,
+    /// connection checkout, any retries, and the time spent waiting on the server. While this
+    /// timeout is in effect, the remaining time is also sent to the server as `maxTimeMS` for
+    /// commands that do not set it explicitly. Operations that exceed it fail with
+    /// [`ErrorKind::Timeout`](crate::error::ErrorKind::Timeout). Operations that are split into
+    /// several commands, such as `insert_many` and `bulk_write`, share a single timeout across
+    /// all of them. Cursors apply the timeout to their whole lifetime, including every batch they
+    /// fetch from the server, except for tailable cursors and change streams, which apply it
+    /// separately to each batch.
+    ///
+    /// This can be overridden for a database, collection, or individual operation via the
+    /// `timeout` field of the corresponding options.
+    ///
+    /// By default, operations are not subject to a client-side timeout.
+    #[builder(default)]
+    pub timeout: Option<Duration>,
+
     /// The TLS configuration for the Client to use in its connections with the server.
     ///
     /// By default, TLS is disabled.
@@ -567,6 +738,23 @@ pub struct ClientOptions {
     #[derivative(Debug = "ignore")]
     pub(crate) resolver_config: Option<ResolverConfig>,
 
+    /// The resolver used for the SRV and TXT lookups of a `mongodb+srv` connection string. If
+    /// unset, lookups are performed with trust-dns, configured as described above.
+    ///
+    /// This resolver is used when polling for changes to the set of mongos hosts; to also use it
+    /// for the initial lookups, parse the connection string with
+    /// [`ClientOptions::parse_with_srv_resolver`].
+    #[builder(default)]
+    #[serde(skip)]
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    pub srv_resolver: Option<Arc<dyn SrvResolver>>,
+
+    /// The event streams registered on the client created from these options.
+    #[builder(default, setter(skip))]
+    #[serde(skip)]
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    pub(crate) event_streams: EventStreams,
+
     /// Control test behavior of the client.
     #[cfg(test)]
     #[builder(default, setter(skip))]
@@ -629,6 +817,19 @@ impl Serialize for ClientOptions {
 
             minpoolsize: &'a Option<u32>,
 
+            maxconnecting: &'a Option<u32>,
+
+            #[serde(serialize_with = "bson_util::serialize_duration_option_as_int_millis")]
+            waitqueuetimeoutms: &'a Option<Duration>,
+
+            proxyhost: &'a Option<String>,
+
+            proxyport: &'a Option<u16>,
+
+            proxyusername: &'a Option<String>,
+
+            proxypassword: &'a Option<String>,
+
             #[serde(flatten, serialize_with = "ReadConcern::serialize_for_client_options")]
             readconcern: &'a Option<ReadConcern>,
 
@@ -650,6 +851,9 @@ impl Serialize for ClientOptions {
             #[serde(serialize_with = "bson_util::serialize_duration_option_as_int_millis")]
             sockettimeoutms: &'a Option<Duration>,
 
+            #[serde(serialize_with = "bson_util::serialize_duration_option_as_int_millis")]
+            timeoutms: &'a Option<Duration>,
+
             #[serde(flatten, serialize_with = "Tls::serialize_for_client_options")]
             tls: &'a Option<Tls>,
 
@@ -671,6 +875,12 @@ impl Serialize for ClientOptions {
             maxidletimems: &self.max_idle_time,
             maxpoolsize: &self.max_pool_size,
             minpoolsize: &self.min_pool_size,
+            maxconnecting: &self.max_connecting,
+            waitqueuetimeoutms: &self.wait_queue_timeout,
+            proxyhost: &self.proxy_host,
+            proxyport: &self.proxy_port,
+            proxyusername: &self.proxy_username,
+            proxypassword: &self.proxy_password,
             readconcern: &self.read_concern,
             replicaset: &self.repl_set_name,
             retryreads: &self.retry_reads,
@@ -678,6 +888,7 @@ impl Serialize for ClientOptions {
             selectioncriteria: &self.selection_criteria,
             serverselectiontimeoutms: &self.server_selection_timeout,
             sockettimeoutms: &self.socket_timeout,
+            timeoutms: &self.timeout,
             tls: &self.tls,
             writeconcern: &self.write_concern,
             loadbalanced: &self.load_balanced,
@@ -691,7 +902,8 @@ impl Serialize for ClientOptions {
 /// Contains the options that can be set via a MongoDB connection string.
 ///
 /// The format of a MongoDB connection string is described [here](https://www.mongodb.com/docs/manual/reference/connection-string/#connection-string-formats).
-#[derive(Debug, Default, PartialEq)]
+#[derive(Derivative, Default, PartialEq)]
+#[derivative(Debug)]
 #[non_exhaustive]
 pub struct ConnectionString {
     /// The initial list of seeds that the Client should connect to, or a DNS name used for SRV
@@ -762,6 +974,27 @@ pub struct ConnectionString {
     /// The default value is 0.
     pub min_pool_size: Option<u32>,
 
+    /// The maximum number of connections that a server's connection pool may be establishing
+    /// concurrently.
+    ///
+    /// The default value is 2.
+    pub max_connecting: Option<u32>,
+
+    /// The hostname or IP address of a SOCKS5 proxy that connections should be tunnelled through.
+    pub proxy_host: Option<String>,
+
+    /// The port of the SOCKS5 proxy specified by `proxy_host`.
+    ///
+    /// The default value is 1080.
+    pub proxy_port: Option<u16>,
+
+    /// The username to authenticate to the SOCKS5 proxy with.
+    pub proxy_username: Option<String>,
+
+    /// The password to authenticate to the SOCKS5 proxy with.
+    #[derivative(Debug = "ignore")]
+    pub proxy_password: Option<String>,
+
     /// The amount of time that a connection can remain idle in a connection pool before being
     /// closed. A value of zero indicates that connections should not be closed due to being idle.
     ///
@@ -811,6 +1044,9 @@ pub struct ConnectionString {
     /// this only applies to application operations, not server discovery and monitoring.
     pub socket_timeout: Option<Duration>,
 
+    /// The default client-side timeout for operations performed by the Client.
+    pub timeout: Option<Duration>,
+
     /// Default read preference for the client.
     pub read_preference: Option<ReadPreference>,
 
@@ -849,12 +1085,19 @@ impl Default for HostInfo {
 }
 
 impl HostInfo {
-    async fn resolve(self, resolver_config: Option<ResolverConfig>) -> Result<ResolvedHostInfo> {
+    async fn resolve(
+        self,
+        srv_resolver: Option<Arc<dyn SrvResolver>>,
+        resolver_config: Option<ResolverConfig>,
+    ) -> Result<ResolvedHostInfo> {
         Ok(match self {
             Self::HostIdentifiers(hosts) => ResolvedHostInfo::HostIdentifiers(hosts),
             Self::DnsRecord(hostname) => {
-                let mut resolver =
-                    SrvResolver::new(resolver_config.clone().map(|config| config.inner)).await?;
+                let mut resolver = SeedlistResolver::new(
+                    srv_resolver,
+                    resolver_config.clone().map(|config| config.inner),
+                )
+                .await?;
                 let config = resolver.resolve_client_options(&hostname).await?;
                 ResolvedHostInfo::DnsRecord { hostname, config }
             }
@@ -912,8 +1155,9 @@ impl Tls {
 }
 
 /// Specifies the TLS configuration that the [`Client`](../struct.Client.html) should use.
-#[derive(Clone, Debug, Default, Deserialize, PartialEq, TypedBuilder)]
+#[derive(Clone, Default, Deserialize, Derivative, TypedBuilder)]
 #[builder(field_defaults(default, setter(into)))]
+#[derivative(Debug, PartialEq)]
 #[non_exhaustive]
 pub struct TlsOptions {
     /// Whether or not the [`Client`](../struct.Client.html) should return an error if the server
@@ -934,6 +1178,62 @@ pub struct TlsOptions {
     /// server.
     pub cert_key_file_path: Option<PathBuf>,
 
+    /// CA certificates that the [`Client`](../struct.Client.html) should use for TLS, provided
+    /// in memory rather than read from `ca_file_path`. If both are specified, the certificates
+    /// from both sources are trusted.
+    #[serde(skip)]
+    pub ca_certificates: Option<TlsData>,
+
+    /// The certificate chain that the [`Client`](../struct.Client.html) should present to the
+    /// server to verify its identity, provided in memory rather than read from
+    /// `cert_key_file_path`. If this is PEM-encoded, it may also contain the private key, in which
+    /// case `client_key` can be omitted.
+    #[serde(skip)]
+    pub client_certificate: Option<TlsData>,
+
+    /// The private key corresponding to `client_certificate`.
+    #[serde(skip)]
+    pub client_key: Option<TlsData>,
+
+    /// The password used to decrypt the client's private key, whether it is read from
+    /// `cert_key_file_path` or provided via `client_key`. Only encrypted PKCS #8 keys are
+    /// supported. Unless the `openssl-tls` feature flag is enabled, this requires the
+    /// `encrypted-private-keys` feature flag.
+    #[derivative(Debug = "ignore")]
+    #[serde(skip)]
+    pub cert_key_password: Option<String>,
+
+    /// A provider consulted for the client's certificate and private key each time a new
+    /// connection is established, allowing rotated certificates to be picked up without recreating
+    /// the client. If set, this takes precedence over `cert_key_file_path`,
+    /// `client_certificate`, `client_key` and `cert_key_password`, and it cannot be combined with
+    /// `rustls_config`. See the [`TlsIdentityProvider`] type documentation for more details.
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[serde(skip)]
+    pub identity_provider: Option<Arc<dyn TlsIdentityProvider>>,
+
+    /// Whether or not the [`Client`](../struct.Client.html) should skip checking whether the
+    /// server's certificate has been revoked, both via an OCSP response stapled by the server and
+    /// via `crl_file_path`. This setting should _not_ be set to `true` in production.
+    ///
+    /// The default value is to check for revocation. A stapled OCSP response that reports the
+    /// certificate as revoked causes the connection to fail with
+    /// [`ErrorKind::CertificateRevoked`](crate::error::ErrorKind::CertificateRevoked).
+    pub disable_certificate_revocation_check: Option<bool>,
+
+    /// Whether or not the [`Client`](../struct.Client.html) should avoid contacting OCSP
+    /// responders when a server does not staple an OCSP response to its certificate. The driver
+    /// never contacts OCSP responders itself, so this currently only affects validation of the
+    /// connection string; stapled responses are checked unless
+    /// `disable_certificate_revocation_check` is set.
+    pub disable_ocsp_endpoint_check: Option<bool>,
+
+    /// The path to a file containing PEM-encoded certificate revocation lists. If specified, the
+    /// [`Client`](../struct.Client.html) will only connect to servers whose certificate issuer has
+    /// an unexpired CRL in this file and whose certificate is not listed in it. The file is
+    /// trusted in the same way as `ca_file_path`.
+    pub crl_file_path: Option<PathBuf>,
+
     /// Whether or not the [`Client`](../struct.Client.html) should return an error if the hostname
     /// is invalid.
     ///
@@ -941,6 +1241,51 @@ pub struct TlsOptions {
     #[cfg(any(feature = "openssl-tls", docsrs))]
     #[cfg_attr(docsrs, doc(cfg(feature = "openssl-tls")))]
     pub allow_invalid_hostnames: Option<bool>,
+
+    /// A complete rustls configuration to use for TLS connections. If this is specified, all of
+    /// the other options in this struct are ignored, and `identity_provider` must not be set.
+    ///
+    /// This requires the `rustls` crate version 0.20.
+    #[cfg(any(not(feature = "openssl-tls"), docsrs))]
+    #[cfg_attr(docsrs, doc(cfg(not(feature = "openssl-tls"))))]
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[serde(skip)]
+    pub rustls_config: Option<Arc<rustls::ClientConfig>>,
+
+    /// A custom verifier for the certificates presented by servers, e.g. to pin certificates or
+    /// to check revocation. If this is specified, it replaces the default verification, so
+    /// `ca_file_path`, `ca_certificates` and `allow_invalid_certificates` have no effect.
+    ///
+    /// This requires the `rustls` crate version 0.20.
+    #[cfg(any(not(feature = "openssl-tls"), docsrs))]
+    #[cfg_attr(docsrs, doc(cfg(not(feature = "openssl-tls"))))]
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[serde(skip)]
+    pub certificate_verifier: Option<Arc<dyn rustls::client::ServerCertVerifier>>,
+}
+
+/// Certificates or a private key provided in memory, e.g. for
+/// [`TlsOptions::ca_certificates`].
+#[derive(Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TlsData {
+    /// PEM-encoded data. This may contain multiple certificates, e.g. a CA bundle or a
+    /// certificate chain followed by its private key.
+    Pem(Vec<u8>),
+
+    /// DER-encoded data containing a single certificate or private key. Private keys must be in
+    /// PKCS #8 or PKCS #1 format.
+    Der(Vec<u8>),
+}
+
+impl fmt::Debug for TlsData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Avoid dumping (potentially secret) key material.
+        match self {
+            Self::Pem(bytes) => write!(f, "Pem({} bytes)", bytes.len()),
+            Self::Der(bytes) => write!(f, "Der({} bytes)", bytes.len()),
+        }
+    }
 }
 
 impl TlsOptions {
@@ -957,7 +1302,11 @@ impl TlsOptions {
             tls: bool,
             tlscafile: Option<&'a str>,
             tlscertificatekeyfile: Option<&'a str>,
+            tlscertificatekeyfilepassword: Option<&'a str>,
             tlsallowinvalidcertificates: Option<bool>,
+            tlsdisablecertificaterevocationcheck: Option<bool>,
+            tlsdisableocspendpointcheck: Option<bool>,
+            tlscrlfile: Option<&'a str>,
         }
 
         let state = TlsOptionsHelper {
@@ -970,7 +1319,14 @@ impl TlsOptions {
                 .cert_key_file_path
                 .as_ref()
                 .map(|s| s.to_str().unwrap()),
+            tlscertificatekeyfilepassword: tls_options.cert_key_password.as_deref(),
             tlsallowinvalidcertificates: tls_options.allow_invalid_certificates,
+            tlsdisablecertificaterevocationcheck: tls_options.disable_certificate_revocation_check,
+            tlsdisableocspendpointcheck: tls_options.disable_ocsp_endpoint_check,
+            tlscrlfile: tls_options
+                .crl_file_path
+                .as_ref()
+                .map(|s| s.to_str().unwrap()),
         };
         state.serialize(serializer)
     }
@@ -1032,11 +1388,16 @@ impl ClientOptions {
     ///   * `heartbeatFrequencyMS`: maps to the `heartbeat_frequency` field
     ///   * `journal`: maps to the `journal` field of the `write_concern` field
     ///   * `localThresholdMS`: maps to the `local_threshold` field
+    ///   * `maxConnecting`: maps to the `max_connecting` field
     ///   * `maxIdleTimeMS`: maps to the `max_idle_time` field
     ///   * `maxStalenessSeconds`: maps to the `max_staleness` field of the `selection_criteria`
     ///     field
     ///   * `maxPoolSize`: maps to the `max_pool_size` field
     ///   * `minPoolSize`: maps to the `min_pool_size` field
+    ///   * `proxyHost`: maps to the `proxy_host` field
+    ///   * `proxyPassword`: maps to the `proxy_password` field
+    ///   * `proxyPort`: maps to the `proxy_port` field
+    ///   * `proxyUsername`: maps to the `proxy_username` field
     ///   * `readConcernLevel`: maps to the `read_concern` field
     ///   * `readPreferenceField`: maps to the ReadPreference enum variant of the
     ///     `selection_criteria` field
@@ -1048,6 +1409,7 @@ impl ClientOptions {
     ///   * `retryReads`: maps to the `retry_reads` field
     ///   * `serverSelectionTimeoutMS`: maps to the `server_selection_timeout` field
     ///   * `socketTimeoutMS`: unsupported, does not map to any field
+    ///   * `timeoutMS`: maps to the `timeout` field
     ///   * `ssl`: an alias of the `tls` option
     ///   * `tls`: maps to the TLS variant of the `tls` field`.
     ///   * `tlsInsecure`: relaxes the TLS constraints on connections being made; currently is just
@@ -1057,8 +1419,15 @@ impl ClientOptions {
     ///     `tls` field
     ///   * `tlsCAFile`: maps to the `ca_file_path` field of the `tls` field
     ///   * `tlsCertificateKeyFile`: maps to the `cert_key_file_path` field of the `tls` field
+    ///   * `tlsCertificateKeyFilePassword`: maps to the `cert_key_password` field of the `tls`
+    ///     field
+    ///   * `tlsCRLFile`: maps to the `crl_file_path` field of the `tls` field
+    ///   * `tlsDisableCertificateRevocationCheck`: maps to the
+    ///     `disable_certificate_revocation_check` field of the `tls` field
+    ///   * `tlsDisableOCSPEndpointCheck`: maps to the `disable_ocsp_endpoint_check` field of the
+    ///     `tls` field
     ///   * `w`: maps to the `w` field of the `write_concern` field
-    ///   * `waitQueueTimeoutMS`: unsupported, does not map to any field
+    ///   * `waitQueueTimeoutMS`: maps to the `wait_queue_timeout` field
     ///   * `wTimeoutMS`: maps to the `w_timeout` field of the `write_concern` field
     ///   * `zlibCompressionLevel`: maps to the `level` field of the `Compressor::Zlib` variant
     ///     (which requires the `zlib-compression` feature flag) of the [`Compressor`] enum
@@ -1108,13 +1477,53 @@ impl ClientOptions {
         runtime::block_on(Self::parse_uri(uri, Some(resolver_config)))
     }
 
+    /// Parses a MongoDB connection string into a `ClientOptions` struct.
+    /// If the string is malformed or one of the options has an invalid value, an error will be
+    /// returned.
+    ///
+    /// In the case that "mongodb+srv" is used, SRV and TXT record lookups will be done using the
+    /// provided `SrvResolver` as part of this method. The resolver is also stored in the returned
+    /// options' `srv_resolver` field so that it is used to poll for changes to the set of mongos
+    /// hosts.
+    ///
+    /// See the docstring on `ClientOptions::parse` for information on how the various URI options
+    /// map to fields on `ClientOptions`.
+    ///
+    /// Note: if the `sync` feature is enabled, then this method will be replaced with [the sync
+    /// version](#method.parse_with_srv_resolver-1).
+    #[cfg(all(not(feature = "sync"), not(feature = "tokio-sync")))]
+    pub async fn parse_with_srv_resolver(
+        uri: impl AsRef<str>,
+        srv_resolver: Arc<dyn SrvResolver>,
+    ) -> Result<Self> {
+        Self::parse_connection_string_internal(
+            ConnectionString::parse(uri)?,
+            Some(srv_resolver),
+            None,
+        )
+        .await
+    }
+
+    /// This method will be present if the `sync` feature is enabled. It's otherwise identical to
+    /// [the async version](#method.parse_with_srv_resolver)
+    #[cfg(any(feature = "sync", feature = "tokio-sync", docsrs))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "sync", feature = "tokio-sync"))))]
+    pub fn parse_with_srv_resolver(uri: &str, srv_resolver: Arc<dyn SrvResolver>) -> Result<Self> {
+        runtime::block_on(Self::parse_connection_string_internal(
+            ConnectionString::parse(uri)?,
+            Some(srv_resolver),
+            None,
+        ))
+    }
+
     /// Populate this `ClientOptions` from the given URI, optionally using the resolver config for
     /// DNS lookups.
     pub(crate) async fn parse_uri(
         uri: impl AsRef<str>,
         resolver_config: Option<ResolverConfig>,
     ) -> Result<Self> {
-        Self::parse_connection_string_internal(ConnectionString::parse(uri)?, resolver_config).await
+        Self::parse_connection_string_internal(ConnectionString::parse(uri)?, None, resolver_config)
+            .await
     }
 
     /// Creates a `ClientOptions` from the given `ConnectionString`.
@@ -1125,16 +1534,28 @@ impl ClientOptions {
         conn_str: ConnectionString,
         resolver_config: ResolverConfig,
     ) -> Result<Self> {
-        Self::parse_connection_string_internal(conn_str, Some(resolver_config)).await
+        Self::parse_connection_string_internal(conn_str, None, Some(resolver_config)).await
     }
 
     /// Creates a `ClientOptions` from the given `ConnectionString`.
     pub async fn parse_connection_string(conn_str: ConnectionString) -> Result<Self> {
-        Self::parse_connection_string_internal(conn_str, None).await
+        Self::parse_connection_string_internal(conn_str, None, None).await
+    }
+
+    /// Creates a `ClientOptions` from the given `ConnectionString`.
+    ///
+    /// In the case that "mongodb+srv" is used, SRV and TXT record lookups will be done using the
+    /// provided `SrvResolver` as part of this method.
+    pub async fn parse_connection_string_with_srv_resolver(
+        conn_str: ConnectionString,
+        srv_resolver: Arc<dyn SrvResolver>,
+    ) -> Result<Self> {
+        Self::parse_connection_string_internal(conn_str, Some(srv_resolver), None).await
     }
 
     async fn parse_connection_string_internal(
         mut conn_str: ConnectionString,
+        srv_resolver: Option<Arc<dyn SrvResolver>>,
         resolver_config: Option<ResolverConfig>,
     ) -> Result<Self> {
         let auth_source_present = conn_str
@@ -1145,8 +1566,9 @@ impl ClientOptions {
         let host_info = std::mem::take(&mut conn_str.host_info);
         let mut options = Self::from_connection_string(conn_str);
         options.resolver_config = resolver_config.clone();
+        options.srv_resolver = srv_resolver.clone();
 
-        let resolved = host_info.resolve(resolver_config).await?;
+        let resolved = host_info.resolve(srv_resolver, resolver_config).await?;
         options.hosts = match resolved {
             ResolvedHostInfo::HostIdentifiers(hosts) => hosts,
             ResolvedHostInfo::DnsRecord {
@@ -1200,7 +1622,7 @@ impl ClientOptions {
     /// Creates a `ClientOptions` from the given `ConnectionString`.
     #[cfg(any(feature = "sync", feature = "tokio-sync"))]
     pub fn parse_connection_string_sync(conn_str: ConnectionString) -> Result<Self> {
-        crate::runtime::block_on(Self::parse_connection_string_internal(conn_str, None))
+        crate::runtime::block_on(Self::parse_connection_string_internal(conn_str, None, None))
     }
 
     /// Creates a `ClientOptions` from the given `ConnectionString`.
@@ -1214,6 +1636,7 @@ impl ClientOptions {
     ) -> Result<Self> {
         crate::runtime::block_on(Self::parse_connection_string_internal(
             conn_str,
+            None,
             Some(resolver_config),
         ))
     }
@@ -1221,20 +1644,8 @@ impl ClientOptions {
     fn from_connection_string(conn_str: ConnectionString) -> Self {
         let mut credential = conn_str.credential;
         // Populate default auth source, if needed.
-        let db = &conn_str.default_database;
         if let Some(credential) = credential.as_mut() {
-            if credential.source.is_none() {
-                credential.source = match &credential.mechanism {
-                    Some(mechanism) => Some(mechanism.default_source(db.as_deref()).into()),
-                    None => {
-                        // If credentials exist (i.e. username is specified) but no mechanism, the
-                        // default source is chosen from the following list in
-                        // order (skipping null ones): authSource option, connection string db,
-                        // SCRAM default (i.e. "admin").
-                        db.clone().or_else(|| Some("admin".into()))
-                    }
-                };
-            }
+            credential.set_default_source(conn_str.default_database.as_deref());
         }
         Self {
             hosts: vec![],
@@ -1248,6 +1659,12 @@ impl ClientOptions {
             write_concern: conn_str.write_concern,
             max_pool_size: conn_str.max_pool_size,
             min_pool_size: conn_str.min_pool_size,
+            max_connecting: conn_str.max_connecting,
+            wait_queue_timeout: conn_str.wait_queue_timeout,
+            proxy_host: conn_str.proxy_host,
+            proxy_port: conn_str.proxy_port,
+            proxy_username: conn_str.proxy_username,
+            proxy_password: conn_str.proxy_password,
             max_idle_time: conn_str.max_idle_time,
             server_selection_timeout: conn_str.server_selection_timeout,
             compressors: conn_str.compressors,
@@ -1255,19 +1672,24 @@ impl ClientOptions {
             retry_reads: conn_str.retry_reads,
             retry_writes: conn_str.retry_writes,
             socket_timeout: conn_str.socket_timeout,
+            timeout: conn_str.timeout,
             direct_connection: conn_str.direct_connection,
             default_database: conn_str.default_database,
             driver_info: None,
             credential,
+            credential_provider: None,
             cmap_event_handler: None,
             command_event_handler: None,
             original_srv_info: None,
             #[cfg(test)]
             original_uri: Some(conn_str.original_uri),
             resolver_config: None,
+            srv_resolver: None,
+            event_streams: Default::default(),
             server_api: None,
             load_balanced: conn_str.load_balanced,
             sdam_event_handler: None,
+            metrics_sink: None,
             #[cfg(test)]
             test_options: None,
             #[cfg(feature = "tracing-unstable")]
@@ -1282,6 +1704,21 @@ impl ClientOptions {
         }
     }
 
+    pub(crate) fn socks5_proxy(&self) -> Option<Socks5Proxy> {
+        let host = self.proxy_host.clone()?;
+        let credentials = match (&self.proxy_username, &self.proxy_password) {
+            (Some(username), Some(password)) => Some((username.clone(), password.clone())),
+            _ => None,
+        };
+        Some(Socks5Proxy {
+            address: ServerAddress::Tcp {
+                host,
+                port: Some(self.proxy_port.unwrap_or(DEFAULT_PROXY_PORT)),
+            },
+            credentials,
+        })
+    }
+
     /// Ensure the options set are valid, returning an error describing the problem if they are not.
     pub(crate) fn validate(&self) -> Result<()> {
         if let Some(true) = self.direct_connection {
@@ -1329,6 +1766,35 @@ impl ClientOptions {
             return Err(Error::invalid_argument("cannot specify maxPoolSize=0"));
         }
 
+        if let Some(0) = self.max_connecting {
+            return Err(Error::invalid_argument("cannot specify maxConnecting=0"));
+        }
+
+        if self.proxy_host.is_none()
+            && (self.proxy_port.is_some()
+                || self.proxy_username.is_some()
+                || self.proxy_password.is_some())
+        {
+            return Err(Error::invalid_argument(
+                "proxyPort, proxyUsername and proxyPassword require proxyHost to be specified",
+            ));
+        }
+
+        if self.proxy_username.is_some() != self.proxy_password.is_some() {
+            return Err(Error::invalid_argument(
+                "proxyUsername and proxyPassword must be specified together",
+            ));
+        }
+
+        #[cfg(not(feature = "openssl-tls"))]
+        if let Some(Tls::Enabled(ref tls_options)) = self.tls {
+            if tls_options.rustls_config.is_some() && tls_options.identity_provider.is_some() {
+                return Err(Error::invalid_argument(
+                    "cannot specify both rustls_config and identity_provider",
+                ));
+            }
+        }
+
         if let Some(SelectionCriteria::ReadPreference(ref rp)) = self.selection_criteria {
             if let Some(max_staleness) = rp.max_staleness() {
                 verify_max_staleness(
@@ -1370,14 +1836,21 @@ impl ClientOptions {
                 command_event_handler,
                 connect_timeout,
                 credential,
+                credential_provider,
                 direct_connection,
                 driver_info,
                 heartbeat_freq,
                 load_balanced,
                 local_threshold,
+                metrics_sink,
+                max_connecting,
                 max_idle_time,
                 max_pool_size,
                 min_pool_size,
+                proxy_host,
+                proxy_password,
+                proxy_port,
+                proxy_username,
                 read_concern,
                 repl_set_name,
                 retry_reads,
@@ -1387,7 +1860,9 @@ impl ClientOptions {
                 server_selection_timeout,
                 socket_timeout,
                 test_options,
+                timeout,
                 tls,
+                wait_queue_timeout,
                 write_concern,
                 original_srv_info,
                 original_uri
@@ -1563,8 +2038,20 @@ impl ConnectionString {
             None => (None, None),
         };
 
-        let host_list: Result<Vec<_>> =
-            hosts_section.split(',').map(ServerAddress::parse).collect();
+        let host_list: Result<Vec<_>> = hosts_section
+            .split(',')
+            .map(|host| {
+                // Unix Domain Socket paths are percent-encoded so that their slashes are not
+                // mistaken for the end of the host list.
+                #[cfg(unix)]
+                if host.ends_with(".sock") {
+                    let path =
+                        percent_decode(host, "Unix Domain Socket paths must be URL encoded")?;
+                    return ServerAddress::parse(path);
+                }
+                ServerAddress::parse(host)
+            })
+            .collect();
 
         let host_list = host_list?;
 
@@ -1576,7 +2063,16 @@ impl ConnectionString {
                 .into());
             }
             // Unwrap safety: the `len` check above guarantees this can't fail.
-            let ServerAddress::Tcp { host, port } = host_list.into_iter().next().unwrap();
+            let (host, port) = match host_list.into_iter().next().unwrap() {
+                ServerAddress::Tcp { host, port } => (host, port),
+                #[cfg(unix)]
+                ServerAddress::Unix { .. } => {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: "a Unix Domain Socket cannot be used with 'mongodb+srv'".into(),
+                    }
+                    .into())
+                }
+            };
 
             if port.is_some() {
                 return Err(ErrorKind::InvalidArgument {
@@ -1648,6 +2144,15 @@ impl ConnectionString {
                     credential.mechanism_properties = Some(doc);
                 }
 
+                if mechanism == &AuthMechanism::Gssapi {
+                    let properties = credential
+                        .mechanism_properties
+                        .get_or_insert_with(Document::new);
+                    if !properties.contains_key("SERVICE_NAME") {
+                        properties.insert("SERVICE_NAME", "mongodb");
+                    }
+                }
+
                 mechanism.validate_credential(credential)?;
                 credential.mechanism = parts.auth_mechanism.take();
             }
@@ -1676,7 +2181,7 @@ impl ConnectionString {
     }
 
     /// Amount of time spent attempting to check out a connection from a server's connection pool
-    /// before timing out.  Not supported by the Rust driver.
+    /// before timing out.
     pub fn wait_queue_timeout(&self) -> Option<Duration> {
         self.wait_queue_timeout
     }
@@ -1724,13 +2229,32 @@ impl ConnectionString {
             }
 
             // Skip leading '=' in value.
-            self.parse_option_pair(
-                &mut parts,
-                &key.to_lowercase(),
-                percent_encoding::percent_decode(&value.as_bytes()[1..])
+            let value = &value[1..];
+            let key = key.to_lowercase();
+            let decoded = match key.as_str() {
+                // The proxy credentials are decoded the same way as the userinfo credentials so
+                // that malformed encodings are rejected rather than silently replaced.
+                "proxyusername" | "proxypassword" => {
+                    validate_userinfo(value, &key)?;
+                    percent_decode(value, &format!("{} must be URL encoded", key))?
+                }
+                _ => percent_encoding::percent_decode(value.as_bytes())
                     .decode_utf8_lossy()
-                    .as_ref(),
-            )?;
+                    .into_owned(),
+            };
+            self.parse_option_pair(&mut parts, &key, &decoded)?;
+        }
+
+        // Options that disable certificate validation entirely also disable revocation checking,
+        // so the URI options spec forbids combining them with the revocation-specific options.
+        let present = |option: &str| keys.iter().any(|k| k.eq_ignore_ascii_case(option));
+        for (first, second) in CONFLICTING_TLS_OPTIONS {
+            if present(first) && present(second) {
+                return Err(ErrorKind::InvalidArgument {
+                    message: format!("'{}' and '{}' cannot both be specified", first, second),
+                }
+                .into());
+            }
         }
 
         if let Some(tags) = parts.read_preference_tags.take() {
@@ -1948,6 +2472,24 @@ impl ConnectionString {
             k @ "minpoolsize" => {
                 self.min_pool_size = Some(get_u32!(value, k));
             }
+            k @ "maxconnecting" => {
+                self.max_connecting = Some(get_u32!(value, k));
+            }
+            "proxyhost" => self.proxy_host = Some(value.to_string()),
+            k @ "proxyport" => match value.parse::<u16>() {
+                Ok(port) => self.proxy_port = Some(port),
+                Err(_) => {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: format!(
+                            "connection string `{}` argument must be a valid port number",
+                            k
+                        ),
+                    }
+                    .into())
+                }
+            },
+            "proxyusername" => self.proxy_username = Some(value.to_string()),
+            "proxypassword" => self.proxy_password = Some(value.to_string()),
             "readconcernlevel" => {
                 self.read_concern = Some(ReadConcernLevel::from_str(value).into());
             }
@@ -2020,6 +2562,9 @@ impl ConnectionString {
             k @ "sockettimeoutms" => {
                 self.socket_timeout = Some(Duration::from_millis(get_duration!(value, k)));
             }
+            k @ "timeoutms" => {
+                self.timeout = Some(Duration::from_millis(get_duration!(value, k)));
+            }
             k @ "tls" | k @ "ssl" => {
                 let tls = get_bool!(value, k);
 
@@ -2119,6 +2664,87 @@ impl ConnectionString {
                     ))
                 }
             },
+            "tlscertificatekeyfilepassword" => match self.tls {
+                Some(Tls::Disabled) => {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: "'tlsCertificateKeyFilePassword' can't be set if tls=false".into(),
+                    }
+                    .into());
+                }
+                Some(Tls::Enabled(ref mut options)) => {
+                    options.cert_key_password = Some(value.to_string());
+                }
+                None => {
+                    self.tls = Some(Tls::Enabled(
+                        TlsOptions::builder()
+                            .cert_key_password(value.to_string())
+                            .build(),
+                    ))
+                }
+            },
+            "tlscrlfile" => match self.tls {
+                Some(Tls::Disabled) => {
+                    return Err(ErrorKind::InvalidArgument {
+                        message: "'tlsCRLFile' can't be set if tls=false".into(),
+                    }
+                    .into());
+                }
+                Some(Tls::Enabled(ref mut options)) => {
+                    options.crl_file_path = Some(value.into());
+                }
+                None => {
+                    self.tls = Some(Tls::Enabled(
+                        TlsOptions::builder()
+                            .crl_file_path(PathBuf::from(value))
+                            .build(),
+                    ))
+                }
+            },
+            k @ "tlsdisablecertificaterevocationcheck" => {
+                let val = get_bool!(value, k);
+                match self.tls {
+                    Some(Tls::Disabled) => {
+                        return Err(ErrorKind::InvalidArgument {
+                            message: "'tlsDisableCertificateRevocationCheck' can't be set if \
+                                      tls=false"
+                                .into(),
+                        }
+                        .into());
+                    }
+                    Some(Tls::Enabled(ref mut options)) => {
+                        options.disable_certificate_revocation_check = Some(val);
+                    }
+                    None => {
+                        self.tls = Some(Tls::Enabled(
+                            TlsOptions::builder()
+                                .disable_certificate_revocation_check(val)
+                                .build(),
+                        ))
+                    }
+                }
+            }
+            k @ "tlsdisableocspendpointcheck" => {
+                let val = get_bool!(value, k);
+                match self.tls {
+                    Some(Tls::Disabled) => {
+                        return Err(ErrorKind::InvalidArgument {
+                            message: "'tlsDisableOCSPEndpointCheck' can't be set if tls=false"
+                                .into(),
+                        }
+                        .into());
+                    }
+                    Some(Tls::Enabled(ref mut options)) => {
+                        options.disable_ocsp_endpoint_check = Some(val);
+                    }
+                    None => {
+                        self.tls = Some(Tls::Enabled(
+                            TlsOptions::builder()
+                                .disable_ocsp_endpoint_check(val)
+                                .build(),
+                        ))
+                    }
+                }
+            }
             "w" => {
                 let mut write_concern = self.write_concern.get_or_insert_with(Default::default);
 
@@ -2261,15 +2887,15 @@ mod tests {
     #[test]
     fn test_parse_address_with_from_str() {
         let x = "localhost:27017".parse::<ServerAddress>().unwrap();
-        let ServerAddress::Tcp { host, port } = x;
-        assert_eq!(host, "localhost");
-        assert_eq!(port, Some(27017));
+        assert!(matches!(x, ServerAddress::Tcp { .. }));
+        assert_eq!(x.host(), "localhost");
+        assert_eq!(x.port(), Some(27017));
 
         // Port defaults to 27017 (so this doesn't fail)
         let x = "localhost".parse::<ServerAddress>().unwrap();
-        let ServerAddress::Tcp { host, port } = x;
-        assert_eq!(host, "localhost");
-        assert_eq!(port, None);
+        assert!(matches!(x, ServerAddress::Tcp { .. }));
+        assert_eq!(x.host(), "localhost");
+        assert_eq!(x.port(), None);
 
         let x = "localhost:not a number".parse::<ServerAddress>();
         assert!(x.is_err());
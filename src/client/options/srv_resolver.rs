@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+
+use crate::error::Result;
+
+/// A DNS resolver used to look up the SRV and TXT records of a `mongodb+srv` connection string,
+/// both when the connection string is parsed and when polling for changes to the set of mongos
+/// hosts.
+///
+/// By default, the driver performs lookups using `trust-dns`, configured via the host system's
+/// resolver configuration or a [`ResolverConfig`](crate::options::ResolverConfig). A custom
+/// resolver can be provided to integrate with other service discovery mechanisms, or to return
+/// fixed records in tests. Errors returned by the resolver can be constructed from a
+/// [`std::io::Error`].
+///
+/// ```rust
+/// # use std::{sync::Arc, time::Duration};
+/// # use futures_util::future::{BoxFuture, FutureExt};
+/// # use mongodb::{error::Result, options::{ClientOptions, SrvRecord, SrvResolver}};
+/// #
+/// struct StaticResolver;
+///
+/// impl SrvResolver for StaticResolver {
+///     fn lookup_srv<'a>(&'a self, _name: &'a str) -> BoxFuture<'a, Result<Vec<SrvRecord>>> {
+///         let record = SrvRecord::new("host1.example.com", 27017, Duration::from_secs(60));
+///         async move { Ok(vec![record]) }.boxed()
+///     }
+///
+///     fn lookup_txt<'a>(&'a self, _name: &'a str) -> BoxFuture<'a, Result<Vec<String>>> {
+///         async move { Ok(vec!["replicaSet=rs0".to_string()]) }.boxed()
+///     }
+/// }
+///
+/// # #[cfg(all(not(feature = "sync"), not(feature = "tokio-sync")))]
+/// # async fn run() -> Result<()> {
+/// let options = ClientOptions::parse_with_srv_resolver(
+///     "mongodb+srv://cluster.example.com",
+///     Arc::new(StaticResolver),
+/// )
+/// .await?;
+/// # Ok(())
+/// # }
+/// # #[cfg(any(feature = "sync", feature = "tokio-sync"))]
+/// # fn run() -> Result<()> {
+/// # let options = ClientOptions::parse_with_srv_resolver(
+/// #     "mongodb+srv://cluster.example.com",
+/// #     Arc::new(StaticResolver),
+/// # )?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait SrvResolver: Send + Sync {
+    /// Looks up the SRV records for `name`. An empty list should be returned if no records exist.
+    fn lookup_srv<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<SrvRecord>>>;
+
+    /// Looks up the TXT records for `name`, returning the contents of each record with its
+    /// character-strings concatenated. An empty list should be returned if no records exist.
+    fn lookup_txt<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<String>>>;
+}
+
+/// A single SRV record returned by an [`SrvResolver`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SrvRecord {
+    /// The hostname of the target of the record.
+    pub target: String,
+
+    /// The port of the target of the record.
+    pub port: u16,
+
+    /// How long the record may be cached for.
+    pub ttl: Duration,
+}
+
+impl SrvRecord {
+    /// Creates a new `SrvRecord`.
+    pub fn new(target: impl Into<String>, port: u16, ttl: Duration) -> Self {
+        Self {
+            target: target.into(),
+            port,
+            ttl,
+        }
+    }
+}
@@ -9,16 +9,22 @@ use std::{
     time::{Duration, Instant},
 };
 
+use futures_util::future::BoxFuture;
 use lazy_static::lazy_static;
 use uuid::Uuid;
 
 use crate::{
     bson::{doc, spec::BinarySubtype, Binary, Bson, Document, Timestamp},
     cmap::conn::PinnedConnectionHandle,
-    error::{ErrorKind, Result},
+    error::{
+        Error,
+        ErrorKind,
+        Result,
+        TRANSIENT_TRANSACTION_ERROR,
+        UNKNOWN_TRANSACTION_COMMIT_RESULT,
+    },
     operation::{AbortTransaction, CommitTransaction, Operation},
     options::{SessionOptions, TransactionOptions},
-    runtime,
     sdam::{ServerInfo, TransactionSupportStatus},
     selection_criteria::SelectionCriteria,
     Client,
@@ -26,17 +32,51 @@ use crate::{
 pub use cluster_time::ClusterTime;
 pub(super) use pool::ServerSessionPool;
 
-use super::options::ServerAddress;
+use super::{options::ServerAddress, AsyncDropToken};
 
 lazy_static! {
     pub(crate) static ref SESSIONS_UNSUPPORTED_COMMANDS: HashSet<&'static str> = {
         let mut hash_set = HashSet::new();
         hash_set.insert("killcursors");
+        hash_set.insert("endsessions");
         hash_set.insert("parallelcollectionscan");
         hash_set
     };
 }
 
+/// The amount of time [`ClientSession::with_transaction`] will keep retrying a transaction before
+/// returning the most recent error.
+pub(crate) const WITH_TRANSACTION_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// The step of `with_transaction` to retry after a failed commit.
+pub(crate) enum WithTransactionRetry {
+    /// Retry the commit.
+    Commit,
+    /// Restart the transaction and run the callback again.
+    Transaction,
+}
+
+/// Determines how `with_transaction` should proceed after committing failed with `error`, or
+/// `None` if the error should be returned.
+pub(crate) fn with_transaction_commit_retry(
+    error: &Error,
+    start: Instant,
+) -> Option<WithTransactionRetry> {
+    if error.is_max_time_ms_expired_error()
+        || error.is_operation_timeout()
+        || start.elapsed() >= WITH_TRANSACTION_TIMEOUT
+    {
+        return None;
+    }
+    if error.contains_label(UNKNOWN_TRANSACTION_COMMIT_RESULT) {
+        return Some(WithTransactionRetry::Commit);
+    }
+    if error.contains_label(TRANSIENT_TRANSACTION_ERROR) {
+        return Some(WithTransactionRetry::Transaction);
+    }
+    None
+}
+
 /// A MongoDB client session. This struct represents a logical session used for ordering sequential
 /// operations. To create a `ClientSession`, call `start_session` on a `Client`.
 ///
@@ -110,6 +150,7 @@ pub struct ClientSession {
     pub(crate) transaction: Transaction,
     pub(crate) snapshot_time: Option<Timestamp>,
     pub(crate) operation_time: Option<Timestamp>,
+    drop_token: AsyncDropToken,
 }
 
 #[derive(Debug)]
@@ -208,6 +249,7 @@ impl ClientSession {
         is_implicit: bool,
     ) -> Self {
         Self {
+            drop_token: client.register_async_drop(),
             client,
             server_session,
             cluster_time: None,
@@ -561,6 +603,114 @@ impl ClientSession {
         }
     }
 
+    /// Starts a transaction, runs the given callback, and commits or aborts the transaction.
+    /// Transient transaction errors will cause the callback or the commit to be retried;
+    /// other errors will cause the transaction to be aborted and the error returned to the
+    /// caller.
+    ///
+    /// Retries are attempted for up to 120 seconds after the first attempt was started. If the
+    /// callback commits or aborts the transaction itself, the result of the callback is returned
+    /// without any further action.
+    ///
+    /// Because the callback can be repeatedly executed and because it returns a future, the rust
+    /// closure borrowing rules for captured values can be overly restrictive. As a
+    /// convenience, `with_transaction` accepts a context argument that will be passed to the
+    /// callback along with the session:
+    ///
+    /// ```rust
+    /// # use mongodb::{bson::{doc, Document}, error::Result, Client};
+    /// # use futures::FutureExt;
+    /// # async fn do_stuff() -> Result<()> {
+    /// # let client = Client::with_uri_str("mongodb://example.com").await?;
+    /// # let mut session = client.start_session(None).await?;
+    /// let coll = client.database("mydb").collection::<Document>("mycoll");
+    /// let my_data = "my data".to_string();
+    /// // This works:
+    /// session.with_transaction(
+    ///     (&coll, &my_data),
+    ///     |session, (coll, my_data)| async move {
+    ///         coll.insert_one_with_session(doc! { "data": my_data.as_str() }, None, session).await
+    ///     }.boxed(),
+    ///     None,
+    /// ).await?;
+    /// /* This will not compile with a "variable moved due to use in generator" error:
+    /// session.with_transaction(
+    ///     (),
+    ///     |session, _| async move {
+    ///         coll.insert_one_with_session(doc! { "data": my_data }, None, session).await
+    ///     }.boxed(),
+    ///     None,
+    /// ).await?;
+    /// */
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_transaction<R, C, F>(
+        &mut self,
+        mut context: C,
+        mut callback: F,
+        options: impl Into<Option<TransactionOptions>>,
+    ) -> Result<R>
+    where
+        F: for<'a> FnMut(&'a mut ClientSession, &'a mut C) -> BoxFuture<'a, Result<R>>,
+    {
+        let options = options.into();
+        let start = Instant::now();
+
+        'transaction: loop {
+            self.start_transaction(options.clone()).await?;
+            let ret = match callback(self, &mut context).await {
+                Ok(v) => v,
+                Err(e) => {
+                    if self.with_transaction_callback_failed(&e, start).await {
+                        continue 'transaction;
+                    }
+                    return Err(e);
+                }
+            };
+            if !self.with_transaction_should_commit() {
+                return Ok(ret);
+            }
+            loop {
+                match self.commit_transaction().await {
+                    Ok(()) => return Ok(ret),
+                    Err(e) => match with_transaction_commit_retry(&e, start) {
+                        Some(WithTransactionRetry::Commit) => continue,
+                        Some(WithTransactionRetry::Transaction) => continue 'transaction,
+                        None => return Err(e),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Aborts the transaction started by `with_transaction` after its callback returned `error`,
+    /// and returns whether the callback should be retried. Errors from aborting are ignored so
+    /// that the callback's error is the one that is retried or returned.
+    pub(crate) async fn with_transaction_callback_failed(
+        &mut self,
+        error: &Error,
+        start: Instant,
+    ) -> bool {
+        if matches!(
+            self.transaction.state,
+            TransactionState::Starting | TransactionState::InProgress
+        ) {
+            let _ = self.abort_transaction().await;
+        }
+        error.contains_label(TRANSIENT_TRANSACTION_ERROR)
+            && start.elapsed() < WITH_TRANSACTION_TIMEOUT
+    }
+
+    /// Whether the callback passed to `with_transaction` left the transaction open, in which case
+    /// `with_transaction` is responsible for committing it.
+    pub(crate) fn with_transaction_should_commit(&self) -> bool {
+        matches!(
+            self.transaction.state,
+            TransactionState::Starting | TransactionState::InProgress
+        )
+    }
+
     fn default_transaction_options(&self) -> Option<&TransactionOptions> {
         self.options
             .as_ref()
@@ -577,6 +727,7 @@ struct DroppedClientSession {
     transaction: Transaction,
     snapshot_time: Option<Timestamp>,
     operation_time: Option<Timestamp>,
+    drop_token: AsyncDropToken,
 }
 
 impl From<DroppedClientSession> for ClientSession {
@@ -590,6 +741,7 @@ impl From<DroppedClientSession> for ClientSession {
             transaction: dropped_session.transaction,
             snapshot_time: dropped_session.snapshot_time,
             operation_time: dropped_session.operation_time,
+            drop_token: dropped_session.drop_token,
         }
     }
 }
@@ -606,15 +758,16 @@ impl Drop for ClientSession {
                 transaction: self.transaction.take(),
                 snapshot_time: self.snapshot_time,
                 operation_time: self.operation_time,
+                drop_token: AsyncDropToken::default(),
             };
-            runtime::execute(async move {
+            self.drop_token.spawn(async move {
                 let mut session: ClientSession = dropped_session.into();
                 let _result = session.abort_transaction().await;
             });
         } else {
             let client = self.client.clone();
             let server_session = self.server_session.clone();
-            runtime::execute(async move {
+            self.drop_token.spawn(async move {
                 client.check_in_server_session(server_session).await;
             });
         }
@@ -3,7 +3,6 @@ use std::{collections::VecDeque, time::Duration};
 use tokio::sync::Mutex;
 
 use super::ServerSession;
-#[cfg(test)]
 use crate::bson::Document;
 
 #[derive(Debug)]
@@ -59,6 +58,16 @@ impl ServerSessionPool {
         }
     }
 
+    /// Returns the ids of all of the sessions currently in the pool.
+    pub(crate) async fn get_session_ids(&self) -> Vec<Document> {
+        self.pool
+            .lock()
+            .await
+            .iter()
+            .map(|s| s.id.clone())
+            .collect()
+    }
+
     #[cfg(test)]
     pub(crate) async fn clear(&self) {
         self.pool.lock().await.clear();
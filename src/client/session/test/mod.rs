@@ -609,7 +609,7 @@ async fn find_and_getmore_share_session() {
         assert_eq!(getmore_session_id, session_id);
     }
 
-    let topology_description = client.topology_description();
+    let topology_description = client.topology_description().description;
     for (addr, server) in topology_description.servers {
         if !server.server_type.is_data_bearing() {
             continue;
@@ -221,7 +221,7 @@ impl Message {
             flags,
             sections,
             checksum,
-            request_id: None,
+            request_id: Some(header.request_id),
         })
     }
 
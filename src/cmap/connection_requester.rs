@@ -32,13 +32,14 @@ impl ConnectionRequester {
     pub(super) async fn request(&self) -> ConnectionRequestResult {
         let (sender, receiver) = oneshot::channel();
 
-        // this only errors if the receiver end is dropped, which can't happen because
-        // we own a handle to the worker, keeping it alive.
-        self.sender.send(sender).unwrap();
-
-        // similarly, the receiver only returns an error if the sender is dropped, which
-        // can't happen due to the handle.
-        receiver.await.unwrap()
+        // Since we own a handle to the worker, keeping it alive, these only error if the pool
+        // was explicitly shut down.
+        if self.sender.send(sender).is_err() {
+            return ConnectionRequestResult::PoolClosed;
+        }
+        receiver
+            .await
+            .unwrap_or(ConnectionRequestResult::PoolClosed)
     }
 }
 
@@ -86,6 +87,9 @@ pub(super) enum ConnectionRequestResult {
     /// The request was rejected because the pool was cleared before it could
     /// be fulfilled. The error that caused the pool to be cleared is returned.
     PoolCleared(Error),
+
+    /// The request was rejected because the pool was shut down.
+    PoolClosed,
 }
 
 impl ConnectionRequestResult {
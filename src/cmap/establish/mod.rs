@@ -1,6 +1,11 @@
 pub(super) mod handshake;
+#[cfg(test)]
+mod test;
 
-use std::time::Duration;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use self::handshake::{Handshaker, HandshakerOptions};
 use super::{
@@ -11,11 +16,25 @@ use super::{
 use crate::{
     client::{
         auth::Credential,
-        options::{ClientOptions, ServerAddress, TlsOptions},
+        options::{
+            ClientOptions,
+            CredentialProvider,
+            ServerAddress,
+            TlsIdentity,
+            TlsIdentityProvider,
+            TlsOptions,
+        },
     },
     error::{Error as MongoError, ErrorKind, Result},
     hello::HelloReply,
-    runtime::{self, stream::DEFAULT_CONNECT_TIMEOUT, AsyncStream, HttpClient, TlsConfig},
+    runtime::{
+        self,
+        stream::DEFAULT_CONNECT_TIMEOUT,
+        AsyncStream,
+        HttpClient,
+        Socks5Proxy,
+        TlsConfig,
+    },
     sdam::HandshakePhase,
 };
 
@@ -29,12 +48,30 @@ pub(crate) struct ConnectionEstablisher {
     /// Cached configuration needed to create TLS connections, if needed.
     tls_config: Option<TlsConfig>,
 
+    /// The configuration for TLS connections whose client identity comes from a provider. If
+    /// set, this takes precedence over `tls_config`.
+    provided_tls_config: Option<ProvidedTlsConfig>,
+
+    /// Provides the credential to authenticate each new connection with, if any. If set, this
+    /// takes precedence over the credential passed to `establish_connection`.
+    credential_provider: Option<Arc<dyn CredentialProvider>>,
+
+    /// The database specified in the connection string, which determines the default auth source
+    /// of credentials returned by `credential_provider`.
+    default_database: Option<String>,
+
+    /// The SOCKS5 proxy to tunnel connections through, if any.
+    proxy: Option<Socks5Proxy>,
+
     connect_timeout: Duration,
 }
 
 pub(crate) struct EstablisherOptions {
     handshake_options: HandshakerOptions,
     tls_options: Option<TlsOptions>,
+    credential_provider: Option<Arc<dyn CredentialProvider>>,
+    default_database: Option<String>,
+    proxy: Option<Socks5Proxy>,
     connect_timeout: Option<Duration>,
 }
 
@@ -49,6 +86,9 @@ impl EstablisherOptions {
                 load_balanced: opts.load_balanced.unwrap_or(false),
             },
             tls_options: opts.tls_options(),
+            credential_provider: opts.credential_provider.clone(),
+            default_database: opts.default_database.clone(),
+            proxy: opts.socks5_proxy(),
             connect_timeout: opts.connect_timeout,
         }
     }
@@ -59,10 +99,19 @@ impl ConnectionEstablisher {
     pub(crate) fn new(http_client: HttpClient, options: EstablisherOptions) -> Result<Self> {
         let handshaker = Handshaker::new(http_client, options.handshake_options);
 
-        let tls_config = if let Some(tls_options) = options.tls_options {
-            Some(TlsConfig::new(tls_options)?)
-        } else {
-            None
+        let (tls_config, provided_tls_config) = match options.tls_options {
+            Some(tls_options) => {
+                // The configuration is built from the static options even when the identity comes
+                // from a provider so that invalid CA or CRL files are reported when the client is
+                // created rather than on each connection attempt.
+                let tls_config = TlsConfig::new(tls_options.clone())?;
+                let provided_tls_config = tls_options
+                    .identity_provider
+                    .clone()
+                    .map(|provider| ProvidedTlsConfig::new(tls_options, provider));
+                (Some(tls_config), provided_tls_config)
+            }
+            None => (None, None),
         };
 
         let connect_timeout = match options.connect_timeout {
@@ -74,16 +123,37 @@ impl ConnectionEstablisher {
         Ok(Self {
             handshaker,
             tls_config,
+            provided_tls_config,
+            credential_provider: options.credential_provider,
+            default_database: options.default_database,
+            proxy: options.proxy,
             connect_timeout,
         })
     }
 
+    /// Opens a stream to the given address, first fetching the TLS identity from its provider if
+    /// one is configured. Callers are responsible for bounding this by the connect timeout.
     async fn make_stream(&self, address: ServerAddress) -> Result<AsyncStream> {
-        runtime::timeout(
-            self.connect_timeout,
-            AsyncStream::connect(address, self.tls_config.as_ref()),
-        )
-        .await?
+        let provided_tls_config = match self.provided_tls_config {
+            Some(ref provided) => Some(provided.tls_config().await?),
+            None => None,
+        };
+        let tls_config = provided_tls_config.as_ref().or(self.tls_config.as_ref());
+
+        AsyncStream::connect(address, tls_config, self.proxy.as_ref()).await
+    }
+
+    /// Fetches the credential from the credential provider, if one is configured.
+    async fn provided_credential(&self) -> Result<Option<Credential>> {
+        match self.credential_provider {
+            Some(ref provider) => {
+                let credential = provider.credential().await?;
+                credential
+                    .resolve_provided(self.default_database.as_deref())
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
     }
 
     /// Establishes a connection.
@@ -95,10 +165,17 @@ impl ConnectionEstablisher {
         let pool_gen = pending_connection.generation.clone();
         let address = pending_connection.address.clone();
 
-        let stream = self
-            .make_stream(address)
-            .await
-            .map_err(|e| EstablishError::pre_hello(e, pool_gen.clone()))?;
+        // The providers are called within the connect timeout so that one that stalls can't
+        // block the establishment of the connection indefinitely.
+        let (provided_credential, stream) = runtime::timeout(self.connect_timeout, async {
+            let provided_credential = self.provided_credential().await?;
+            let stream = self.make_stream(address).await?;
+            Result::Ok((provided_credential, stream))
+        })
+        .await
+        .and_then(|result| result)
+        .map_err(|e| EstablishError::pre_hello(e, pool_gen.clone()))?;
+        let credential = provided_credential.as_ref().or(credential);
 
         let mut connection = Connection::new_pooled(pending_connection, stream);
         let handshake_result = self.handshaker.handshake(&mut connection, credential).await;
@@ -147,7 +224,8 @@ impl ConnectionEstablisher {
         &self,
         address: ServerAddress,
     ) -> Result<(Connection, HelloReply)> {
-        let stream = self.make_stream(address.clone()).await?;
+        let stream =
+            runtime::timeout(self.connect_timeout, self.make_stream(address.clone())).await??;
         let mut connection = Connection::new_monitoring(address, stream);
 
         let hello_reply = self.handshaker.handshake(&mut connection, None).await?;
@@ -156,6 +234,45 @@ impl ConnectionEstablisher {
     }
 }
 
+/// TLS configuration built from the identity returned by a `TlsIdentityProvider`. The
+/// configuration is cached and only rebuilt when the provider returns a different identity.
+#[derive(Clone)]
+struct ProvidedTlsConfig {
+    options: TlsOptions,
+    provider: Arc<dyn TlsIdentityProvider>,
+    cached: Arc<Mutex<Option<(TlsIdentity, TlsConfig)>>>,
+}
+
+impl ProvidedTlsConfig {
+    fn new(options: TlsOptions, provider: Arc<dyn TlsIdentityProvider>) -> Self {
+        Self {
+            options,
+            provider,
+            cached: Default::default(),
+        }
+    }
+
+    /// Returns the configuration for the identity currently returned by the provider.
+    async fn tls_config(&self) -> Result<TlsConfig> {
+        let identity = self.provider.identity().await?;
+        if let Some((ref cached_identity, ref config)) = *self.cached.lock().unwrap() {
+            if *cached_identity == identity {
+                return Ok(config.clone());
+            }
+        }
+
+        let mut options = self.options.clone();
+        options.cert_key_file_path = None;
+        options.client_certificate = Some(identity.certificate.clone());
+        options.client_key = identity.key.clone();
+        options.cert_key_password = identity.key_password.clone();
+        let config = TlsConfig::new(options)?;
+
+        *self.cached.lock().unwrap() = Some((identity, config.clone()));
+        Ok(config)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct EstablishError {
     pub(crate) cause: MongoError,
@@ -0,0 +1,90 @@
+use std::sync::{Arc, Mutex};
+
+use futures_util::future::{BoxFuture, FutureExt};
+
+use super::ProvidedTlsConfig;
+use crate::{
+    error::{Error, Result},
+    options::{TlsData, TlsIdentity, TlsIdentityProvider, TlsOptions},
+};
+
+// Self-signed certificates, each followed by its unencrypted private key.
+const CLIENT1: &[u8] = include_bytes!("testdata/client1.pem");
+const CLIENT2: &[u8] = include_bytes!("testdata/client2.pem");
+
+/// A provider that returns whichever identity it was last set to, or an error if it was cleared.
+#[derive(Default)]
+struct SwitchableIdentityProvider {
+    identity: Mutex<Option<TlsIdentity>>,
+}
+
+impl SwitchableIdentityProvider {
+    fn set(&self, pem: Option<&[u8]>) {
+        *self.identity.lock().unwrap() = pem.map(|pem| {
+            TlsIdentity::builder()
+                .certificate(TlsData::Pem(pem.to_vec()))
+                .build()
+        });
+    }
+}
+
+impl TlsIdentityProvider for SwitchableIdentityProvider {
+    fn identity(&self) -> BoxFuture<'_, Result<TlsIdentity>> {
+        let identity = self
+            .identity
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| Error::internal("identity unavailable"));
+        async move { identity }.boxed()
+    }
+}
+
+fn cached_certificate(config: &ProvidedTlsConfig) -> Option<TlsData> {
+    config
+        .cached
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|(identity, _)| identity.certificate.clone())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn provided_tls_config() {
+    let provider = Arc::new(SwitchableIdentityProvider::default());
+    let config = ProvidedTlsConfig::new(TlsOptions::default(), provider.clone());
+
+    provider.set(Some(CLIENT1));
+    config.tls_config().await.unwrap();
+    assert_eq!(
+        cached_certificate(&config),
+        Some(TlsData::Pem(CLIENT1.to_vec()))
+    );
+
+    // An unchanged identity reuses the cached configuration, which is observable by making the
+    // static options invalid: rebuilding the configuration would fail.
+    let mut invalid = config.clone();
+    invalid.options.ca_file_path = Some("/nonexistent/ca.pem".into());
+    invalid.tls_config().await.unwrap();
+
+    // A changed identity rebuilds the configuration.
+    provider.set(Some(CLIENT2));
+    invalid.tls_config().await.unwrap_err();
+    config.tls_config().await.unwrap();
+    assert_eq!(
+        cached_certificate(&config),
+        Some(TlsData::Pem(CLIENT2.to_vec()))
+    );
+
+    // A provider error only fails the connection attempt it happened during, and leaves the
+    // cached configuration in place for later ones.
+    provider.set(None);
+    config.tls_config().await.unwrap_err();
+    assert_eq!(
+        cached_certificate(&config),
+        Some(TlsData::Pem(CLIENT2.to_vec()))
+    );
+    provider.set(Some(CLIENT2));
+    config.tls_config().await.unwrap();
+}
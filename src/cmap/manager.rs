@@ -51,6 +51,20 @@ impl PoolManager {
         }
     }
 
+    /// Close the pool, waiting for its worker to close all available connections.
+    pub(super) async fn shutdown(&self) {
+        let (message, listener) = AcknowledgedMessage::package(());
+        if self
+            .sender
+            .send(PoolManagementRequest::Shutdown {
+                completion_handler: message,
+            })
+            .is_ok()
+        {
+            listener.wait_for_acknowledgment().await;
+        }
+    }
+
     /// Check in the given connection to the pool.
     /// This returns an error containing the connection if the pool has been dropped already.
     pub(crate) fn check_in(&self, connection: Connection) -> std::result::Result<(), Connection> {
@@ -78,6 +92,12 @@ impl PoolManager {
             .send(PoolManagementRequest::HandleConnectionSucceeded(conn));
     }
 
+    /// Ask the pool to establish connections until `min_pool_size` is met rather than waiting for
+    /// its next maintenance task.
+    pub(super) fn warm_up(&self) {
+        let _ = self.sender.send(PoolManagementRequest::WarmUp);
+    }
+
     /// Create a synchronization point for the pool's worker.
     #[cfg(test)]
     pub(super) fn sync_worker(&self) -> oneshot::Receiver<()> {
@@ -112,6 +132,11 @@ pub(super) enum PoolManagementRequest {
         completion_handler: AcknowledgedMessage<()>,
     },
 
+    /// Close the pool, even if handles to it are still outstanding.
+    Shutdown {
+        completion_handler: AcknowledgedMessage<()>,
+    },
+
     /// Check in the given connection.
     CheckIn(Box<Connection>),
 
@@ -122,6 +147,9 @@ pub(super) enum PoolManagementRequest {
     /// with the successful connection.
     HandleConnectionSucceeded(ConnectionSucceeded),
 
+    /// Eagerly populate the pool up to `min_pool_size`.
+    WarmUp,
+
     /// Synchronize the worker queue state with an external caller, i.e. a test.
     #[cfg(test)]
     Sync(oneshot::Sender<()>),
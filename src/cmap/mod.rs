@@ -9,14 +9,16 @@ pub(crate) mod options;
 mod status;
 mod worker;
 
+use std::time::Duration;
+
 use derivative::Derivative;
 #[cfg(test)]
 use tokio::sync::oneshot;
 
-pub use self::conn::ConnectionInfo;
+pub use self::{conn::ConnectionInfo, status::ConnectionPoolStatus};
 pub(crate) use self::{
     conn::{Command, Connection, RawCommand, RawCommandResponse, StreamDescription},
-    status::PoolGenerationSubscriber,
+    status::PoolStatusSubscriber,
     worker::PoolGeneration,
 };
 use self::{
@@ -26,7 +28,7 @@ use self::{
 };
 use crate::{
     bson::oid::ObjectId,
-    error::{Error, Result},
+    error::{Error, ErrorKind, Result},
     event::cmap::{
         CmapEvent,
         CmapEventEmitter,
@@ -36,6 +38,7 @@ use crate::{
         PoolCreatedEvent,
     },
     options::ServerAddress,
+    runtime,
     sdam::TopologyUpdater,
 };
 use connection_requester::ConnectionRequester;
@@ -56,7 +59,9 @@ pub(crate) struct ConnectionPool {
     address: ServerAddress,
     manager: PoolManager,
     connection_requester: ConnectionRequester,
-    generation_subscriber: PoolGenerationSubscriber,
+    status_subscriber: PoolStatusSubscriber,
+    min_pool_size: Option<u32>,
+    wait_queue_timeout: Option<Duration>,
 
     #[derivative(Debug = "ignore")]
     event_emitter: CmapEventEmitter,
@@ -73,10 +78,20 @@ impl ConnectionPool {
         let event_handler = options
             .as_ref()
             .and_then(|opts| opts.cmap_event_handler.clone());
+        let event_streams = options
+            .as_ref()
+            .and_then(|opts| opts.cmap_event_streams.clone());
 
-        let event_emitter = CmapEventEmitter::new(event_handler, topology_id);
+        let event_emitter = CmapEventEmitter::new(event_handler, event_streams, topology_id);
 
-        let (manager, connection_requester, generation_subscriber) = ConnectionPoolWorker::start(
+        let min_pool_size = options.as_ref().and_then(|opts| opts.min_pool_size);
+        // A zero wait queue timeout means that checkouts wait indefinitely, as with the connect
+        // timeout.
+        let wait_queue_timeout = options
+            .as_ref()
+            .and_then(|opts| opts.wait_queue_timeout)
+            .filter(|timeout| !timeout.is_zero());
+        let (manager, connection_requester, status_subscriber) = ConnectionPoolWorker::start(
             address.clone(),
             connection_establisher,
             server_updater,
@@ -95,7 +110,9 @@ impl ConnectionPool {
             address,
             manager,
             connection_requester,
-            generation_subscriber,
+            status_subscriber,
+            min_pool_size,
+            wait_queue_timeout,
             event_emitter,
         }
     }
@@ -105,20 +122,24 @@ impl ConnectionPool {
         let (manager, _) = manager::channel();
         let handle = WorkerHandle::new_mocked();
         let (connection_requester, _) = connection_requester::channel(handle);
-        let (_, generation_subscriber) = status::channel(PoolGeneration::normal());
+        let (_, status_subscriber) = status::channel(PoolGeneration::normal());
 
         Self {
             address,
             manager,
             connection_requester,
-            generation_subscriber,
-            event_emitter: CmapEventEmitter::new(None, ObjectId::new()),
+            status_subscriber,
+            min_pool_size: None,
+            wait_queue_timeout: None,
+            event_emitter: CmapEventEmitter::new(None, None, ObjectId::new()),
         }
     }
 
     /// Checks out a connection from the pool. This method will yield until this thread is at the
     /// front of the wait queue, and then will block again if no available connections are in the
-    /// pool and the total number of connections is not less than the max pool size.
+    /// pool and the total number of connections is not less than the max pool size. If a
+    /// `wait_queue_timeout` is configured and elapses before the request is serviced, a timeout
+    /// error is returned.
     pub(crate) async fn check_out(&self) -> Result<Connection> {
         self.event_emitter.emit_event(|| {
             ConnectionCheckoutStartedEvent {
@@ -127,15 +148,32 @@ impl ConnectionPool {
             .into()
         });
 
-        let response = self.connection_requester.request().await;
+        // The wait queue timeout only bounds the time spent waiting for the worker to service the
+        // request; establishing a new connection is bounded by the connect timeout instead.
+        let response = match self.wait_queue_timeout {
+            Some(timeout) => runtime::timeout(timeout, self.connection_requester.request())
+                .await
+                .ok(),
+            None => Some(self.connection_requester.request().await),
+        };
 
-        let conn = match response {
-            ConnectionRequestResult::Pooled(c) => Ok(*c),
-            ConnectionRequestResult::Establishing(task) => task.await,
-            ConnectionRequestResult::PoolCleared(e) => {
-                Err(Error::pool_cleared_error(&self.address, &e))
+        let (conn, failure_reason) = match response {
+            Some(ConnectionRequestResult::Pooled(c)) => (Ok(*c), None),
+            Some(ConnectionRequestResult::Establishing(task)) => (task.await, None),
+            Some(ConnectionRequestResult::PoolCleared(e)) => {
+                (Err(Error::pool_cleared_error(&self.address, &e)), None)
             }
+            Some(ConnectionRequestResult::PoolClosed) => (Err(ErrorKind::Shutdown.into()), None),
+            None => (
+                Err(Error::wait_queue_timeout(
+                    &self.address,
+                    self.wait_queue_timeout.unwrap_or_default(),
+                )),
+                Some(ConnectionCheckoutFailedReason::Timeout),
+            ),
         };
+        let failure_reason =
+            failure_reason.unwrap_or(ConnectionCheckoutFailedReason::ConnectionError);
 
         match conn {
             Ok(ref conn) => {
@@ -147,7 +185,7 @@ impl ConnectionPool {
                 self.event_emitter.emit_event(|| {
                     ConnectionCheckoutFailedEvent {
                         address: self.address.clone(),
-                        reason: ConnectionCheckoutFailedReason::ConnectionError,
+                        reason: failure_reason,
                         error: Some(err.clone()),
                     }
                     .into()
@@ -158,7 +196,7 @@ impl ConnectionPool {
                 self.event_emitter.emit_event(|| {
                     ConnectionCheckoutFailedEvent {
                         address: self.address.clone(),
-                        reason: ConnectionCheckoutFailedReason::ConnectionError,
+                        reason: failure_reason,
                     }
                     .into()
                 });
@@ -179,8 +217,33 @@ impl ConnectionPool {
         self.manager.mark_as_ready().await
     }
 
+    /// Close the pool regardless of any outstanding handles to it, waiting until all of its
+    /// available connections have been closed.
+    pub(crate) async fn shutdown(&self) {
+        self.manager.shutdown().await
+    }
+
     pub(crate) fn generation(&self) -> PoolGeneration {
-        self.generation_subscriber.generation()
+        self.status_subscriber.generation()
+    }
+
+    /// The latest connection counts of the pool.
+    pub(crate) fn status(&self) -> ConnectionPoolStatus {
+        self.status_subscriber.connections()
+    }
+
+    /// Eagerly establish connections until the pool manages `min_pool_size` of them, returning
+    /// once they are all established. Returns immediately if no `min_pool_size` is configured.
+    pub(crate) async fn warm_up(&self) {
+        let min_pool_size = match self.min_pool_size {
+            Some(min_pool_size) if min_pool_size > 0 => min_pool_size,
+            _ => return,
+        };
+        self.manager.warm_up();
+        self.status_subscriber
+            .clone()
+            .wait_for_established_connections(min_pool_size)
+            .await;
     }
 
     #[cfg(test)]
@@ -10,7 +10,10 @@ use serde::Deserialize;
 use crate::{
     bson_util,
     client::auth::Credential,
-    event::cmap::{CmapEventHandler, ConnectionPoolOptions as EventOptions},
+    event::{
+        cmap::{CmapEvent, CmapEventHandler, ConnectionPoolOptions as EventOptions},
+        EventStreamSenders,
+    },
     options::ClientOptions,
 };
 
@@ -28,6 +31,11 @@ pub(crate) struct ConnectionPoolOptions {
     #[serde(skip)]
     pub(crate) cmap_event_handler: Option<Arc<dyn CmapEventHandler>>,
 
+    /// The event streams to send all events generated by the pool to.
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    #[serde(skip)]
+    pub(crate) cmap_event_streams: Option<Arc<EventStreamSenders<CmapEvent>>>,
+
     /// Interval between background thread maintenance runs (e.g. ensure minPoolSize).
     #[cfg(test)]
     #[serde(rename = "backgroundThreadIntervalMS")]
@@ -55,6 +63,20 @@ pub(crate) struct ConnectionPoolOptions {
     /// The default is that no minimum is enforced
     pub(crate) min_pool_size: Option<u32>,
 
+    /// The maximum number of connections that the pool can be establishing concurrently.
+    ///
+    /// The default is 2.
+    pub(crate) max_connecting: Option<u32>,
+
+    /// The maximum amount of time a checkout request can wait for a connection to become available
+    /// before failing.
+    ///
+    /// The default is that requests will wait until the operation's server selection timeout.
+    #[serde(rename = "waitQueueTimeoutMS")]
+    #[serde(default)]
+    #[serde(deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis")]
+    pub(crate) wait_queue_timeout: Option<Duration>,
+
     /// Whether to start the pool as "ready" or not.
     /// For tests only.
     #[cfg(test)]
@@ -70,7 +92,10 @@ impl ConnectionPoolOptions {
             max_idle_time: options.max_idle_time,
             min_pool_size: options.min_pool_size,
             max_pool_size: options.max_pool_size,
+            max_connecting: options.max_connecting,
+            wait_queue_timeout: options.wait_queue_timeout,
             cmap_event_handler: options.cmap_event_handler.clone(),
+            cmap_event_streams: Some(options.event_streams.cmap.clone()),
             #[cfg(test)]
             background_thread_interval: None,
             #[cfg(test)]
@@ -85,6 +110,8 @@ impl ConnectionPoolOptions {
             max_idle_time: self.max_idle_time,
             min_pool_size: self.min_pool_size,
             max_pool_size: self.max_pool_size,
+            max_connecting: self.max_connecting,
+            wait_queue_timeout: self.wait_queue_timeout,
         }
     }
 }
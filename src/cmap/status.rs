@@ -1,48 +1,106 @@
+use serde::Serialize;
+
 use crate::cmap::PoolGeneration;
 
+/// A snapshot of the number of connections managed by a server's connection pool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ConnectionPoolStatus {
+    /// The total number of connections managed by the pool, including connections that are
+    /// checked out and connections that are still being established.
+    pub total_connections: u32,
+
+    /// The number of established connections that are checked into the pool and ready for use.
+    pub available_connections: u32,
+
+    /// The number of connections that are currently being established.
+    pub pending_connections: u32,
+}
+
+impl ConnectionPoolStatus {
+    /// The number of connections that have finished being established, i.e. connections that
+    /// are either available or checked out.
+    pub(crate) fn established_connections(&self) -> u32 {
+        self.total_connections - self.pending_connections
+    }
+}
+
 /// Struct used to track the latest status of the pool.
 #[derive(Clone, Debug)]
 struct PoolStatus {
     /// The current generation of the pool.
     generation: PoolGeneration,
+
+    /// The current connection counts of the pool.
+    connections: ConnectionPoolStatus,
 }
 
-/// Create a channel for publishing and receiving updates to the pool's generation.
-pub(super) fn channel(init: PoolGeneration) -> (PoolGenerationPublisher, PoolGenerationSubscriber) {
-    let (sender, receiver) = tokio::sync::watch::channel(PoolStatus { generation: init });
+/// Create a channel for publishing and receiving updates to the pool's status.
+pub(super) fn channel(init: PoolGeneration) -> (PoolStatusPublisher, PoolStatusSubscriber) {
+    let (sender, receiver) = tokio::sync::watch::channel(PoolStatus {
+        generation: init,
+        connections: Default::default(),
+    });
     (
-        PoolGenerationPublisher { sender },
-        PoolGenerationSubscriber { receiver },
+        PoolStatusPublisher { sender },
+        PoolStatusSubscriber { receiver },
     )
 }
 
-/// Struct used to publish updates to the pool's generation.
+/// Struct used to publish updates to the pool's status.
 #[derive(Debug)]
-pub(super) struct PoolGenerationPublisher {
+pub(super) struct PoolStatusPublisher {
     sender: tokio::sync::watch::Sender<PoolStatus>,
 }
 
-impl PoolGenerationPublisher {
+impl PoolStatusPublisher {
     /// Publish a new generation.
-    pub(super) fn publish(&self, new_generation: PoolGeneration) {
-        let new_status = PoolStatus {
-            generation: new_generation,
-        };
+    pub(super) fn publish_generation(&self, new_generation: PoolGeneration) {
+        self.sender
+            .send_modify(|status| status.generation = new_generation);
+    }
 
-        // if nobody is listening, this will return an error, which we don't mind.
-        let _: std::result::Result<_, _> = self.sender.send(new_status);
+    /// Publish new connection counts, notifying subscribers only if they changed.
+    pub(super) fn publish_connections(&self, connections: ConnectionPoolStatus) {
+        if self.sender.borrow().connections != connections {
+            self.sender
+                .send_modify(|status| status.connections = connections);
+        }
     }
 }
 
-/// Subscriber used to get the latest generation of the pool.
+/// Subscriber used to get the latest status of the pool.
 #[derive(Clone, Debug)]
-pub(crate) struct PoolGenerationSubscriber {
+pub(crate) struct PoolStatusSubscriber {
     receiver: tokio::sync::watch::Receiver<PoolStatus>,
 }
 
-impl PoolGenerationSubscriber {
+impl PoolStatusSubscriber {
     /// Get a copy of the latest generation.
     pub(crate) fn generation(&self) -> PoolGeneration {
         self.receiver.borrow().generation.clone()
     }
+
+    /// Get the latest connection counts.
+    pub(crate) fn connections(&self) -> ConnectionPoolStatus {
+        self.receiver.borrow().connections
+    }
+
+    /// Wait until the pool has established at least `count` connections. Returns `false` if the
+    /// pool was closed before that happened.
+    pub(crate) async fn wait_for_established_connections(&mut self, count: u32) -> bool {
+        while self
+            .receiver
+            .borrow_and_update()
+            .connections
+            .established_connections()
+            < count
+        {
+            if self.receiver.changed().await.is_err() {
+                return false;
+            }
+        }
+        true
+    }
 }
@@ -88,6 +88,30 @@ async fn acquire_connection_and_send_command() {
     assert!(names.iter().any(|name| name == "config"));
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn zero_wait_queue_timeout_does_not_time_out() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client_options = CLIENT_OPTIONS.get().await.clone();
+    let mut pool_options = ConnectionPoolOptions::from_client_options(&client_options);
+    pool_options.ready = Some(true);
+    pool_options.wait_queue_timeout = Some(Duration::ZERO);
+
+    let pool = ConnectionPool::new(
+        client_options.hosts[0].clone(),
+        ConnectionEstablisher::new(
+            Default::default(),
+            EstablisherOptions::from_client_options(&client_options),
+        )
+        .unwrap(),
+        TopologyUpdater::channel().0,
+        bson::oid::ObjectId::new(),
+        Some(pool_options),
+    );
+    pool.check_out().await.unwrap();
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn concurrent_connections() {
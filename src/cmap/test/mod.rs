@@ -41,9 +41,6 @@ use bson::doc;
 const TEST_DESCRIPTIONS_TO_SKIP: &[&str] = &[
     "must destroy checked in connection if pool has been closed",
     "must throw error if checkOut is called on a closed pool",
-    // WaitQueueTimeoutMS is not supported
-    "must aggressively timeout threads enqueued longer than waitQueueTimeoutMS",
-    "waiting on maxConnecting is limited by WaitQueueTimeoutMS",
     // TODO DRIVERS-1785 remove this skip when test event order is fixed
     "error during minPoolSize population clears pool",
 ];
@@ -371,6 +368,12 @@ impl Matchable for EventOptions {
         self.min_pool_size
             .matches(&expected.min_pool_size)
             .prefix("min_pool_size")?;
+        self.max_connecting
+            .matches(&expected.max_connecting)
+            .prefix("max_connecting")?;
+        self.wait_queue_timeout
+            .matches(&expected.wait_queue_timeout)
+            .prefix("wait_queue_timeout")?;
         Ok(())
     }
 }
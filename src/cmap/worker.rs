@@ -14,7 +14,7 @@ use super::{
     manager::{ConnectionSucceeded, ManagementRequestReceiver, PoolManagementRequest, PoolManager},
     options::ConnectionPoolOptions,
     status,
-    status::{PoolGenerationPublisher, PoolGenerationSubscriber},
+    status::{ConnectionPoolStatus, PoolStatusPublisher, PoolStatusSubscriber},
     Connection,
     DEFAULT_MAX_POOL_SIZE,
 };
@@ -40,7 +40,7 @@ use std::{
     time::Duration,
 };
 
-const MAX_CONNECTING: u32 = 2;
+const DEFAULT_MAX_CONNECTING: u32 = 2;
 const MAINTENACE_FREQUENCY: Duration = Duration::from_millis(500);
 
 /// A worker task that manages the shared state of the pool.
@@ -106,6 +106,11 @@ pub(crate) struct ConnectionPoolWorker {
     /// wait_queue_timeout is exceeded.
     max_pool_size: u32,
 
+    /// The maximum number of connections that the pool can be establishing concurrently. Requests
+    /// that would require a new connection beyond this limit wait until one of the pending
+    /// connections is established or a connection is checked back in.
+    max_connecting: u32,
+
     /// Receiver used to determine if any threads hold references to this pool. If all the
     /// sender ends of this receiver drop, this worker will be notified and drop too.
     handle_listener: WorkerHandleListener,
@@ -119,9 +124,12 @@ pub(crate) struct ConnectionPoolWorker {
     /// Receiver for incoming pool management requests (e.g. checking in a connection).
     management_receiver: ManagementRequestReceiver,
 
-    /// Sender used to publish the latest generation and notifications for any establishment errors
-    /// encountered by the pool.
-    generation_publisher: PoolGenerationPublisher,
+    /// Sender used to publish the latest generation and connection counts of the pool.
+    status_publisher: PoolStatusPublisher,
+
+    /// Whether a caller has asked for the pool to be populated to `min_pool_size` eagerly rather
+    /// than as part of periodic maintenance.
+    warming_up: bool,
 
     /// A pool manager that can be cloned and attached to connections checked out of the pool.
     manager: PoolManager,
@@ -141,7 +149,7 @@ impl ConnectionPoolWorker {
         server_updater: TopologyUpdater,
         event_emitter: CmapEventEmitter,
         options: Option<ConnectionPoolOptions>,
-    ) -> (PoolManager, ConnectionRequester, PoolGenerationSubscriber) {
+    ) -> (PoolManager, ConnectionRequester, PoolStatusSubscriber) {
         // The CMAP spec indicates that a max idle time of zero means that connections should not be
         // closed due to idleness.
         let mut max_idle_time = options.as_ref().and_then(|opts| opts.max_idle_time);
@@ -154,6 +162,11 @@ impl ConnectionPoolWorker {
             .and_then(|opts| opts.max_pool_size)
             .unwrap_or(DEFAULT_MAX_POOL_SIZE);
 
+        let max_connecting = options
+            .as_ref()
+            .and_then(|opts| opts.max_connecting)
+            .unwrap_or(DEFAULT_MAX_CONNECTING);
+
         let min_pool_size = options.as_ref().and_then(|opts| opts.min_pool_size);
 
         let (handle, handle_listener) = WorkerHandleListener::channel();
@@ -169,7 +182,7 @@ impl ConnectionPoolWorker {
         } else {
             PoolGeneration::normal()
         };
-        let (generation_publisher, generation_subscriber) = status::channel(generation.clone());
+        let (status_publisher, status_subscriber) = status::channel(generation.clone());
 
         #[cfg(test)]
         let mut state = if options
@@ -218,13 +231,15 @@ impl ConnectionPoolWorker {
             service_connection_count: HashMap::new(),
             available_connections: VecDeque::new(),
             max_pool_size,
+            max_connecting,
             request_receiver,
             wait_queue: Default::default(),
             management_receiver,
             manager: manager.clone(),
             handle_listener,
             state,
-            generation_publisher,
+            status_publisher,
+            warming_up: false,
             maintenance_frequency,
             server_updater,
         };
@@ -233,14 +248,15 @@ impl ConnectionPoolWorker {
             worker.execute().await;
         });
 
-        (manager, connection_requester, generation_subscriber)
+        (manager, connection_requester, status_subscriber)
     }
 
     /// Run the worker thread, listening on the various receivers until all handles have been
-    /// dropped. Once all handles are dropped, the pool will close any available connections and
-    /// emit a pool closed event.
+    /// dropped or the pool is shut down. Once that happens, the pool will close any available
+    /// connections and emit a pool closed event.
     async fn execute(mut self) {
         let mut maintenance_interval = runtime::interval(self.maintenance_frequency);
+        let mut shutdown_handler = None;
 
         loop {
             let task = tokio::select! {
@@ -286,6 +302,10 @@ impl ConnectionPoolWorker {
                     }
                 },
                 PoolTask::HandleManagementRequest(request) => match *request {
+                    PoolManagementRequest::Shutdown { completion_handler } => {
+                        shutdown_handler = Some(completion_handler);
+                        break;
+                    }
                     PoolManagementRequest::CheckIn(connection) => {
                         self.check_in(*connection);
                     }
@@ -304,6 +324,12 @@ impl ConnectionPoolWorker {
                     PoolManagementRequest::HandleConnectionFailed => {
                         self.handle_connection_failed();
                     }
+                    PoolManagementRequest::WarmUp => {
+                        self.warming_up = true;
+                        if matches!(self.state, PoolState::Ready) {
+                            self.ensure_min_connections();
+                        }
+                    }
                     #[cfg(test)]
                     PoolManagementRequest::Sync(tx) => {
                         let _ = tx.send(());
@@ -319,6 +345,13 @@ impl ConnectionPoolWorker {
                     self.check_out(request);
                 }
             }
+
+            self.status_publisher
+                .publish_connections(ConnectionPoolStatus {
+                    total_connections: self.total_connection_count,
+                    available_connections: self.available_connections.len() as u32,
+                    pending_connections: self.pending_connection_count,
+                });
         }
 
         while let Some(connection) = self.available_connections.pop_front() {
@@ -331,6 +364,10 @@ impl ConnectionPoolWorker {
             }
             .into()
         });
+
+        if let Some(handler) = shutdown_handler {
+            handler.acknowledge(());
+        }
     }
 
     fn below_max_connections(&self) -> bool {
@@ -346,7 +383,7 @@ impl ConnectionPoolWorker {
             return true;
         }
 
-        self.below_max_connections() && self.pending_connection_count < MAX_CONNECTING
+        self.below_max_connections() && self.pending_connection_count < self.max_connecting
     }
 
     fn check_out(&mut self, request: ConnectionRequest) {
@@ -440,6 +477,7 @@ impl ConnectionPoolWorker {
         // connection count.
         self.total_connection_count -= 1;
         self.pending_connection_count -= 1;
+        self.continue_warm_up();
     }
 
     /// Process a successful connection establishment, optionally populating the pool with the
@@ -455,6 +493,15 @@ impl ConnectionPoolWorker {
             connection.mark_as_available();
             self.available_connections.push_back(connection);
         }
+        self.continue_warm_up();
+    }
+
+    /// If a warm-up was requested, keep establishing connections until `min_pool_size` is met
+    /// instead of waiting for the next maintenance task.
+    fn continue_warm_up(&mut self) {
+        if self.warming_up && matches!(self.state, PoolState::Ready) {
+            self.ensure_min_connections();
+        }
     }
 
     fn check_in(&mut self, mut conn: Connection) {
@@ -488,7 +535,8 @@ impl ConnectionPoolWorker {
             }
             (..) => load_balanced_mode_mismatch!(),
         };
-        self.generation_publisher.publish(self.generation.clone());
+        self.status_publisher
+            .publish_generation(self.generation.clone());
 
         if was_ready {
             self.event_emitter.emit_event(|| {
@@ -577,8 +625,11 @@ impl ConnectionPoolWorker {
     /// Populate the the pool with enough connections to meet the min_pool_size_requirement.
     fn ensure_min_connections(&mut self) {
         if let Some(min_pool_size) = self.min_pool_size {
+            if self.total_connection_count >= min_pool_size {
+                self.warming_up = false;
+            }
             while self.total_connection_count < min_pool_size
-                && self.pending_connection_count < MAX_CONNECTING
+                && self.pending_connection_count < self.max_connecting
             {
                 let pending_connection = self.create_pending_connection();
                 let event_handler = self.event_emitter.clone();
@@ -0,0 +1,192 @@
+use crate::{
+    bson::Document,
+    bson_util,
+    coll::{options::*, Collection},
+    error::Result,
+    operation::{
+        Aggregate,
+        Count,
+        CountDocuments,
+        Delete,
+        Distinct,
+        Explain,
+        Find,
+        Operation,
+        Update,
+    },
+    results::ExplainResult,
+};
+
+/// A handle for explaining operations on a [`Collection`], obtained via
+/// [`Collection::explain`].
+///
+/// Each method builds the exact command the corresponding [`Collection`] method would send and
+/// runs it wrapped in an `explain` command, returning the server's description of how the
+/// operation would be (or was) executed rather than its result. Explained write operations do not
+/// modify any data.
+///
+/// ```rust
+/// # use mongodb::{bson::{doc, Document}, error::Result, options::ExplainVerbosity, Collection};
+/// # async fn run(coll: Collection<Document>) -> Result<()> {
+/// let result = coll
+///     .explain(ExplainVerbosity::ExecutionStats)
+///     .find(doc! { "x": 1 }, None)
+///     .await?;
+/// assert_eq!(result.indexes_used(), vec!["x_1".to_string()]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CollectionExplain<'a, T> {
+    coll: &'a Collection<T>,
+    verbosity: ExplainVerbosity,
+}
+
+impl<'a, T> CollectionExplain<'a, T> {
+    pub(super) fn new(coll: &'a Collection<T>, verbosity: ExplainVerbosity) -> Self {
+        Self { coll, verbosity }
+    }
+
+    async fn execute(&self, op: impl Operation<Command = Document>) -> Result<ExplainResult> {
+        self.coll
+            .client()
+            .execute_operation(Explain::new(op, self.verbosity), None)
+            .await
+    }
+
+    /// Explains a [`Collection::find`] operation.
+    pub async fn find(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<FindOptions>>,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self.coll, options, [selection_criteria, timeout]);
+
+        let find = Find::new(self.coll.namespace(), filter.into(), options);
+        self.execute(find).await
+    }
+
+    /// Explains a [`Collection::aggregate`] operation.
+    pub async fn aggregate(
+        &self,
+        pipeline: impl IntoIterator<Item = Document>,
+        options: impl Into<Option<AggregateOptions>>,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self.coll, options, [selection_criteria, timeout]);
+
+        let aggregate = Aggregate::new(self.coll.namespace(), pipeline, options);
+        self.execute(aggregate).await
+    }
+
+    /// Explains a [`Collection::count_documents`] operation.
+    pub async fn count_documents(
+        &self,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<CountOptions>>,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self.coll, options, [selection_criteria, timeout]);
+
+        let op = CountDocuments::new(self.coll.namespace(), filter.into(), options)?;
+        self.execute(op).await
+    }
+
+    /// Explains a [`Collection::estimated_document_count`] operation.
+    pub async fn estimated_document_count(
+        &self,
+        options: impl Into<Option<EstimatedDocumentCountOptions>>,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self.coll, options, [selection_criteria, timeout]);
+
+        let op = Count::new(self.coll.namespace(), options);
+        self.execute(op).await
+    }
+
+    /// Explains a [`Collection::distinct`] operation.
+    pub async fn distinct(
+        &self,
+        field_name: impl AsRef<str>,
+        filter: impl Into<Option<Document>>,
+        options: impl Into<Option<DistinctOptions>>,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self.coll, options, [selection_criteria, timeout]);
+
+        let op = Distinct::new(
+            self.coll.namespace(),
+            field_name.as_ref().to_string(),
+            filter.into(),
+            options,
+        );
+        self.execute(op).await
+    }
+
+    async fn update_common(
+        &self,
+        query: Document,
+        update: UpdateModifications,
+        multi: bool,
+        options: Option<UpdateOptions>,
+    ) -> Result<ExplainResult> {
+        if let UpdateModifications::Document(ref d) = update {
+            bson_util::update_document_check(d)?;
+        }
+
+        let mut options = options;
+        resolve_options!(self.coll, options, [timeout]);
+
+        let update = Update::new(self.coll.namespace(), query, update, multi, options);
+        self.execute(update).await
+    }
+
+    /// Explains a [`Collection::update_one`] operation.
+    pub async fn update_one(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+    ) -> Result<ExplainResult> {
+        self.update_common(query, update.into(), false, options.into())
+            .await
+    }
+
+    /// Explains a [`Collection::update_many`] operation.
+    pub async fn update_many(
+        &self,
+        query: Document,
+        update: impl Into<UpdateModifications>,
+        options: impl Into<Option<UpdateOptions>>,
+    ) -> Result<ExplainResult> {
+        self.update_common(query, update.into(), true, options.into())
+            .await
+    }
+
+    /// Explains a [`Collection::delete_one`] operation.
+    pub async fn delete_one(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self.coll, options, [timeout]);
+
+        let delete = Delete::new(self.coll.namespace(), query, Some(1), options);
+        self.execute(delete).await
+    }
+
+    /// Explains a [`Collection::delete_many`] operation.
+    pub async fn delete_many(
+        &self,
+        query: Document,
+        options: impl Into<Option<DeleteOptions>>,
+    ) -> Result<ExplainResult> {
+        let mut options = options.into();
+        resolve_options!(self.coll, options, [timeout]);
+
+        let delete = Delete::new(self.coll.namespace(), query, None, options);
+        self.execute(delete).await
+    }
+}
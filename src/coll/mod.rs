@@ -1,6 +1,7 @@
+mod explain;
 pub mod options;
 
-use std::{borrow::Borrow, collections::HashSet, fmt, fmt::Debug, sync::Arc};
+use std::{borrow::Borrow, collections::HashSet, fmt, fmt::Debug, sync::Arc, time::Duration};
 
 use futures_util::{
     future,
@@ -13,6 +14,7 @@ use serde::{
     Serialize,
 };
 
+pub use self::explain::CollectionExplain;
 use self::options::*;
 use crate::{
     bson::{doc, to_document, Bson, Document},
@@ -26,24 +28,41 @@ use crate::{
     client::options::ServerAddress,
     cmap::conn::PinnedConnectionHandle,
     concern::{ReadConcern, WriteConcern},
-    error::{convert_bulk_errors, BulkWriteError, BulkWriteFailure, Error, ErrorKind, Result},
+    error::{
+        convert_bulk_errors,
+        BulkWriteError,
+        BulkWriteFailure,
+        Error,
+        ErrorKind,
+        Result,
+        WriteConcernError,
+    },
     index::IndexModel,
     operation::{
+        write_statement,
         Aggregate,
+        BatchKind,
+        BulkWrite,
         Count,
         CountDocuments,
         CreateIndexes,
+        CreateSearchIndexes,
         Delete,
         Distinct,
         DropCollection,
         DropIndexes,
+        DropSearchIndex,
         Find,
         FindAndModify,
         Insert,
         ListIndexes,
+        RenameCollection,
         Update,
+        UpdateSearchIndex,
     },
     results::{
+        BulkWriteResult,
+        CollectionStats,
         CreateIndexResult,
         CreateIndexesResult,
         DeleteResult,
@@ -51,6 +70,15 @@ use crate::{
         InsertOneResult,
         UpdateResult,
     },
+    search_index::{
+        options::{
+            CreateSearchIndexOptions,
+            DropSearchIndexOptions,
+            ListSearchIndexOptions,
+            UpdateSearchIndexOptions,
+        },
+        SearchIndexModel,
+    },
     selection_criteria::SelectionCriteria,
     Client,
     ClientSession,
@@ -143,6 +171,7 @@ struct CollectionInner {
     selection_criteria: Option<SelectionCriteria>,
     read_concern: Option<ReadConcern>,
     write_concern: Option<WriteConcern>,
+    timeout: Option<Duration>,
 }
 
 impl<T> Collection<T> {
@@ -158,6 +187,8 @@ impl<T> Collection<T> {
             .write_concern
             .or_else(|| db.write_concern().cloned());
 
+        let timeout = options.timeout.or_else(|| db.timeout());
+
         Self {
             inner: Arc::new(CollectionInner {
                 client: db.client().clone(),
@@ -166,6 +197,7 @@ impl<T> Collection<T> {
                 selection_criteria,
                 read_concern,
                 write_concern,
+                timeout,
             }),
             _phantom: Default::default(),
         }
@@ -217,6 +249,11 @@ impl<T> Collection<T> {
         self.inner.write_concern.as_ref()
     }
 
+    /// Gets the client-side operation timeout of the `Collection`.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout
+    }
+
     #[allow(clippy::needless_option_as_deref)]
     async fn drop_common(
         &self,
@@ -226,7 +263,7 @@ impl<T> Collection<T> {
         let mut session = session.into();
 
         let mut options: Option<DropCollectionOptions> = options.into();
-        resolve_options!(self, options, [write_concern]);
+        resolve_options!(self, options, [write_concern, timeout]);
 
         #[cfg(feature = "in-use-encryption-unstable")]
         self.drop_aux_collections(options.as_ref(), session.as_deref_mut())
@@ -253,6 +290,102 @@ impl<T> Collection<T> {
         self.drop_common(options, session).await
     }
 
+    /// Gets statistics for the collection using the `$collStats` aggregation stage. When run
+    /// against a sharded cluster, the statistics are totaled across all shards.
+    ///
+    /// See the documentation
+    /// [here](https://www.mongodb.com/docs/manual/reference/operator/aggregation/collStats/) for
+    /// more information on the reported statistics.
+    pub async fn stats(
+        &self,
+        options: impl Into<Option<CollectionStatsOptions>>,
+    ) -> Result<CollectionStats> {
+        let options = options.into().unwrap_or_default();
+
+        let mut stage = Document::new();
+        if options.storage_stats.unwrap_or(true) {
+            let mut storage_stats = Document::new();
+            if let Some(scale) = options.scale {
+                storage_stats.insert("scale", i64::from(scale));
+            }
+            stage.insert("storageStats", storage_stats);
+        }
+        if options.latency_stats.unwrap_or(false) {
+            stage.insert("latencyStats", Document::new());
+        }
+        if options.count.unwrap_or(false) {
+            stage.insert("count", Document::new());
+        }
+
+        let aggregate_options = AggregateOptions::builder()
+            .selection_criteria(options.selection_criteria)
+            .timeout(options.timeout)
+            .build();
+        let docs: Vec<Document> = self
+            .aggregate([doc! { "$collStats": stage }], aggregate_options)
+            .await?
+            .try_collect()
+            .await?;
+        CollectionStats::from_documents(docs)
+    }
+
+    async fn rename_common(
+        &self,
+        new_namespace: Namespace,
+        options: impl Into<Option<RenameCollectionOptions>>,
+        session: impl Into<Option<&mut ClientSession>>,
+    ) -> Result<Collection<T>> {
+        let session = session.into();
+
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+        resolve_write_concern_with_session!(self, options, session.as_ref())?;
+
+        let rename = RenameCollection::new(self.namespace(), new_namespace.clone(), options);
+        self.client().execute_operation(rename, session).await?;
+
+        let db = if new_namespace.db == self.inner.db.name() {
+            self.inner.db.clone()
+        } else {
+            self.client().database(&new_namespace.db)
+        };
+        let options = CollectionOptions::builder()
+            .selection_criteria(self.inner.selection_criteria.clone())
+            .read_concern(self.inner.read_concern.clone())
+            .write_concern(self.inner.write_concern.clone())
+            .timeout(self.inner.timeout)
+            .build();
+        Ok(Collection::new(db, &new_namespace.coll, Some(options)))
+    }
+
+    /// Renames the collection to `new_namespace`, which may be in a different database, and
+    /// returns a handle to the renamed collection with the same options as this one.
+    ///
+    /// If a collection already exists at `new_namespace`, this operation will fail unless
+    /// [`RenameCollectionOptions::drop_target`] is set.
+    pub async fn rename(
+        &self,
+        new_namespace: Namespace,
+        options: impl Into<Option<RenameCollectionOptions>>,
+    ) -> Result<Collection<T>> {
+        self.rename_common(new_namespace, options, None).await
+    }
+
+    /// Renames the collection to `new_namespace`, which may be in a different database, using the
+    /// provided `ClientSession`, and returns a handle to the renamed collection with the same
+    /// options as this one.
+    ///
+    /// If a collection already exists at `new_namespace`, this operation will fail unless
+    /// [`RenameCollectionOptions::drop_target`] is set.
+    pub async fn rename_with_session(
+        &self,
+        new_namespace: Namespace,
+        options: impl Into<Option<RenameCollectionOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Collection<T>> {
+        self.rename_common(new_namespace, options, session).await
+    }
+
     #[cfg(feature = "in-use-encryption-unstable")]
     #[allow(clippy::needless_option_as_deref)]
     async fn drop_aux_collections(
@@ -314,6 +447,14 @@ impl<T> Collection<T> {
         Ok(())
     }
 
+    /// Returns a handle for explaining operations on this collection with the given verbosity.
+    ///
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/reference/command/explain/)
+    /// for more information on explaining operations.
+    pub fn explain(&self, verbosity: ExplainVerbosity) -> CollectionExplain<'_, T> {
+        CollectionExplain::new(self, verbosity)
+    }
+
     /// Runs an aggregation operation.
     ///
     /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
@@ -327,7 +468,7 @@ impl<T> Collection<T> {
         resolve_options!(
             self,
             options,
-            [read_concern, write_concern, selection_criteria]
+            [read_concern, write_concern, selection_criteria, timeout]
         );
 
         let aggregate = Aggregate::new(self.namespace(), pipeline, options);
@@ -346,6 +487,7 @@ impl<T> Collection<T> {
         session: &mut ClientSession,
     ) -> Result<SessionCursor<Document>> {
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_write_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
@@ -373,7 +515,7 @@ impl<T> Collection<T> {
         options: impl Into<Option<EstimatedDocumentCountOptions>>,
     ) -> Result<u64> {
         let mut options = options.into();
-        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
 
         let op = Count::new(self.namespace(), options);
 
@@ -389,6 +531,7 @@ impl<T> Collection<T> {
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_read_concern_with_session!(self, options, session.as_ref())?;
         resolve_selection_criteria_with_session!(self, options, session.as_ref())?;
 
@@ -430,6 +573,7 @@ impl<T> Collection<T> {
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let delete = Delete::new(self.namespace(), query, None, options);
@@ -445,6 +589,7 @@ impl<T> Collection<T> {
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let indexes: Vec<IndexModel> = indexes.into_iter().collect();
@@ -534,6 +679,7 @@ impl<T> Collection<T> {
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let delete = Delete::new(self.namespace(), query, Some(1), options);
@@ -579,6 +725,7 @@ impl<T> Collection<T> {
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_read_concern_with_session!(self, options, session.as_ref())?;
         resolve_selection_criteria_with_session!(self, options, session.as_ref())?;
 
@@ -624,6 +771,7 @@ impl<T> Collection<T> {
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         // If there is no provided name, that means we should drop all indexes.
@@ -689,7 +837,10 @@ impl<T> Collection<T> {
         &self,
         options: impl Into<Option<ListIndexesOptions>>,
     ) -> Result<Cursor<IndexModel>> {
-        let list_indexes = ListIndexes::new(self.namespace(), options.into());
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let list_indexes = ListIndexes::new(self.namespace(), options);
         let client = self.client();
         client.execute_cursor_operation(list_indexes).await
     }
@@ -700,7 +851,10 @@ impl<T> Collection<T> {
         options: impl Into<Option<ListIndexesOptions>>,
         session: &mut ClientSession,
     ) -> Result<SessionCursor<IndexModel>> {
-        let list_indexes = ListIndexes::new(self.namespace(), options.into());
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let list_indexes = ListIndexes::new(self.namespace(), options);
         let client = self.client();
         client
             .execute_session_cursor_operation(list_indexes, session)
@@ -732,6 +886,96 @@ impl<T> Collection<T> {
         self.list_index_names_common(cursor.stream(session)).await
     }
 
+    /// Creates the given Atlas Search index on this collection, returning the name of the created
+    /// index.
+    ///
+    /// Note that search indexes are built asynchronously by the server; the index may not be
+    /// queryable immediately after this method returns.
+    pub async fn create_search_index(
+        &self,
+        model: SearchIndexModel,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<String> {
+        let mut names = self.create_search_indexes(Some(model), options).await?;
+        match names.len() {
+            1 => Ok(names.pop().unwrap()),
+            n => Err(Error::internal(format!("expected 1 index name, got {}", n))),
+        }
+    }
+
+    /// Creates the given Atlas Search indexes on this collection, returning the names of the
+    /// created indexes.
+    pub async fn create_search_indexes(
+        &self,
+        models: impl IntoIterator<Item = SearchIndexModel>,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<Vec<String>> {
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let op = CreateSearchIndexes::new(self.namespace(), models.into_iter().collect(), options);
+        self.client().execute_operation(op, None).await
+    }
+
+    /// Updates the definition of the Atlas Search index with the given name on this collection.
+    pub async fn update_search_index(
+        &self,
+        name: impl AsRef<str>,
+        definition: Document,
+        options: impl Into<Option<UpdateSearchIndexOptions>>,
+    ) -> Result<()> {
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let op = UpdateSearchIndex::new(
+            self.namespace(),
+            name.as_ref().to_string(),
+            definition,
+            options,
+        );
+        self.client().execute_operation(op, None).await
+    }
+
+    /// Drops the Atlas Search index with the given name from this collection.
+    pub async fn drop_search_index(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropSearchIndexOptions>>,
+    ) -> Result<()> {
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let op = DropSearchIndex::new(self.namespace(), name.as_ref().to_string(), options);
+        self.client().execute_operation(op, None).await
+    }
+
+    /// Lists the Atlas Search indexes on this collection using the `$listSearchIndexes`
+    /// aggregation stage. If `name` is specified, only the index with that name is returned.
+    pub async fn list_search_indexes(
+        &self,
+        name: impl Into<Option<&str>>,
+        options: impl Into<Option<ListSearchIndexOptions>>,
+    ) -> Result<Cursor<SearchIndexModel>> {
+        let mut stage = Document::new();
+        if let Some(name) = name.into() {
+            stage.insert("name", name);
+        }
+        let aggregate_options = options.into().map(|options| {
+            AggregateOptions::builder()
+                .batch_size(options.batch_size)
+                .timeout(options.timeout)
+                .build()
+        });
+
+        let cursor = self
+            .aggregate(
+                vec![doc! { "$listSearchIndexes": stage }],
+                aggregate_options,
+            )
+            .await?;
+        Ok(cursor.with_type())
+    }
+
     async fn update_many_common(
         &self,
         query: Document,
@@ -748,6 +992,7 @@ impl<T> Collection<T> {
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let update = Update::new(self.namespace(), query, update, true, options);
@@ -801,6 +1046,7 @@ impl<T> Collection<T> {
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let update = Update::new(self.namespace(), query, update, false, options);
@@ -901,7 +1147,7 @@ impl<T> Collection<T> {
         T: DeserializeOwned + Unpin + Send + Sync,
     {
         let mut options = options.into();
-        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
         let target = self.namespace().into();
         self.client()
             .execute_watch(pipeline, options, target, None)
@@ -920,6 +1166,7 @@ impl<T> Collection<T> {
         T: DeserializeOwned + Unpin + Send + Sync,
     {
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
         let target = self.namespace().into();
@@ -935,7 +1182,7 @@ impl<T> Collection<T> {
         options: impl Into<Option<FindOptions>>,
     ) -> Result<Cursor<T>> {
         let mut options = options.into();
-        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
 
         let find = Find::new(self.namespace(), filter.into(), options);
         let client = self.client();
@@ -951,6 +1198,7 @@ impl<T> Collection<T> {
         session: &mut ClientSession,
     ) -> Result<SessionCursor<T>> {
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
 
@@ -972,7 +1220,7 @@ where
         options: impl Into<Option<FindOneOptions>>,
     ) -> Result<Option<T>> {
         let mut options = options.into();
-        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
 
         let options: FindOptions = options.map(Into::into).unwrap_or_else(Default::default);
         let mut cursor = self.find(filter, Some(options)).await?;
@@ -988,6 +1236,7 @@ where
         session: &mut ClientSession,
     ) -> Result<Option<T>> {
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
 
@@ -1013,6 +1262,7 @@ where
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let op = FindAndModify::<T>::with_delete(self.namespace(), filter, options);
@@ -1062,6 +1312,7 @@ where
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let op = FindAndModify::<T>::with_update(self.namespace(), filter, update, options)?;
@@ -1124,6 +1375,7 @@ where
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let op = FindAndModify::<T>::with_replace(self.namespace(), filter, replacement, options)?;
@@ -1179,6 +1431,7 @@ where
     ) -> Result<InsertManyResult> {
         let ds: Vec<_> = docs.into_iter().collect();
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         if ds.is_empty() {
@@ -1198,6 +1451,10 @@ where
         let mut error_labels: HashSet<String> = Default::default();
         let mut cumulative_result: Option<InsertManyResult> = None;
 
+        // Every batch shares a single deadline so that the timeout covers the whole call.
+        let deadline = self
+            .client()
+            .operation_deadline(options.as_ref().and_then(|o| o.timeout));
         let mut n_attempted = 0;
 
         while n_attempted < ds.len() {
@@ -1206,7 +1463,7 @@ where
 
             match self
                 .client()
-                .execute_operation(insert, session.as_deref_mut())
+                .execute_operation_with_deadline(insert, session.as_deref_mut(), deadline)
                 .await
             {
                 Ok(result) => {
@@ -1323,6 +1580,7 @@ where
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let insert = Insert::new(
@@ -1386,6 +1644,7 @@ where
         let session = session.into();
 
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_write_concern_with_session!(self, options, session.as_ref())?;
 
         let update = Update::new(
@@ -1431,6 +1690,253 @@ where
         self.replace_one_common(query, replacement, options, session)
             .await
     }
+
+    #[allow(clippy::needless_option_as_deref)]
+    async fn bulk_write_common(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        mut session: Option<&mut ClientSession>,
+    ) -> Result<BulkWriteResult> {
+        let models: Vec<WriteModel<T>> = models.into_iter().collect();
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+        resolve_write_concern_with_session!(self, options, session.as_ref())?;
+
+        if models.is_empty() {
+            return Err(ErrorKind::InvalidArgument {
+                message: "No write models provided to bulk_write".to_string(),
+            }
+            .into());
+        }
+
+        // The insert command does not support "let", so it can't be applied to insert models.
+        if options.as_ref().map_or(false, |o| o.let_vars.is_some())
+            && models.iter().any(|m| matches!(m, WriteModel::InsertOne(_)))
+        {
+            return Err(ErrorKind::InvalidArgument {
+                message: "the let option cannot be used with insert models in bulk_write"
+                    .to_string(),
+            }
+            .into());
+        }
+
+        let ordered = options.as_ref().and_then(|o| o.ordered).unwrap_or(true);
+        #[cfg(feature = "in-use-encryption-unstable")]
+        let encrypted = self.client().auto_encryption_opts().await.is_some();
+        #[cfg(not(feature = "in-use-encryption-unstable"))]
+        let encrypted = false;
+
+        // Every batch shares a single deadline so that the timeout covers the whole call.
+        let deadline = self
+            .client()
+            .operation_deadline(options.as_ref().and_then(|o| o.timeout));
+        let mut result = BulkWriteResult::default();
+        let mut cumulative_failure: Option<BulkWriteFailure> = None;
+        let mut error_labels: HashSet<String> = Default::default();
+
+        let mut group_start = 0;
+        while group_start < models.len() {
+            // Consecutive models of the same kind are sent to the server together.
+            let kind = BatchKind::of(&models[group_start]);
+            let group_len = models[group_start..]
+                .iter()
+                .take_while(|m| BatchKind::of(m) == kind)
+                .count();
+            let group = &models[group_start..group_start + group_len];
+
+            match kind {
+                None => {
+                    let docs: Vec<&T> = group
+                        .iter()
+                        .filter_map(|m| match m {
+                            WriteModel::InsertOne(m) => Some(&m.document),
+                            _ => None,
+                        })
+                        .collect();
+                    let insert_options = options.as_ref().map(|o| o.to_insert_many_options());
+
+                    let mut n_attempted = 0;
+                    while n_attempted < docs.len() {
+                        let insert = Insert::new_encrypted(
+                            self.namespace(),
+                            docs[n_attempted..].to_vec(),
+                            insert_options.clone(),
+                            encrypted,
+                        );
+                        let offset = group_start + n_attempted;
+                        match self
+                            .client()
+                            .execute_operation_with_deadline(
+                                insert,
+                                session.as_deref_mut(),
+                                deadline,
+                            )
+                            .await
+                        {
+                            Ok(insert_result) => {
+                                n_attempted += insert_result.inserted_ids.len();
+                                result.inserted_count += insert_result.inserted_ids.len() as u64;
+                                for (index, id) in insert_result.inserted_ids {
+                                    result.inserted_ids.insert(index + offset, id);
+                                }
+                            }
+                            Err(e) => {
+                                let labels = e.labels().clone();
+                                match *e.kind {
+                                    ErrorKind::BulkWrite(bw) => {
+                                        let n_errors =
+                                            bw.write_errors.as_ref().map(Vec::len).unwrap_or(0);
+                                        let current_batch_size = bw.inserted_ids.len() + n_errors;
+                                        result.inserted_count += bw.inserted_ids.len() as u64;
+                                        for (index, id) in bw.inserted_ids {
+                                            result.inserted_ids.insert(index + offset, id);
+                                        }
+                                        merge_bulk_failure(
+                                            &mut cumulative_failure,
+                                            bw.write_errors,
+                                            bw.write_concern_error,
+                                            offset,
+                                        );
+                                        error_labels.extend(labels);
+                                        if ordered && n_errors > 0 {
+                                            break;
+                                        }
+                                        n_attempted += current_batch_size;
+                                    }
+                                    _ => return Err(e),
+                                }
+                            }
+                        }
+                    }
+                }
+                Some(kind) => {
+                    let statements = group
+                        .iter()
+                        .map(write_statement)
+                        .collect::<Result<Vec<_>>>()?;
+
+                    let mut n_attempted = 0;
+                    while n_attempted < statements.len() {
+                        let batch = BulkWrite::new(
+                            self.namespace(),
+                            kind,
+                            &statements[n_attempted..],
+                            options.clone(),
+                        );
+                        let offset = group_start + n_attempted;
+                        let batch_result = self
+                            .client()
+                            .execute_operation_with_deadline(
+                                batch,
+                                session.as_deref_mut(),
+                                deadline,
+                            )
+                            .await?;
+
+                        match kind {
+                            BatchKind::Update => {
+                                let upserted_count = batch_result.upserted.len() as u64;
+                                result.upserted_count += upserted_count;
+                                result.matched_count +=
+                                    batch_result.n.saturating_sub(upserted_count);
+                                result.modified_count += batch_result.n_modified;
+                                for (index, id) in batch_result.upserted {
+                                    result.upserted_ids.insert(index + offset, id);
+                                }
+                            }
+                            BatchKind::Delete => result.deleted_count += batch_result.n,
+                        }
+
+                        let has_write_errors = batch_result.write_errors.is_some();
+                        if has_write_errors || batch_result.write_concern_error.is_some() {
+                            merge_bulk_failure(
+                                &mut cumulative_failure,
+                                batch_result.write_errors,
+                                batch_result.write_concern_error,
+                                offset,
+                            );
+                            error_labels.extend(batch_result.labels.into_iter().flatten());
+                            if ordered && has_write_errors {
+                                break;
+                            }
+                        }
+                        n_attempted += batch_result.n_attempted;
+                    }
+                }
+            }
+
+            if ordered
+                && cumulative_failure
+                    .as_ref()
+                    .map_or(false, |f| f.write_errors.is_some())
+            {
+                break;
+            }
+            group_start += group_len;
+        }
+
+        match cumulative_failure {
+            Some(mut failure) => {
+                failure.inserted_ids = result.inserted_ids.clone();
+                failure.partial_result = Some(result);
+                Err(Error::new(
+                    ErrorKind::BulkWrite(failure),
+                    Some(error_labels),
+                ))
+            }
+            None => Ok(result),
+        }
+    }
+
+    /// Executes the given write models against the collection, grouping consecutive models of the
+    /// same kind into as few `insert`, `update` and `delete` commands as possible.
+    ///
+    /// If `ordered` is true (the default), execution stops at the first write error; otherwise
+    /// all models are attempted. Write errors from every batch are reported in a single
+    /// [`BulkWriteFailure`](crate::error::BulkWriteFailure), with indexes relative to `models`,
+    /// whose `partial_result` contains the outcome of the writes that were performed.
+    pub async fn bulk_write(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+    ) -> Result<BulkWriteResult> {
+        self.bulk_write_common(models, options, None).await
+    }
+
+    /// Executes the given write models against the collection using the provided
+    /// `ClientSession`. See [`Collection::bulk_write`] for more details.
+    pub async fn bulk_write_with_session(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<BulkWriteResult> {
+        self.bulk_write_common(models, options, Some(session)).await
+    }
+}
+
+/// Adds the errors from a single batch of a bulk write to the cumulative failure, offsetting their
+/// indexes by the position of the batch within the full list of models.
+fn merge_bulk_failure(
+    cumulative_failure: &mut Option<BulkWriteFailure>,
+    write_errors: Option<Vec<BulkWriteError>>,
+    write_concern_error: Option<WriteConcernError>,
+    offset: usize,
+) {
+    let failure = cumulative_failure.get_or_insert_with(BulkWriteFailure::new);
+    if let Some(write_errors) = write_errors {
+        for err in write_errors {
+            let index = offset + err.index;
+            failure
+                .write_errors
+                .get_or_insert_with(Default::default)
+                .push(BulkWriteError { index, ..err });
+        }
+    }
+    if let Some(wc_error) = write_concern_error {
+        failure.write_concern_error = Some(wc_error);
+    }
 }
 
 /// A struct modeling the canonical name for a collection in MongoDB.
@@ -28,6 +28,15 @@ pub struct CollectionOptions {
 
     /// The default write concern for operations.
     pub write_concern: Option<WriteConcern>,
+
+    /// The default client-side timeout for operations. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies whether a
@@ -77,6 +86,34 @@ impl Hint {
     }
 }
 
+/// Specifies the amount of information returned by an explained operation. See the
+/// [documentation](https://www.mongodb.com/docs/manual/reference/command/explain/#verbosity-modes)
+/// for more information.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ExplainVerbosity {
+    /// Return the plan selected by the query optimizer without executing it.
+    QueryPlanner,
+
+    /// Execute the winning plan and return statistics describing its execution.
+    ExecutionStats,
+
+    /// Execute the winning plan and return statistics describing its execution, along with
+    /// partial execution statistics for the rejected plans.
+    AllPlansExecution,
+}
+
+impl ExplainVerbosity {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::QueryPlanner => "queryPlanner",
+            Self::ExecutionStats => "executionStats",
+            Self::AllPlansExecution => "allPlansExecution",
+        }
+    }
+}
+
 /// Specifies the type of cursor to return from a find operation.
 #[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
@@ -112,6 +149,17 @@ pub struct InsertOneOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -140,6 +188,17 @@ pub struct InsertManyOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 impl InsertManyOptions {
@@ -149,6 +208,7 @@ impl InsertManyOptions {
             ordered: None,
             write_concern: options.write_concern,
             comment: options.comment,
+            timeout: options.timeout,
         }
     }
 }
@@ -240,6 +300,17 @@ pub struct UpdateOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 impl UpdateOptions {
@@ -252,6 +323,7 @@ impl UpdateOptions {
             collation: options.collation,
             let_vars: options.let_vars,
             comment: options.comment,
+            timeout: options.timeout,
             ..Default::default()
         }
     }
@@ -299,6 +371,17 @@ pub struct ReplaceOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -336,6 +419,262 @@ pub struct DeleteOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
+/// A single write to be performed as part of a
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum WriteModel<T> {
+    /// Inserts a single document.
+    InsertOne(InsertOneModel<T>),
+
+    /// Updates up to one document matching a filter.
+    UpdateOne(UpdateOneModel),
+
+    /// Updates all documents matching a filter.
+    UpdateMany(UpdateManyModel),
+
+    /// Replaces up to one document matching a filter.
+    ReplaceOne(ReplaceOneModel<T>),
+
+    /// Deletes up to one document matching a filter.
+    DeleteOne(DeleteOneModel),
+
+    /// Deletes all documents matching a filter.
+    DeleteMany(DeleteManyModel),
+}
+
+impl<T> From<InsertOneModel<T>> for WriteModel<T> {
+    fn from(model: InsertOneModel<T>) -> Self {
+        Self::InsertOne(model)
+    }
+}
+
+impl<T> From<UpdateOneModel> for WriteModel<T> {
+    fn from(model: UpdateOneModel) -> Self {
+        Self::UpdateOne(model)
+    }
+}
+
+impl<T> From<UpdateManyModel> for WriteModel<T> {
+    fn from(model: UpdateManyModel) -> Self {
+        Self::UpdateMany(model)
+    }
+}
+
+impl<T> From<ReplaceOneModel<T>> for WriteModel<T> {
+    fn from(model: ReplaceOneModel<T>) -> Self {
+        Self::ReplaceOne(model)
+    }
+}
+
+impl<T> From<DeleteOneModel> for WriteModel<T> {
+    fn from(model: DeleteOneModel) -> Self {
+        Self::DeleteOne(model)
+    }
+}
+
+impl<T> From<DeleteManyModel> for WriteModel<T> {
+    fn from(model: DeleteManyModel) -> Self {
+        Self::DeleteMany(model)
+    }
+}
+
+/// Inserts a single document as part of a bulk write.
+#[derive(Clone, Debug, TypedBuilder)]
+#[non_exhaustive]
+pub struct InsertOneModel<T> {
+    /// The document to insert.
+    pub document: T,
+}
+
+/// Updates up to one document as part of a bulk write.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct UpdateOneModel {
+    /// The filter used to select the document to update.
+    pub filter: Document,
+
+    /// The modifications to apply to the matched document.
+    pub update: UpdateModifications,
+
+    /// A set of filters specifying to which array elements an update should apply.
+    #[builder(default)]
+    pub array_filters: Option<Vec<Document>>,
+
+    /// The collation to use for the operation.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use for the operation.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+
+    /// If true, insert a document if no matching document is found.
+    #[builder(default)]
+    pub upsert: Option<bool>,
+}
+
+/// Updates all matching documents as part of a bulk write.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct UpdateManyModel {
+    /// The filter used to select the documents to update.
+    pub filter: Document,
+
+    /// The modifications to apply to the matched documents.
+    pub update: UpdateModifications,
+
+    /// A set of filters specifying to which array elements an update should apply.
+    #[builder(default)]
+    pub array_filters: Option<Vec<Document>>,
+
+    /// The collation to use for the operation.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use for the operation.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+
+    /// If true, insert a document if no matching document is found.
+    #[builder(default)]
+    pub upsert: Option<bool>,
+}
+
+/// Replaces up to one document as part of a bulk write.
+#[derive(Clone, Debug, TypedBuilder)]
+#[non_exhaustive]
+pub struct ReplaceOneModel<T> {
+    /// The filter used to select the document to replace.
+    pub filter: Document,
+
+    /// The replacement document.
+    pub replacement: T,
+
+    /// The collation to use for the operation.
+    #[builder(default, setter(into))]
+    pub collation: Option<Collation>,
+
+    /// The index to use for the operation.
+    #[builder(default, setter(into))]
+    pub hint: Option<Hint>,
+
+    /// If true, insert the replacement document if no matching document is found.
+    #[builder(default, setter(into))]
+    pub upsert: Option<bool>,
+}
+
+/// Deletes up to one document as part of a bulk write.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct DeleteOneModel {
+    /// The filter used to select the document to delete.
+    pub filter: Document,
+
+    /// The collation to use for the operation.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use for the operation.
+    /// Only available in MongoDB 4.4+.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+}
+
+/// Deletes all matching documents as part of a bulk write.
+#[derive(Clone, Debug, TypedBuilder)]
+#[builder(field_defaults(setter(into)))]
+#[non_exhaustive]
+pub struct DeleteManyModel {
+    /// The filter used to select the documents to delete.
+    pub filter: Document,
+
+    /// The collation to use for the operation.
+    #[builder(default)]
+    pub collation: Option<Collation>,
+
+    /// The index to use for the operation.
+    /// Only available in MongoDB 4.4+.
+    #[builder(default)]
+    pub hint: Option<Hint>,
+}
+
+/// Specifies the options to a
+/// [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write) operation.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct BulkWriteOptions {
+    /// If true, when a write fails, return without performing the remaining writes. If false,
+    /// when a write fails, continue with the remaining writes, if any.
+    ///
+    /// Defaults to true.
+    pub ordered: Option<bool>,
+
+    /// Opt out of document-level validation.
+    pub bypass_document_validation: Option<bool>,
+
+    /// The write concern for the operation.
+    #[serde(skip_deserializing)]
+    pub write_concern: Option<WriteConcern>,
+
+    /// Map of parameter names and values. Values must be constant or closed
+    /// expressions that do not reference document fields. Parameters can then be
+    /// accessed as variables in an aggregate expression context (e.g. "$$var").
+    ///
+    /// This option applies to the update, replace and delete models; specifying it alongside any
+    /// insert models results in an error, as inserts do not support it.
+    ///
+    /// Only available in MongoDB 5.0+.
+    #[serde(rename = "let")]
+    pub let_vars: Option<Document>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
+impl BulkWriteOptions {
+    pub(crate) fn to_insert_many_options(&self) -> InsertManyOptions {
+        InsertManyOptions {
+            bypass_document_validation: self.bypass_document_validation,
+            ordered: self.ordered,
+            write_concern: self.write_concern.clone(),
+            comment: self.comment.clone(),
+            timeout: self.timeout,
+        }
+    }
 }
 
 /// Specifies the options to a
@@ -353,6 +692,17 @@ pub struct FindOneAndDeleteOptions {
     /// across the wire as an integer number of milliseconds.
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// Limits the fields of the document being returned.
     pub projection: Option<Document>,
 
@@ -405,6 +755,17 @@ pub struct FindOneAndReplaceOptions {
     /// across the wire as an integer number of milliseconds.
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// Limits the fields of the document being returned.
     pub projection: Option<Document>,
 
@@ -469,6 +830,17 @@ pub struct FindOneAndUpdateOptions {
     /// across the wire as an integer number of milliseconds.
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// Limits the fields of the document being returned.
     pub projection: Option<Document>,
 
@@ -585,6 +957,17 @@ pub struct AggregateOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// The read concern to use for the operation.
     ///
     /// If none is specified, the read concern defined on the object executing this operation will
@@ -640,6 +1023,17 @@ pub struct CountOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// The number of documents to skip before counting.
     pub skip: Option<u64>,
 
@@ -690,6 +1084,17 @@ pub struct EstimatedDocumentCountOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// The criteria used to select a server for this operation.
     ///
     /// If none specified, the default set on the collection will be used.
@@ -729,6 +1134,17 @@ pub struct DistinctOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// The criteria used to select a server for this operation.
     ///
     /// If none specified, the default set on the collection will be used.
@@ -833,6 +1249,17 @@ pub struct FindOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// The inclusive lower bound for a specific index.
     pub min: Option<Document>,
 
@@ -908,6 +1335,7 @@ impl From<FindOneOptions> for FindOptions {
             no_cursor_timeout: None,
             sort: options.sort,
             let_vars: options.let_vars,
+            timeout: options.timeout,
         }
     }
 }
@@ -981,6 +1409,17 @@ pub struct FindOneOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// The inclusive lower bound for a specific index.
     pub min: Option<Document>,
 
@@ -1046,6 +1485,12 @@ pub struct CreateIndexOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
+
     /// The write concern for the operation.
     pub write_concern: Option<WriteConcern>,
 
@@ -1056,6 +1501,69 @@ pub struct CreateIndexOptions {
     pub comment: Option<Bson>,
 }
 
+/// Specifies the options to a [`Collection::stats`](../struct.Collection.html#method.stats)
+/// operation.
+#[derive(Clone, Debug, Default, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct CollectionStatsOptions {
+    /// Whether to report storage statistics. Defaults to true.
+    pub storage_stats: Option<bool>,
+
+    /// Whether to report latency statistics. Defaults to false.
+    pub latency_stats: Option<bool>,
+
+    /// Whether to report the number of documents in the collection. Defaults to false. The count
+    /// is also reported as part of the storage statistics.
+    pub count: Option<bool>,
+
+    /// The factor by which to divide the reported storage sizes, e.g. 1024 to report sizes in
+    /// kibibytes. Defaults to 1.
+    pub scale: Option<u32>,
+
+    /// The criteria used to select a server for this operation. If none is specified, the
+    /// selection criteria defined on the object executing this operation will be used.
+    pub selection_criteria: Option<SelectionCriteria>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    pub timeout: Option<Duration>,
+}
+
+/// Specifies the options to a [`Collection::rename`](../struct.Collection.html#method.rename)
+/// operation.
+#[serde_with::skip_serializing_none]
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct RenameCollectionOptions {
+    /// Whether to drop an existing collection with the target name before renaming. If this is
+    /// false or unset and the target collection exists, the operation will fail.
+    pub drop_target: Option<bool>,
+
+    /// The write concern for the operation.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Tags the query with an arbitrary [`Bson`] value to help trace the operation through the
+    /// database profiler, currentOp and logs.
+    ///
+    /// This option is only available on server versions 4.4+.
+    pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
 /// Specifies the options to a [`Collection::drop`](../struct.Collection.html#method.drop)
 /// operation.
 #[serde_with::skip_serializing_none]
@@ -1073,6 +1581,17 @@ pub struct DropCollectionOptions {
     #[cfg(feature = "in-use-encryption-unstable")]
     #[serde(skip_serializing)]
     pub encrypted_fields: Option<Document>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -1096,6 +1615,17 @@ pub struct DropIndexOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// The write concern for the operation.
     pub write_concern: Option<WriteConcern>,
 
@@ -1126,6 +1656,17 @@ pub struct ListIndexesOptions {
     )]
     pub max_time: Option<Duration>,
 
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+
     /// The number of indexes the server should return per cursor batch.
     #[serde(default, skip_serializing)]
     pub batch_size: Option<u32>,
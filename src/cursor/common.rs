@@ -16,12 +16,12 @@ use tokio::sync::oneshot;
 use crate::{
     bson::{Bson, Document},
     change_stream::event::ResumeToken,
+    client::{AsyncDropToken, Deadline},
     cmap::conn::PinnedConnectionHandle,
     error::{Error, ErrorKind, Result},
     operation,
     options::ServerAddress,
     results::GetMoreResult,
-    runtime,
     Client,
     Namespace,
 };
@@ -384,6 +384,9 @@ impl CursorSpecification {
                 batch_size: batch_size.into(),
                 max_time: max_time.into(),
                 comment: comment.into(),
+                timeout: None,
+                deadline: None,
+                tailable: false,
             },
             initial_buffer: info.first_batch,
             post_batch_resume_token: ResumeToken::from_raw(info.post_batch_resume_token),
@@ -419,6 +422,26 @@ pub(crate) struct CursorInformation {
     pub(crate) batch_size: Option<u32>,
     pub(crate) max_time: Option<Duration>,
     pub(crate) comment: Option<Bson>,
+    /// The client-side timeout applied to each `getMore` issued by the cursor.
+    pub(crate) timeout: Option<Duration>,
+    /// The deadline shared by every `getMore` issued by the cursor, derived from the timeout of
+    /// the operation that created it.
+    pub(crate) deadline: Option<Deadline>,
+    /// Whether the cursor is tailable, in which case the client-side timeout applies to each
+    /// `getMore` rather than to the lifetime of the cursor.
+    pub(crate) tailable: bool,
+}
+
+impl CursorInformation {
+    /// Applies the client-side timeout of the operation that created this cursor to the
+    /// `getMore`s it issues.
+    pub(crate) fn set_timeout(&mut self, timeout: Option<Duration>, deadline: Option<Deadline>) {
+        if self.tailable {
+            self.timeout = timeout;
+        } else {
+            self.deadline = deadline;
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -475,12 +498,13 @@ pub(super) fn kill_cursor(
     cursor_id: i64,
     pinned_conn: PinnedConnection,
     drop_address: Option<ServerAddress>,
+    drop_token: &mut AsyncDropToken,
     #[cfg(test)] kill_watcher: Option<oneshot::Sender<()>>,
 ) {
     let coll = client
         .database(ns.db.as_str())
         .collection::<Document>(ns.coll.as_str());
-    runtime::execute(async move {
+    drop_token.spawn(async move {
         if !pinned_conn.is_invalid() {
             let _ = coll
                 .kill_cursor(cursor_id, pinned_conn.handle(), drop_address)
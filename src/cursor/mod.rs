@@ -19,7 +19,7 @@ use tokio::sync::oneshot;
 
 use crate::{
     change_stream::event::ResumeToken,
-    client::options::ServerAddress,
+    client::{options::ServerAddress, AsyncDropToken},
     cmap::conn::PinnedConnectionHandle,
     error::{Error, Result},
     operation::GetMore,
@@ -106,6 +106,7 @@ pub struct Cursor<T> {
     // that's had `with_type` called; in all other circumstances it will be `Some`.
     wrapped_cursor: Option<ImplicitSessionCursor<T>>,
     drop_address: Option<ServerAddress>,
+    drop_token: AsyncDropToken,
     #[cfg(test)]
     kill_watcher: Option<oneshot::Sender<()>>,
     _phantom: std::marker::PhantomData<T>,
@@ -122,6 +123,7 @@ impl<T> Cursor<T> {
 
         Self {
             client: client.clone(),
+            drop_token: client.register_async_drop(),
             wrapped_cursor: Some(ImplicitSessionCursor::new(
                 client,
                 spec,
@@ -273,6 +275,7 @@ impl<T> Cursor<T> {
             client: self.client.clone(),
             wrapped_cursor: self.wrapped_cursor.take().map(|c| c.with_type()),
             drop_address: self.drop_address.take(),
+            drop_token: std::mem::take(&mut self.drop_token),
             #[cfg(test)]
             kill_watcher: self.kill_watcher.take(),
             _phantom: Default::default(),
@@ -336,6 +339,7 @@ impl<T> Drop for Cursor<T> {
             wrapped_cursor.id(),
             wrapped_cursor.pinned_connection().replicate(),
             self.drop_address.take(),
+            &mut self.drop_token,
             #[cfg(test)]
             self.kill_watcher.take(),
         );
@@ -421,9 +425,14 @@ impl GetMoreProvider for ImplicitSessionGetMoreProvider {
             Self::Idle(mut session) => {
                 let pinned_connection = pinned_connection.map(|c| c.replicate());
                 let future = Box::pin(async move {
+                    let deadline = info.deadline;
                     let get_more = GetMore::new(info, pinned_connection.as_ref());
                     let get_more_result = client
-                        .execute_operation(get_more, session.as_mut().map(|b| b.as_mut()))
+                        .execute_operation_with_deadline(
+                            get_more,
+                            session.as_mut().map(|b| b.as_mut()),
+                            deadline,
+                        )
                         .await;
                     ImplicitSessionGetMoreResult {
                         get_more_result,
@@ -444,9 +453,14 @@ impl GetMoreProvider for ImplicitSessionGetMoreProvider {
     ) -> BoxFuture<'_, Result<GetMoreResult>> {
         match self {
             Self::Idle(ref mut session) => Box::pin(async move {
+                let deadline = info.deadline;
                 let get_more = GetMore::new(info, pinned_connection.handle());
                 client
-                    .execute_operation(get_more, session.as_mut().map(|b| b.as_mut()))
+                    .execute_operation_with_deadline(
+                        get_more,
+                        session.as_mut().map(|b| b.as_mut()),
+                        deadline,
+                    )
                     .await
             }),
             Self::Executing(_fut) => Box::pin(async {
@@ -28,7 +28,7 @@ use super::{
 use crate::{
     bson::Document,
     change_stream::event::ResumeToken,
-    client::options::ServerAddress,
+    client::{options::ServerAddress, AsyncDropToken},
     cmap::conn::PinnedConnectionHandle,
     cursor::CursorSpecification,
     error::{Error, Result},
@@ -75,6 +75,7 @@ pub struct SessionCursor<T> {
     info: CursorInformation,
     state: Option<CursorState>,
     drop_address: Option<ServerAddress>,
+    drop_token: AsyncDropToken,
     _phantom: PhantomData<T>,
     #[cfg(test)]
     kill_watcher: Option<oneshot::Sender<()>>,
@@ -89,6 +90,7 @@ impl<T> SessionCursor<T> {
         let exhausted = spec.info.id == 0;
 
         Self {
+            drop_token: client.register_async_drop(),
             client,
             info: spec.info,
             drop_address: None,
@@ -313,6 +315,7 @@ impl<T> SessionCursor<T> {
             info: self.info.clone(),
             state: Some(self.take_state()),
             drop_address: self.drop_address.take(),
+            drop_token: std::mem::take(&mut self.drop_token),
             _phantom: Default::default(),
             #[cfg(test)]
             kill_watcher: self.kill_watcher.take(),
@@ -353,7 +356,6 @@ impl<T> SessionCursor<T> {
         self.state.as_ref().unwrap().exhausted
     }
 
-    #[cfg(test)]
     pub(crate) fn client(&self) -> &Client {
         &self.client
     }
@@ -371,6 +373,7 @@ impl<T> Drop for SessionCursor<T> {
             self.info.id,
             self.state.as_ref().unwrap().pinned_connection.replicate(),
             self.drop_address.take(),
+            &mut self.drop_token,
             #[cfg(test)]
             self.kill_watcher.take(),
         );
@@ -478,9 +481,14 @@ impl<'session> GetMoreProvider for ExplicitSessionGetMoreProvider<'session> {
             if let ExplicitSessionGetMoreProvider::Idle(session) = self_ {
                 let pinned_connection = pinned_connection.map(|c| c.replicate());
                 let future = Box::pin(async move {
+                    let deadline = info.deadline;
                     let get_more = GetMore::new(info, pinned_connection.as_ref());
                     let get_more_result = client
-                        .execute_operation(get_more, Some(&mut *session.reference))
+                        .execute_operation_with_deadline(
+                            get_more,
+                            Some(&mut *session.reference),
+                            deadline,
+                        )
                         .await;
                     ExecutionResult {
                         get_more_result,
@@ -501,9 +509,14 @@ impl<'session> GetMoreProvider for ExplicitSessionGetMoreProvider<'session> {
     ) -> BoxFuture<'_, Result<GetMoreResult>> {
         match self {
             Self::Idle(ref mut session) => Box::pin(async move {
+                let deadline = info.deadline;
                 let get_more = GetMore::new(info, pinned_connection.handle());
                 client
-                    .execute_operation(get_more, Some(&mut *session.reference))
+                    .execute_operation_with_deadline(
+                        get_more,
+                        Some(&mut *session.reference),
+                        deadline,
+                    )
                     .await
             }),
             Self::Executing(_fut) => Box::pin(async {
@@ -1,8 +1,7 @@
 pub mod options;
 
-use std::{fmt::Debug, sync::Arc};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
-#[cfg(feature = "in-use-encryption-unstable")]
 use bson::doc;
 use futures_util::stream::TryStreamExt;
 
@@ -26,10 +25,11 @@ use crate::{
         CollectionOptions,
         CreateCollectionOptions,
         DatabaseOptions,
+        DatabaseStatsOptions,
         DropDatabaseOptions,
         ListCollectionsOptions,
     },
-    results::CollectionSpecification,
+    results::{CollectionSpecification, DatabaseStats},
     selection_criteria::SelectionCriteria,
     Client,
     ClientSession,
@@ -87,6 +87,7 @@ struct DatabaseInner {
     selection_criteria: Option<SelectionCriteria>,
     read_concern: Option<ReadConcern>,
     write_concern: Option<WriteConcern>,
+    timeout: Option<Duration>,
 }
 
 impl Database {
@@ -104,6 +105,8 @@ impl Database {
             .write_concern
             .or_else(|| client.write_concern().cloned());
 
+        let timeout = options.timeout.or_else(|| client.timeout());
+
         Self {
             inner: Arc::new(DatabaseInner {
                 client,
@@ -111,6 +114,7 @@ impl Database {
                 selection_criteria,
                 read_concern,
                 write_concern,
+                timeout,
             }),
         }
     }
@@ -140,6 +144,11 @@ impl Database {
         self.inner.write_concern.as_ref()
     }
 
+    /// Gets the client-side operation timeout of the `Database`.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout
+    }
+
     /// Gets a handle to a collection in this database with the provided name. The
     /// [`Collection`] options (e.g. read preference and write concern) will default to those of
     /// this [`Database`].
@@ -176,7 +185,7 @@ impl Database {
         session: impl Into<Option<&mut ClientSession>>,
     ) -> Result<()> {
         let mut options = options.into();
-        resolve_options!(self, options, [write_concern]);
+        resolve_options!(self, options, [write_concern, timeout]);
 
         let drop_database = DropDatabase::new(self.name().to_string(), options);
         self.client()
@@ -206,12 +215,11 @@ impl Database {
         filter: impl Into<Option<Document>>,
         options: impl Into<Option<ListCollectionsOptions>>,
     ) -> Result<Cursor<CollectionSpecification>> {
-        let list_collections = ListCollections::new(
-            self.name().to_string(),
-            filter.into(),
-            false,
-            options.into(),
-        );
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let list_collections =
+            ListCollections::new(self.name().to_string(), filter.into(), false, options);
         self.client()
             .execute_cursor_operation(list_collections)
             .await
@@ -226,12 +234,11 @@ impl Database {
         options: impl Into<Option<ListCollectionsOptions>>,
         session: &mut ClientSession,
     ) -> Result<SessionCursor<CollectionSpecification>> {
-        let list_collections = ListCollections::new(
-            self.name().to_string(),
-            filter.into(),
-            false,
-            options.into(),
-        );
+        let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
+
+        let list_collections =
+            ListCollections::new(self.name().to_string(), filter.into(), false, options);
         self.client()
             .execute_session_cursor_operation(list_collections, session)
             .await
@@ -261,8 +268,11 @@ impl Database {
         &self,
         filter: impl Into<Option<Document>>,
     ) -> Result<Vec<String>> {
+        let mut options: Option<ListCollectionsOptions> = None;
+        resolve_options!(self, options, [timeout]);
+
         let list_collections =
-            ListCollections::new(self.name().to_string(), filter.into(), true, None);
+            ListCollections::new(self.name().to_string(), filter.into(), true, options);
         let cursor: Cursor<Document> = self
             .client()
             .execute_cursor_operation(list_collections)
@@ -277,8 +287,11 @@ impl Database {
         filter: impl Into<Option<Document>>,
         session: &mut ClientSession,
     ) -> Result<Vec<String>> {
+        let mut options: Option<ListCollectionsOptions> = None;
+        resolve_options!(self, options, [timeout]);
+
         let list_collections =
-            ListCollections::new(self.name().to_string(), filter.into(), true, None);
+            ListCollections::new(self.name().to_string(), filter.into(), true, options);
         let mut cursor: SessionCursor<Document> = self
             .client()
             .execute_session_cursor_operation(list_collections, &mut *session)
@@ -296,7 +309,7 @@ impl Database {
         session: impl Into<Option<&mut ClientSession>>,
     ) -> Result<()> {
         let mut options: Option<CreateCollectionOptions> = options.into();
-        resolve_options!(self, options, [write_concern]);
+        resolve_options!(self, options, [write_concern, timeout]);
         let mut session = session.into();
 
         let ns = Namespace {
@@ -433,10 +446,44 @@ impl Database {
             command,
             selection_criteria.into(),
             pinned_connection,
-        )?;
+        )?
+        .with_timeout(self.timeout());
         self.client().execute_operation(operation, session).await
     }
 
+    /// Gets storage statistics for the database. When run against a sharded cluster, the
+    /// statistics are totaled across all shards.
+    ///
+    /// See the documentation [here](https://www.mongodb.com/docs/manual/reference/command/dbStats/)
+    /// for more information on the reported statistics.
+    pub async fn stats(
+        &self,
+        options: impl Into<Option<DatabaseStatsOptions>>,
+    ) -> Result<DatabaseStats> {
+        let options = options.into().unwrap_or_default();
+
+        let mut command = doc! { "dbStats": 1 };
+        if let Some(scale) = options.scale {
+            command.insert("scale", i64::from(scale));
+        }
+
+        let operation = RunCommand::new(
+            self.name().into(),
+            command,
+            options
+                .selection_criteria
+                .or_else(|| self.selection_criteria().cloned()),
+            None,
+        )?
+        .with_timeout(options.timeout.or_else(|| self.timeout()));
+        let response = self.client().execute_operation(operation, None).await?;
+        let mut stats: DatabaseStats = bson::from_document(response)?;
+        if stats.db.is_empty() {
+            stats.db = self.name().to_string();
+        }
+        Ok(stats)
+    }
+
     /// Runs a database-level command.
     ///
     /// Note that no inspection is done on `doc`, so the command will not use the database's default
@@ -505,7 +552,7 @@ impl Database {
         resolve_options!(
             self,
             options,
-            [read_concern, write_concern, selection_criteria]
+            [read_concern, write_concern, selection_criteria, timeout]
         );
 
         let aggregate = Aggregate::new(self.name().to_string(), pipeline, options);
@@ -527,7 +574,7 @@ impl Database {
         resolve_options!(
             self,
             options,
-            [read_concern, write_concern, selection_criteria]
+            [read_concern, write_concern, selection_criteria, timeout]
         );
 
         let aggregate = Aggregate::new(self.name().to_string(), pipeline, options);
@@ -560,7 +607,7 @@ impl Database {
         options: impl Into<Option<ChangeStreamOptions>>,
     ) -> Result<ChangeStream<ChangeStreamEvent<Document>>> {
         let mut options = options.into();
-        resolve_options!(self, options, [read_concern, selection_criteria]);
+        resolve_options!(self, options, [read_concern, selection_criteria, timeout]);
         let target = AggregateTarget::Database(self.name().to_string());
         self.client()
             .execute_watch(pipeline, options, target, None)
@@ -576,6 +623,7 @@ impl Database {
         session: &mut ClientSession,
     ) -> Result<SessionChangeStream<ChangeStreamEvent<Document>>> {
         let mut options = options.into();
+        resolve_options!(self, options, [timeout]);
         resolve_read_concern_with_session!(self, options, Some(&mut *session))?;
         resolve_selection_criteria_with_session!(self, options, Some(&mut *session))?;
         let target = AggregateTarget::Database(self.name().to_string());
@@ -27,6 +27,15 @@ pub struct DatabaseOptions {
 
     /// The default write concern for operations.
     pub write_concern: Option<WriteConcern>,
+
+    /// The default client-side timeout for operations. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// These are the valid options for creating a collection with
@@ -120,6 +129,17 @@ pub struct CreateCollectionOptions {
     /// Map of encrypted fields for the created collection.
     #[cfg(feature = "in-use-encryption-unstable")]
     pub encrypted_fields: Option<Document>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies how strictly the database should apply validation rules to existing documents during
@@ -232,6 +252,32 @@ pub enum TimeseriesGranularity {
 pub struct DropDatabaseOptions {
     /// The write concern for the operation.
     pub write_concern: Option<WriteConcern>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(skip)]
+    pub timeout: Option<Duration>,
+}
+
+/// Specifies the options to a [`Database::stats`](../struct.Database.html#method.stats)
+/// operation.
+#[derive(Clone, Debug, Default, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct DatabaseStatsOptions {
+    /// The factor by which to divide the reported sizes, e.g. 1024 to report sizes in kibibytes.
+    /// Defaults to 1.
+    pub scale: Option<u32>,
+
+    /// The criteria used to select a server for this operation. If none is specified, the
+    /// selection criteria defined on the object executing this operation will be used.
+    pub selection_criteria: Option<SelectionCriteria>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -259,6 +305,17 @@ pub struct ListCollectionsOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies the options to a
@@ -278,6 +335,17 @@ pub struct ListDatabasesOptions {
     ///
     /// This option is only available on server versions 4.4+.
     pub comment: Option<Bson>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        skip_serializing,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
 }
 
 /// Specifies how change stream pre- and post-images should be supported.
@@ -4,13 +4,19 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::{self, Debug},
     sync::Arc,
+    time::Duration,
 };
 
 use bson::Bson;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{bson::Document, options::ServerAddress, sdam::TopologyVersion};
+use crate::{
+    bson::Document,
+    options::ServerAddress,
+    results::BulkWriteResult,
+    sdam::TopologyVersion,
+};
 
 const RECOVERING_CODES: [i32; 5] = [11600, 11602, 13436, 189, 91];
 const NOTWRITABLEPRIMARY_CODES: [i32; 3] = [10107, 13435, 10058];
@@ -108,6 +114,43 @@ impl Error {
         ErrorKind::Io(Arc::new(std::io::ErrorKind::TimedOut.into())).into()
     }
 
+    /// Construct an error indicating that an operation exceeded its client-side timeout.
+    pub(crate) fn operation_timeout(timeout: Duration) -> Error {
+        ErrorKind::Timeout {
+            message: format!("operation exceeded the timeout of {:?}", timeout),
+        }
+        .into()
+    }
+
+    /// Construct an error indicating that the server stopped executing a command because it
+    /// exceeded the `maxTimeMS` derived from the operation's timeout. The server's error is kept as
+    /// the source.
+    pub(crate) fn server_operation_timeout(source: Error) -> Error {
+        Error::new(
+            ErrorKind::Timeout {
+                message: format!(
+                    "operation exceeded its timeout while executing on the server: {}",
+                    source
+                ),
+            },
+            Some(source.labels().clone()),
+        )
+        .with_source(source)
+    }
+
+    /// Construct an error indicating that a connection could not be checked out of the pool for
+    /// the given server within its `wait_queue_timeout`.
+    pub(crate) fn wait_queue_timeout(address: &ServerAddress, timeout: Duration) -> Error {
+        ErrorKind::Timeout {
+            message: format!(
+                "timed out after {:?} while checking out a connection from the connection pool \
+                 for {}",
+                timeout, address
+            ),
+        }
+        .into()
+    }
+
     pub(crate) fn invalid_argument(message: impl Into<String>) -> Error {
         ErrorKind::InvalidArgument {
             message: message.into(),
@@ -136,6 +179,22 @@ impl Error {
         matches!(self.kind.as_ref(), ErrorKind::Command(ref err) if err.code == 26)
     }
 
+    /// Whether this error is a "ReauthenticationRequired" error, indicating that the connection's
+    /// authentication has expired and must be renewed before the operation can succeed.
+    pub(crate) fn is_reauthentication_required(&self) -> bool {
+        self.code() == Some(391)
+    }
+
+    /// Whether this error is a "MaxTimeMSExpired" error, either from a command or from a write
+    /// concern.
+    pub(crate) fn is_max_time_ms_expired_error(&self) -> bool {
+        self.code() == Some(50)
+    }
+
+    pub(crate) fn is_operation_timeout(&self) -> bool {
+        matches!(self.kind.as_ref(), ErrorKind::Timeout { .. })
+    }
+
     pub(crate) fn is_server_selection_error(&self) -> bool {
         matches!(self.kind.as_ref(), ErrorKind::ServerSelection { .. })
     }
@@ -265,7 +324,7 @@ impl Error {
             ErrorKind::BulkWrite(BulkWriteFailure {
                 write_concern_error,
                 write_errors,
-                inserted_ids: _,
+                ..
             }) => {
                 let mut msg = "".to_string();
                 if let Some(wc_error) = write_concern_error {
@@ -419,9 +478,12 @@ impl Error {
             | ErrorKind::ServerSelection { .. }
             | ErrorKind::SessionsNotSupported
             | ErrorKind::InvalidTlsConfig { .. }
+            | ErrorKind::CertificateRevoked { .. }
             | ErrorKind::Transaction { .. }
             | ErrorKind::IncompatibleServer { .. }
             | ErrorKind::MissingResumeToken
+            | ErrorKind::Timeout { .. }
+            | ErrorKind::Shutdown
             | ErrorKind::Authentication { .. }
             | ErrorKind::GridFs(_) => {}
             #[cfg(feature = "in-use-encryption-unstable")]
@@ -556,6 +618,12 @@ pub enum ErrorKind {
     #[non_exhaustive]
     InvalidTlsConfig { message: String },
 
+    /// The certificate presented by a server has been revoked by its issuer, as reported by a
+    /// stapled OCSP response or by the configured certificate revocation list.
+    #[error("Server certificate has been revoked: {message}")]
+    #[non_exhaustive]
+    CertificateRevoked { message: String },
+
     /// An error occurred when trying to execute a write operation.
     #[error("An error occurred when trying to execute a write operation: {0:?}")]
     Write(WriteFailure),
@@ -574,6 +642,17 @@ pub enum ErrorKind {
     #[error("Cannot provide resume functionality when the resume token is missing")]
     MissingResumeToken,
 
+    /// An operation did not complete within its configured client-side timeout, or a connection
+    /// could not be checked out of a connection pool within its `wait_queue_timeout`.
+    #[error("Operation timed out: {message}")]
+    #[non_exhaustive]
+    Timeout { message: String },
+
+    /// The `Client` was used after [`Client::shutdown`](crate::Client::shutdown) or
+    /// [`Client::shutdown_immediate`](crate::Client::shutdown_immediate) was called.
+    #[error("Client has been shut down")]
+    Shutdown,
+
     /// An error occurred during encryption or decryption.
     #[cfg(feature = "in-use-encryption-unstable")]
     #[error("An error occurred during client-side encryption: {0}")]
@@ -756,6 +835,12 @@ pub struct BulkWriteFailure {
 
     #[serde(skip)]
     pub(crate) inserted_ids: HashMap<usize, Bson>,
+
+    /// The outcome of the writes that were performed before the failure, for failures returned
+    /// from [`Collection::bulk_write`](crate::Collection::bulk_write). This includes the results
+    /// of every batch sent to the server, including those in which the errors occurred.
+    #[serde(skip)]
+    pub partial_result: Option<BulkWriteResult>,
 }
 
 impl BulkWriteFailure {
@@ -764,6 +849,7 @@ impl BulkWriteFailure {
             write_errors: None,
             write_concern_error: None,
             inserted_ids: Default::default(),
+            partial_result: None,
         }
     }
 }
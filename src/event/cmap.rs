@@ -5,7 +5,7 @@ use std::{sync::Arc, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{bson::oid::ObjectId, bson_util, options::ServerAddress};
+use crate::{bson::oid::ObjectId, bson_util, event::EventStreamSenders, options::ServerAddress};
 use derivative::Derivative;
 use derive_more::From;
 
@@ -69,6 +69,20 @@ pub struct ConnectionPoolOptions {
     ///
     /// The default is that no minimum is enforced
     pub min_pool_size: Option<u32>,
+
+    /// The maximum number of connections that the pool can be establishing concurrently.
+    ///
+    /// The default is 2.
+    pub max_connecting: Option<u32>,
+
+    /// The maximum amount of time a checkout request can wait for a connection to become available
+    /// before failing with a [`ConnectionCheckoutFailedReason::Timeout`].
+    ///
+    /// The default is that requests will wait until the operation's server selection timeout.
+    #[serde(rename = "waitQueueTimeoutMS")]
+    #[serde(default)]
+    #[serde(deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis")]
+    pub wait_queue_timeout: Option<Duration>,
 }
 
 /// Event emitted when a connection pool becomes ready.
@@ -356,18 +370,112 @@ pub trait CmapEventHandler: Send + Sync {
     fn handle_connection_checked_in_event(&self, _event: ConnectionCheckedInEvent) {}
 }
 
+/// Forwards each event to every handler in the list, in order. This can be used to register
+/// multiple handlers on a single `Client`.
+impl CmapEventHandler for Vec<Arc<dyn CmapEventHandler>> {
+    fn handle_pool_created_event(&self, event: PoolCreatedEvent) {
+        for handler in self {
+            handler.handle_pool_created_event(event.clone());
+        }
+    }
+
+    fn handle_pool_ready_event(&self, event: PoolReadyEvent) {
+        for handler in self {
+            handler.handle_pool_ready_event(event.clone());
+        }
+    }
+
+    fn handle_pool_cleared_event(&self, event: PoolClearedEvent) {
+        for handler in self {
+            handler.handle_pool_cleared_event(event.clone());
+        }
+    }
+
+    fn handle_pool_closed_event(&self, event: PoolClosedEvent) {
+        for handler in self {
+            handler.handle_pool_closed_event(event.clone());
+        }
+    }
+
+    fn handle_connection_created_event(&self, event: ConnectionCreatedEvent) {
+        for handler in self {
+            handler.handle_connection_created_event(event.clone());
+        }
+    }
+
+    fn handle_connection_ready_event(&self, event: ConnectionReadyEvent) {
+        for handler in self {
+            handler.handle_connection_ready_event(event.clone());
+        }
+    }
+
+    fn handle_connection_closed_event(&self, event: ConnectionClosedEvent) {
+        for handler in self {
+            handler.handle_connection_closed_event(event.clone());
+        }
+    }
+
+    fn handle_connection_checkout_started_event(&self, event: ConnectionCheckoutStartedEvent) {
+        for handler in self {
+            handler.handle_connection_checkout_started_event(event.clone());
+        }
+    }
+
+    fn handle_connection_checkout_failed_event(&self, event: ConnectionCheckoutFailedEvent) {
+        for handler in self {
+            handler.handle_connection_checkout_failed_event(event.clone());
+        }
+    }
+
+    fn handle_connection_checked_out_event(&self, event: ConnectionCheckedOutEvent) {
+        for handler in self {
+            handler.handle_connection_checked_out_event(event.clone());
+        }
+    }
+
+    fn handle_connection_checked_in_event(&self, event: ConnectionCheckedInEvent) {
+        for handler in self {
+            handler.handle_connection_checked_in_event(event.clone());
+        }
+    }
+}
+
+/// A connection monitoring and pooling event, as received from an
+/// [`EventStream`](crate::event::EventStream).
 #[derive(Clone, Debug, PartialEq, From)]
-pub(crate) enum CmapEvent {
+#[non_exhaustive]
+pub enum CmapEvent {
+    /// A connection pool was created.
     PoolCreated(PoolCreatedEvent),
+
+    /// A connection pool became ready.
     PoolReady(PoolReadyEvent),
+
+    /// A connection pool was cleared.
     PoolCleared(PoolClearedEvent),
+
+    /// A connection pool was closed.
     PoolClosed(PoolClosedEvent),
+
+    /// A connection was created.
     ConnectionCreated(ConnectionCreatedEvent),
+
+    /// A connection finished being established and is ready to be used.
     ConnectionReady(ConnectionReadyEvent),
+
+    /// A connection was closed.
     ConnectionClosed(ConnectionClosedEvent),
+
+    /// An attempt to check out a connection started.
     ConnectionCheckoutStarted(ConnectionCheckoutStartedEvent),
+
+    /// An attempt to check out a connection failed.
     ConnectionCheckoutFailed(ConnectionCheckoutFailedEvent),
+
+    /// A connection was checked out.
     ConnectionCheckedOut(ConnectionCheckedOutEvent),
+
+    /// A connection was checked back into its pool.
     ConnectionCheckedIn(ConnectionCheckedInEvent),
 }
 
@@ -375,6 +483,8 @@ pub(crate) enum CmapEvent {
 pub(crate) struct CmapEventEmitter {
     user_handler: Option<Arc<dyn CmapEventHandler>>,
 
+    event_streams: Option<Arc<EventStreamSenders<CmapEvent>>>,
+
     #[cfg(feature = "tracing-unstable")]
     tracing_emitter: ConnectionTracingEventEmitter,
 }
@@ -384,19 +494,37 @@ impl CmapEventEmitter {
     #[allow(unused_variables)]
     pub(crate) fn new(
         user_handler: Option<Arc<dyn CmapEventHandler>>,
+        event_streams: Option<Arc<EventStreamSenders<CmapEvent>>>,
         topology_id: ObjectId,
     ) -> CmapEventEmitter {
         Self {
             user_handler,
+            event_streams,
             #[cfg(feature = "tracing-unstable")]
             tracing_emitter: ConnectionTracingEventEmitter::new(topology_id),
         }
     }
 
+    /// The event streams to send events to, if any are registered.
+    fn active_event_streams(&self) -> Option<&EventStreamSenders<CmapEvent>> {
+        self.event_streams
+            .as_deref()
+            .filter(|streams| streams.is_active())
+    }
+
     #[cfg(not(feature = "tracing-unstable"))]
     pub(crate) fn emit_event(&self, generate_event: impl FnOnce() -> CmapEvent) {
+        let event_streams = self.active_event_streams();
+        if self.user_handler.is_none() && event_streams.is_none() {
+            return;
+        }
+
+        let event = generate_event();
+        if let Some(event_streams) = event_streams {
+            event_streams.send(&event);
+        }
         if let Some(ref handler) = self.user_handler {
-            handle_cmap_event(handler.as_ref(), generate_event());
+            handle_cmap_event(handler.as_ref(), event);
         }
     }
 
@@ -412,19 +540,27 @@ impl CmapEventEmitter {
         } else {
             None
         };
+        let event_streams = self.active_event_streams();
+        if self.user_handler.is_none()
+            && tracing_emitter_to_use.is_none()
+            && event_streams.is_none()
+        {
+            return;
+        }
 
+        let event = generate_event();
+        if let Some(event_streams) = event_streams {
+            event_streams.send(&event);
+        }
         match (&self.user_handler, tracing_emitter_to_use) {
             (None, None) => {}
             (None, Some(tracing_emitter)) => {
-                let event = generate_event();
                 handle_cmap_event(tracing_emitter, event);
             }
             (Some(user_handler), None) => {
-                let event = generate_event();
                 handle_cmap_event(user_handler.as_ref(), event);
             }
             (Some(user_handler), Some(tracing_emitter)) => {
-                let event = generate_event();
                 handle_cmap_event(user_handler.as_ref(), event.clone());
                 handle_cmap_event(tracing_emitter, event);
             }
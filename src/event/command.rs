@@ -1,7 +1,7 @@
 //! Contains the events and functionality to monitor the commands and responses that a `Client`
 //! sends and receives from the server.
 
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use serde::Serialize;
 
@@ -145,11 +145,41 @@ pub trait CommandEventHandler: Send + Sync {
     fn handle_command_failed_event(&self, _event: CommandFailedEvent) {}
 }
 
+/// Forwards each event to every handler in the list, in order. This can be used to register
+/// multiple handlers on a single `Client`.
+impl CommandEventHandler for Vec<Arc<dyn CommandEventHandler>> {
+    fn handle_command_started_event(&self, event: CommandStartedEvent) {
+        for handler in self {
+            handler.handle_command_started_event(event.clone());
+        }
+    }
+
+    fn handle_command_succeeded_event(&self, event: CommandSucceededEvent) {
+        for handler in self {
+            handler.handle_command_succeeded_event(event.clone());
+        }
+    }
+
+    fn handle_command_failed_event(&self, event: CommandFailedEvent) {
+        for handler in self {
+            handler.handle_command_failed_event(event.clone());
+        }
+    }
+}
+
+/// A command monitoring event, as received from an
+/// [`EventStream`](crate::event::EventStream).
 #[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
-pub(crate) enum CommandEvent {
+#[non_exhaustive]
+pub enum CommandEvent {
+    /// A command was initiated.
     Started(CommandStartedEvent),
+
+    /// A command completed successfully.
     Succeeded(CommandSucceededEvent),
+
+    /// A command failed to complete successfully.
     Failed(CommandFailedEvent),
 }
 
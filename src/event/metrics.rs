@@ -0,0 +1,209 @@
+//! Contains the functionality for recording metrics about the behavior of a `Client`.
+//!
+//! Metric names and attributes follow the OpenTelemetry semantic conventions for database clients
+//! where applicable, so a [`MetricsSink`] can forward them to an OpenTelemetry meter (or any other
+//! metrics backend) without translation.
+
+use std::sync::{Arc, Mutex};
+
+use crate::client::options::{ServerAddress, DEFAULT_PORT};
+
+/// Histogram of the time taken to execute an operation, in seconds. This includes server
+/// selection, connection checkout and any retries.
+///
+/// Attributes: `db.system`, `db.operation` and `outcome` (either `"success"` or `"failure"`).
+pub const OPERATION_DURATION: &str = "db.client.operation.duration";
+
+/// Counter of the number of times an operation was retried.
+///
+/// Attributes: `db.system` and `db.operation`.
+pub const OPERATION_RETRIES: &str = "db.client.operation.retries";
+
+/// Histogram of the time spent waiting to check out a connection for an operation, in seconds.
+///
+/// Attributes: `db.system`, `server.address` and `server.port`.
+pub const CONNECTION_WAIT_TIME: &str = "db.client.connection.wait_time";
+
+/// Counter of the number of failed attempts to check out a connection for an operation.
+///
+/// Attributes: `db.system`, `server.address` and `server.port`.
+pub const CONNECTION_CHECKOUT_FAILURES: &str = "db.client.connection.checkout_failures";
+
+/// The key-value pairs describing a single measurement.
+pub type MetricAttributes = [(&'static str, String)];
+
+/// Applications can implement this trait to receive the metrics recorded by the driver, e.g. to
+/// forward them to a metrics backend. See the constants in this module for the metrics that are
+/// recorded.
+///
+/// The methods of this trait are called inline on the tasks executing operations, so they should
+/// return quickly.
+///
+/// ```rust
+/// # use std::sync::Arc;
+/// #
+/// # use mongodb::{
+/// #     error::Result,
+/// #     event::metrics::{MetricAttributes, MetricsSink, OPERATION_DURATION},
+/// #     options::ClientOptions,
+/// # };
+/// # #[cfg(any(feature = "sync", feature = "tokio-sync"))]
+/// # use mongodb::sync::Client;
+/// # #[cfg(all(not(feature = "sync"), not(feature = "tokio-sync")))]
+/// # use mongodb::Client;
+/// #
+/// struct SlowOperationLogger;
+///
+/// impl MetricsSink for SlowOperationLogger {
+///     fn record_histogram(&self, name: &'static str, value: f64, attributes: &MetricAttributes) {
+///         if name == OPERATION_DURATION && value > 1.0 {
+///             eprintln!("Slow operation ({}s): {:?}", value, attributes);
+///         }
+///     }
+/// }
+///
+/// # fn do_stuff() -> Result<()> {
+/// let sink: Arc<dyn MetricsSink> = Arc::new(SlowOperationLogger);
+/// let options = ClientOptions::builder().metrics_sink(sink).build();
+/// let client = Client::with_options(options)?;
+///
+/// // Do things with the client, and slow operations will be logged to stderr.
+/// # Ok(())
+/// # }
+/// ```
+pub trait MetricsSink: Send + Sync {
+    /// A [`Client`](../../struct.Client.html) will call this method to add `value` to the counter
+    /// with the given name.
+    fn increment_counter(&self, _name: &'static str, _value: u64, _attributes: &MetricAttributes) {}
+
+    /// A [`Client`](../../struct.Client.html) will call this method to record a measurement in the
+    /// histogram with the given name.
+    fn record_histogram(&self, _name: &'static str, _value: f64, _attributes: &MetricAttributes) {}
+}
+
+/// A single measurement recorded by an [`InMemoryMetricsSink`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct RecordedMetric {
+    /// The name of the metric.
+    pub name: &'static str,
+
+    /// The recorded value.
+    pub value: MetricValue,
+
+    /// The attributes describing the measurement.
+    pub attributes: Vec<(&'static str, String)>,
+}
+
+impl RecordedMetric {
+    /// Gets the value of the attribute with the given key, if present.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// The value of a [`RecordedMetric`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum MetricValue {
+    /// An increment of a counter.
+    Counter(u64),
+
+    /// A measurement recorded in a histogram.
+    Histogram(f64),
+}
+
+/// A [`MetricsSink`] that keeps every measurement in memory. This is primarily intended for
+/// asserting on the metrics recorded by a `Client` in tests.
+///
+/// Clones of an `InMemoryMetricsSink` share the same underlying storage.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryMetricsSink {
+    recorded: Arc<Mutex<Vec<RecordedMetric>>>,
+}
+
+impl InMemoryMetricsSink {
+    /// Creates a new, empty sink.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Gets all of the measurements recorded so far, in the order they were recorded.
+    pub fn recorded(&self) -> Vec<RecordedMetric> {
+        self.recorded.lock().unwrap().clone()
+    }
+
+    /// Gets the sum of all of the increments of the counter with the given name.
+    pub fn counter_total(&self, name: &str) -> u64 {
+        self.recorded
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|metric| metric.name == name)
+            .map(|metric| match metric.value {
+                MetricValue::Counter(value) => value,
+                MetricValue::Histogram(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Gets all of the measurements recorded in the histogram with the given name.
+    pub fn histogram_values(&self, name: &str) -> Vec<f64> {
+        self.recorded
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|metric| metric.name == name)
+            .filter_map(|metric| match metric.value {
+                MetricValue::Histogram(value) => Some(value),
+                MetricValue::Counter(_) => None,
+            })
+            .collect()
+    }
+
+    /// Removes all of the measurements recorded so far.
+    pub fn clear(&self) {
+        self.recorded.lock().unwrap().clear();
+    }
+
+    fn record(&self, name: &'static str, value: MetricValue, attributes: &MetricAttributes) {
+        self.recorded.lock().unwrap().push(RecordedMetric {
+            name,
+            value,
+            attributes: attributes.to_vec(),
+        });
+    }
+}
+
+impl MetricsSink for InMemoryMetricsSink {
+    fn increment_counter(&self, name: &'static str, value: u64, attributes: &MetricAttributes) {
+        self.record(name, MetricValue::Counter(value), attributes);
+    }
+
+    fn record_histogram(&self, name: &'static str, value: f64, attributes: &MetricAttributes) {
+        self.record(name, MetricValue::Histogram(value), attributes);
+    }
+}
+
+/// Builds the attributes describing an operation.
+pub(crate) fn operation_attributes(operation_name: &str) -> Vec<(&'static str, String)> {
+    vec![
+        ("db.system", "mongodb".to_string()),
+        ("db.operation", operation_name.to_string()),
+    ]
+}
+
+/// Builds the attributes describing a server.
+pub(crate) fn server_attributes(address: &ServerAddress) -> Vec<(&'static str, String)> {
+    let mut attributes = vec![
+        ("db.system", "mongodb".to_string()),
+        ("server.address", address.host().to_string()),
+    ];
+    if let ServerAddress::Tcp { port, .. } = address {
+        attributes.push(("server.port", port.unwrap_or(DEFAULT_PORT).to_string()));
+    }
+    attributes
+}
@@ -2,4 +2,9 @@
 
 pub mod cmap;
 pub mod command;
+pub mod metrics;
 pub mod sdam;
+mod stream;
+
+pub use self::stream::{EventStream, EventStreamOptions, OverflowPolicy};
+pub(crate) use self::stream::{EventStreamSenders, EventStreams};
@@ -2,7 +2,7 @@
 
 mod topology_description;
 
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
@@ -160,16 +160,36 @@ pub struct ServerHeartbeatFailedEvent {
     pub awaited: bool,
 }
 
+/// A server discovery and monitoring event, as received from an
+/// [`EventStream`](crate::event::EventStream).
 #[derive(Clone, Debug)]
-pub(crate) enum SdamEvent {
+#[non_exhaustive]
+pub enum SdamEvent {
+    /// A server's description changed.
     ServerDescriptionChanged(Box<ServerDescriptionChangedEvent>),
+
+    /// A server was added to the topology.
     ServerOpening(ServerOpeningEvent),
+
+    /// A server was removed from the topology.
     ServerClosed(ServerClosedEvent),
+
+    /// The topology's description changed.
     TopologyDescriptionChanged(Box<TopologyDescriptionChangedEvent>),
+
+    /// The topology was initialized.
     TopologyOpening(TopologyOpeningEvent),
+
+    /// The topology was closed.
     TopologyClosed(TopologyClosedEvent),
+
+    /// A server heartbeat started.
     ServerHeartbeatStarted(ServerHeartbeatStartedEvent),
+
+    /// A server heartbeat succeeded.
     ServerHeartbeatSucceeded(ServerHeartbeatSucceededEvent),
+
+    /// A server heartbeat failed.
     ServerHeartbeatFailed(ServerHeartbeatFailedEvent),
 }
 
@@ -250,6 +270,64 @@ pub trait SdamEventHandler: Send + Sync {
     fn handle_server_heartbeat_failed_event(&self, _event: ServerHeartbeatFailedEvent) {}
 }
 
+/// Forwards each event to every handler in the list, in order. This can be used to register
+/// multiple handlers on a single `Client`.
+impl SdamEventHandler for Vec<Arc<dyn SdamEventHandler>> {
+    fn handle_server_description_changed_event(&self, event: ServerDescriptionChangedEvent) {
+        for handler in self {
+            handler.handle_server_description_changed_event(event.clone());
+        }
+    }
+
+    fn handle_server_opening_event(&self, event: ServerOpeningEvent) {
+        for handler in self {
+            handler.handle_server_opening_event(event.clone());
+        }
+    }
+
+    fn handle_server_closed_event(&self, event: ServerClosedEvent) {
+        for handler in self {
+            handler.handle_server_closed_event(event.clone());
+        }
+    }
+
+    fn handle_topology_description_changed_event(&self, event: TopologyDescriptionChangedEvent) {
+        for handler in self {
+            handler.handle_topology_description_changed_event(event.clone());
+        }
+    }
+
+    fn handle_topology_opening_event(&self, event: TopologyOpeningEvent) {
+        for handler in self {
+            handler.handle_topology_opening_event(event.clone());
+        }
+    }
+
+    fn handle_topology_closed_event(&self, event: TopologyClosedEvent) {
+        for handler in self {
+            handler.handle_topology_closed_event(event.clone());
+        }
+    }
+
+    fn handle_server_heartbeat_started_event(&self, event: ServerHeartbeatStartedEvent) {
+        for handler in self {
+            handler.handle_server_heartbeat_started_event(event.clone());
+        }
+    }
+
+    fn handle_server_heartbeat_succeeded_event(&self, event: ServerHeartbeatSucceededEvent) {
+        for handler in self {
+            handler.handle_server_heartbeat_succeeded_event(event.clone());
+        }
+    }
+
+    fn handle_server_heartbeat_failed_event(&self, event: ServerHeartbeatFailedEvent) {
+        for handler in self {
+            handler.handle_server_heartbeat_failed_event(event.clone());
+        }
+    }
+}
+
 pub(crate) fn handle_sdam_event(handler: &dyn SdamEventHandler, event: SdamEvent) {
     match event {
         SdamEvent::ServerClosed(event) => handler.handle_server_closed_event(event),
@@ -0,0 +1,320 @@
+//! Contains the types used to receive events as asynchronous streams.
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex, RwLock},
+    task::{Context, Poll, Waker},
+};
+
+use futures_core::Stream;
+use tokio::sync::Notify;
+use typed_builder::TypedBuilder;
+
+use crate::event::{cmap::CmapEvent, command::CommandEvent, sdam::SdamEvent};
+
+/// The number of events buffered by an [`EventStream`] if no capacity is specified.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Specifies the options for creating an [`EventStream`].
+#[derive(Clone, Debug, Default, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[non_exhaustive]
+pub struct EventStreamOptions {
+    /// The maximum number of events that will be buffered by the stream while waiting to be
+    /// consumed.
+    ///
+    /// The default value is 1024.
+    pub capacity: Option<usize>,
+
+    /// What to do when an event is emitted while the stream's buffer is full.
+    ///
+    /// The default value is [`OverflowPolicy::DropOldest`].
+    pub overflow_policy: Option<OverflowPolicy>,
+}
+
+/// Specifies how an [`EventStream`] behaves when its consumer falls behind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OverflowPolicy {
+    /// Discard the oldest buffered event to make room for the new one. The number of discarded
+    /// events can be retrieved via [`EventStream::dropped_events`].
+    DropOldest,
+
+    /// Discard the new event, keeping the buffered ones. The number of discarded events can be
+    /// retrieved via [`EventStream::dropped_events`].
+    DropNewest,
+
+    /// Never discard events. Instead, operations executed by the `Client` will wait for the
+    /// stream's buffer to have room before starting. This wait counts against the operation's
+    /// timeout, if any; otherwise, the operation starts anyway once the client's
+    /// [`server_selection_timeout`](crate::options::ClientOptions::server_selection_timeout) has
+    /// elapsed. Operations that only release server-side resources (e.g. killing cursors, ending
+    /// sessions or aborting transactions) never wait.
+    ///
+    /// Events emitted by operations that are already in progress or by background tasks (e.g.
+    /// server monitoring) are still buffered, so the buffer may grow beyond its capacity while
+    /// the consumer is behind. Consumers should not wait on operations executed by the same
+    /// `Client` while handling events, as those operations may in turn wait for the consumer.
+    Backpressure,
+}
+
+/// A bounded stream of events emitted by a [`Client`](crate::Client).
+///
+/// Event streams are created via [`Client::command_events`](crate::Client::command_events),
+/// [`Client::cmap_events`](crate::Client::cmap_events) and
+/// [`Client::sdam_events`](crate::Client::sdam_events). A stream only receives the events emitted
+/// after it was created, and it ends once the `Client` and all of its resources have been dropped
+/// or shut down. Dropping the stream unregisters it.
+///
+/// ```rust
+/// # use futures::stream::StreamExt;
+/// # use mongodb::{
+/// #     error::Result,
+/// #     event::{command::CommandEvent, EventStreamOptions, OverflowPolicy},
+/// #     Client,
+/// # };
+/// #
+/// # #[cfg(all(not(feature = "sync"), not(feature = "tokio-sync")))]
+/// # async fn do_stuff(client: Client) -> Result<()> {
+/// let options = EventStreamOptions::builder()
+///     .capacity(100)
+///     .overflow_policy(OverflowPolicy::Backpressure)
+///     .build();
+/// let mut events = client.command_events(options);
+/// tokio::spawn(async move {
+///     while let Some(event) = events.next().await {
+///         if let CommandEvent::Failed(event) = event {
+///             // ship the failure to a metrics backend
+///         }
+///     }
+/// });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EventStream<T> {
+    queue: Arc<EventQueue<T>>,
+}
+
+impl<T> EventStream<T> {
+    /// The number of events that were discarded because the stream's buffer was full.
+    pub fn dropped_events(&self) -> u64 {
+        self.queue.lock().dropped
+    }
+}
+
+impl<T> Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.queue.lock();
+        match state.events.pop_front() {
+            Some(event) => {
+                if state.events.len() < self.queue.capacity {
+                    self.queue.space_available.notify_waiters();
+                }
+                Poll::Ready(Some(event))
+            }
+            None if state.closed => Poll::Ready(None),
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> Drop for EventStream<T> {
+    fn drop(&mut self) {
+        let mut state = self.queue.lock();
+        state.receiver_dropped = true;
+        state.events.clear();
+        self.queue.space_available.notify_waiters();
+    }
+}
+
+/// The buffer shared between an [`EventStream`] and the driver.
+#[derive(Debug)]
+struct EventQueue<T> {
+    state: Mutex<EventQueueState<T>>,
+    capacity: usize,
+    overflow_policy: OverflowPolicy,
+
+    /// Notified whenever room is freed up in the buffer.
+    space_available: Notify,
+}
+
+#[derive(Debug)]
+struct EventQueueState<T> {
+    events: VecDeque<T>,
+    dropped: u64,
+    waker: Option<Waker>,
+    receiver_dropped: bool,
+    closed: bool,
+}
+
+impl<T> EventQueue<T> {
+    fn new(options: EventStreamOptions) -> Self {
+        Self {
+            state: Mutex::new(EventQueueState {
+                events: VecDeque::new(),
+                dropped: 0,
+                waker: None,
+                receiver_dropped: false,
+                closed: false,
+            }),
+            // A capacity of zero would make every stream either drop every event or block every
+            // operation, so treat it as one.
+            capacity: options.capacity.unwrap_or(DEFAULT_CAPACITY).max(1),
+            overflow_policy: options
+                .overflow_policy
+                .unwrap_or(OverflowPolicy::DropOldest),
+            space_available: Notify::new(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, EventQueueState<T>> {
+        self.state.lock().unwrap()
+    }
+
+    /// Buffer an event, returning `false` if the stream has been dropped.
+    fn push(&self, event: T) -> bool {
+        let mut state = self.lock();
+        if state.receiver_dropped {
+            return false;
+        }
+
+        if state.events.len() >= self.capacity {
+            match self.overflow_policy {
+                OverflowPolicy::DropOldest => {
+                    state.events.pop_front();
+                    state.dropped += 1;
+                }
+                OverflowPolicy::DropNewest => {
+                    state.dropped += 1;
+                    return true;
+                }
+                OverflowPolicy::Backpressure => {}
+            }
+        }
+        state.events.push_back(event);
+
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+        true
+    }
+
+    fn close(&self) {
+        let mut state = self.lock();
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        let state = self.lock();
+        !state.receiver_dropped && state.events.len() >= self.capacity
+    }
+
+    async fn wait_for_capacity(&self) {
+        loop {
+            let notified = self.space_available.notified();
+            if !self.is_full() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// The set of streams registered for one category of events.
+#[derive(Debug)]
+pub(crate) struct EventStreamSenders<T> {
+    queues: RwLock<Vec<Arc<EventQueue<T>>>>,
+}
+
+impl<T> Default for EventStreamSenders<T> {
+    fn default() -> Self {
+        Self {
+            queues: Default::default(),
+        }
+    }
+}
+
+impl<T: Clone> EventStreamSenders<T> {
+    pub(crate) fn subscribe(&self, options: Option<EventStreamOptions>) -> EventStream<T> {
+        let queue = Arc::new(EventQueue::new(options.unwrap_or_default()));
+        self.write().push(queue.clone());
+        EventStream { queue }
+    }
+
+    /// Whether any streams are registered. Used to avoid generating events that nobody will
+    /// receive.
+    pub(crate) fn is_active(&self) -> bool {
+        !self.read().is_empty()
+    }
+
+    /// Send a copy of the event to each registered stream.
+    pub(crate) fn send(&self, event: &T) {
+        let mut any_dropped = false;
+        for queue in self.read().iter() {
+            any_dropped |= !queue.push(event.clone());
+        }
+        if any_dropped {
+            self.write().retain(|queue| !queue.lock().receiver_dropped);
+        }
+    }
+
+    /// Wait until every stream using [`OverflowPolicy::Backpressure`] has room in its buffer.
+    async fn wait_for_capacity(&self) {
+        let queues: Vec<_> = self
+            .read()
+            .iter()
+            .filter(|queue| queue.overflow_policy == OverflowPolicy::Backpressure)
+            .cloned()
+            .collect();
+        for queue in queues {
+            queue.wait_for_capacity().await;
+        }
+    }
+
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, Vec<Arc<EventQueue<T>>>> {
+        self.queues.read().unwrap()
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, Vec<Arc<EventQueue<T>>>> {
+        self.queues.write().unwrap()
+    }
+}
+
+impl<T> Drop for EventStreamSenders<T> {
+    fn drop(&mut self) {
+        if let Ok(queues) = self.queues.get_mut() {
+            for queue in queues.iter() {
+                queue.close();
+            }
+        }
+    }
+}
+
+/// The event streams registered on a `Client`. These are shared by all of the components of the
+/// client that emit events, and the streams are closed once all of those components have been
+/// dropped.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct EventStreams {
+    pub(crate) command: Arc<EventStreamSenders<CommandEvent>>,
+    pub(crate) cmap: Arc<EventStreamSenders<CmapEvent>>,
+    pub(crate) sdam: Arc<EventStreamSenders<SdamEvent>>,
+}
+
+impl EventStreams {
+    /// Wait until every stream using [`OverflowPolicy::Backpressure`] has room in its buffer.
+    pub(crate) async fn wait_for_capacity(&self) {
+        self.command.wait_for_capacity().await;
+        self.cmap.wait_for_capacity().await;
+        self.sdam.wait_for_capacity().await;
+    }
+}
@@ -1,4 +1,5 @@
 use std::{
+    io::SeekFrom,
     marker::Unpin,
     ops::Range,
     pin::Pin,
@@ -7,13 +8,13 @@ use std::{
 
 use futures_util::{
     future::{BoxFuture, FutureExt},
-    io::{AsyncRead, AsyncWrite, AsyncWriteExt},
+    io::{AsyncRead, AsyncSeek, AsyncWrite, AsyncWriteExt},
 };
 
 use super::{options::GridFsDownloadByNameOptions, Chunk, FilesCollectionDocument, GridFsBucket};
 use crate::{
     bson::{doc, Bson},
-    error::{ErrorKind, GridFsErrorKind, GridFsFileIdentifier, Result},
+    error::{Error, ErrorKind, GridFsErrorKind, GridFsFileIdentifier, Result},
     options::{FindOneOptions, FindOptions},
     Collection,
     Cursor,
@@ -220,6 +221,12 @@ impl GridFsBucket {
 /// # }
 /// ```
 ///
+/// # Seeking within the Stream
+/// The `GridFsDownloadStream` type also implements [`futures_io::AsyncSeek`], so reads can start
+/// at any offset in the file. Seeking does not perform any I/O; the chunks containing the new
+/// position are fetched on the next read. To read only part of a file, use
+/// [`GridFsBucket::open_download_stream_range`].
+///
 /// # Using [`tokio::io::AsyncRead`]
 /// Users who prefer to use tokio's `AsyncRead` trait can use the [`tokio_util::compat`] module.
 ///
@@ -237,6 +244,14 @@ pub struct GridFsDownloadStream {
     state: State,
     current_n: u32,
     file: FilesCollectionDocument,
+    chunks: Collection<Chunk<'static>>,
+    // The offset in the file of the next byte to be returned from the stream.
+    position: u64,
+    // The offset in the file at which the stream ends.
+    end: u64,
+    // The number of bytes to discard from the start of the next chunk read, used when the stream
+    // starts partway through a chunk.
+    skip: usize,
 }
 
 type GetBytesFuture = BoxFuture<'static, Result<(Vec<u8>, Box<Cursor<Chunk<'static>>>)>>;
@@ -251,7 +266,8 @@ enum State {
 
 struct Idle {
     buffer: Vec<u8>,
-    cursor: Box<Cursor<Chunk<'static>>>,
+    // The cursor is opened lazily after a seek, when the next read occurs.
+    cursor: Option<Box<Cursor<Chunk<'static>>>>,
 }
 
 impl State {
@@ -268,22 +284,69 @@ impl GridFsDownloadStream {
     async fn new(
         file: FilesCollectionDocument,
         chunks: &Collection<Chunk<'static>>,
+        range: Option<Range<u64>>,
     ) -> Result<Self> {
-        let initial_state = if file.length == 0 {
+        let Range { start, end } = range.unwrap_or(0..file.length);
+        if start > end || end > file.length {
+            return Err(Error::invalid_argument(format!(
+                "invalid range {}..{} for file of length {}",
+                start, end, file.length
+            )));
+        }
+
+        let mut stream = Self {
+            state: State::Done,
+            current_n: 0,
+            file,
+            chunks: chunks.clone(),
+            position: 0,
+            end,
+            skip: 0,
+        };
+        stream.seek_to(start);
+
+        // Open the cursor eagerly so that errors are reported when the stream is created.
+        if matches!(stream.state, State::Idle(_)) {
+            let cursor = open_chunks_cursor(
+                stream.chunks.clone(),
+                stream.file.id.clone(),
+                stream.current_n,
+                stream.end_n(),
+            )
+            .await?;
+            stream.state = State::Idle(Some(Idle {
+                buffer: Vec::new(),
+                cursor: Some(Box::new(cursor)),
+            }));
+        }
+        Ok(stream)
+    }
+
+    /// The offset in the file of the next byte that will be read from this stream.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// The index of the chunk after the last one containing bytes within the stream's range.
+    fn end_n(&self) -> u32 {
+        FilesCollectionDocument::n_from_vals(self.end, self.file.chunk_size_bytes)
+    }
+
+    /// Resets the stream to begin reading at `position`. The chunks cursor is reopened on the
+    /// next read.
+    fn seek_to(&mut self, position: u64) {
+        let chunk_size_bytes = self.file.chunk_size_bytes as u64;
+        self.position = position;
+        self.current_n = (position / chunk_size_bytes) as u32;
+        self.skip = (position % chunk_size_bytes) as usize;
+        self.state = if position >= self.end {
             State::Done
         } else {
-            let options = FindOptions::builder().sort(doc! { "n": 1 }).build();
-            let cursor = chunks.find(doc! { "files_id": &file.id }, options).await?;
             State::Idle(Some(Idle {
                 buffer: Vec::new(),
-                cursor: Box::new(cursor),
+                cursor: None,
             }))
         };
-        Ok(Self {
-            state: initial_state,
-            current_n: 0,
-            file,
-        })
     }
 }
 
@@ -299,34 +362,48 @@ impl AsyncRead for GridFsDownloadStream {
             State::Idle(idle) => {
                 let Idle { buffer, cursor } = idle.take().unwrap();
 
-                if !buffer.is_empty() {
-                    Ok((buffer, cursor))
-                } else {
-                    let chunks_in_buf = FilesCollectionDocument::n_from_vals(
-                        buf.len() as u64,
-                        stream.file.chunk_size_bytes,
-                    );
-                    // We should read from current_n to chunks_in_buf + current_n, or, if that would
-                    // exceed the total number of chunks in the file, to the last chunk in the file.
-                    let final_n = std::cmp::min(chunks_in_buf + stream.current_n, stream.file.n());
-                    let n_range = stream.current_n..final_n;
-
-                    stream.current_n = final_n;
-
-                    let new_future = stream.state.set_busy(
-                        get_bytes(
-                            cursor,
-                            buffer,
-                            n_range,
+                match cursor {
+                    Some(cursor) if !buffer.is_empty() => Ok((buffer, cursor)),
+                    _ => {
+                        let chunks_in_buf = FilesCollectionDocument::n_from_vals(
+                            buf.len() as u64 + stream.skip as u64,
                             stream.file.chunk_size_bytes,
-                            stream.file.length,
-                        )
-                        .boxed(),
-                    );
-
-                    match new_future.poll_unpin(cx) {
-                        Poll::Ready(result) => result,
-                        Poll::Pending => return Poll::Pending,
+                        );
+                        // We should read from current_n to chunks_in_buf + current_n, or, if that
+                        // would exceed the last chunk in the stream's range, to that chunk.
+                        let final_n =
+                            std::cmp::min(chunks_in_buf + stream.current_n, stream.end_n());
+                        let n_range = stream.current_n..final_n;
+
+                        let cursor = match cursor {
+                            Some(cursor) => futures_util::future::ready(Ok(cursor)).boxed(),
+                            None => open_chunks_cursor(
+                                stream.chunks.clone(),
+                                stream.file.id.clone(),
+                                stream.current_n,
+                                stream.end_n(),
+                            )
+                            .map(|result| result.map(Box::new))
+                            .boxed(),
+                        };
+
+                        stream.current_n = final_n;
+
+                        let new_future = stream.state.set_busy(
+                            get_bytes(
+                                cursor,
+                                buffer,
+                                n_range,
+                                stream.file.chunk_size_bytes,
+                                stream.file.length,
+                            )
+                            .boxed(),
+                        );
+
+                        match new_future.poll_unpin(cx) {
+                            Poll::Ready(result) => result,
+                            Poll::Pending => return Poll::Pending,
+                        }
                     }
                 }
             }
@@ -340,11 +417,26 @@ impl AsyncRead for GridFsDownloadStream {
 
         match result {
             Ok((mut buffer, cursor)) => {
+                // Discard any bytes preceding the start of the stream in the first chunk read.
+                let skipped = std::cmp::min(stream.skip, buffer.len());
+                buffer.drain(0..skipped);
+                stream.skip -= skipped;
+
+                // Discard any bytes following the end of the stream in the last chunk read.
+                let remaining = stream.end - stream.position;
+                if buffer.len() as u64 > remaining {
+                    buffer.truncate(remaining as usize);
+                }
+
                 let bytes_to_write = std::cmp::min(buffer.len(), buf.len());
                 buf[..bytes_to_write].copy_from_slice(buffer.drain(0..bytes_to_write).as_slice());
+                stream.position += bytes_to_write as u64;
 
-                stream.state = if !buffer.is_empty() || cursor.has_next() {
-                    State::Idle(Some(Idle { buffer, cursor }))
+                stream.state = if stream.position < stream.end {
+                    State::Idle(Some(Idle {
+                        buffer,
+                        cursor: Some(cursor),
+                    }))
                 } else {
                     State::Done
                 };
@@ -359,13 +451,88 @@ impl AsyncRead for GridFsDownloadStream {
     }
 }
 
+impl AsyncSeek for GridFsDownloadStream {
+    /// Seeks to an offset in the file. Offsets are relative to the whole file rather than the
+    /// range the stream was opened with, and [`SeekFrom::End`] is relative to the end of the file.
+    /// Reads will never return bytes past the end of the stream's range.
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<std::result::Result<u64, futures_util::io::Error>> {
+        let stream = self.get_mut();
+
+        let (base, offset) = match pos {
+            SeekFrom::Start(offset) => (offset, 0),
+            SeekFrom::End(offset) => (stream.file.length, offset),
+            SeekFrom::Current(offset) => (stream.position, offset),
+        };
+        let position = if offset >= 0 {
+            base.checked_add(offset as u64)
+        } else {
+            base.checked_sub(offset.unsigned_abs())
+        };
+
+        match position {
+            Some(position) => Poll::Ready(Ok(stream.seek_within(position))),
+            None => Poll::Ready(Err(futures_util::io::Error::new(
+                futures_util::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ))),
+        }
+    }
+}
+
+impl GridFsDownloadStream {
+    fn seek_within(&mut self, position: u64) -> u64 {
+        if position == self.position {
+            return position;
+        }
+
+        // Seeking forward within the buffered bytes does not require fetching any chunks.
+        if let State::Idle(Some(Idle {
+            ref mut buffer,
+            cursor: Some(_),
+        })) = self.state
+        {
+            if self.skip == 0
+                && position > self.position
+                && position - self.position < buffer.len() as u64
+            {
+                buffer.drain(0..(position - self.position) as usize);
+                self.position = position;
+                return position;
+            }
+        }
+
+        self.seek_to(position);
+        position
+    }
+}
+
+async fn open_chunks_cursor(
+    chunks: Collection<Chunk<'static>>,
+    files_id: Bson,
+    start_n: u32,
+    end_n: u32,
+) -> Result<Cursor<Chunk<'static>>> {
+    let options = FindOptions::builder().sort(doc! { "n": 1 }).build();
+    let filter = if start_n == 0 {
+        doc! { "files_id": files_id, "n": { "$lt": end_n } }
+    } else {
+        doc! { "files_id": files_id, "n": { "$gte": start_n, "$lt": end_n } }
+    };
+    chunks.find(filter, options).await
+}
+
 async fn get_bytes(
-    mut cursor: Box<Cursor<Chunk<'static>>>,
+    cursor: BoxFuture<'static, Result<Box<Cursor<Chunk<'static>>>>>,
     mut buffer: Vec<u8>,
     n_range: Range<u32>,
     chunk_size_bytes: u32,
     file_len: u64,
 ) -> Result<(Vec<u8>, Box<Cursor<Chunk<'static>>>)> {
+    let mut cursor = cursor.await?;
     for n in n_range {
         if !cursor.advance().await? {
             return Err(ErrorKind::GridFs(GridFsErrorKind::MissingChunk { n }).into());
@@ -401,7 +568,35 @@ impl GridFsBucket {
     /// the contents of the stored file specified by `id`.
     pub async fn open_download_stream(&self, id: Bson) -> Result<GridFsDownloadStream> {
         let file = self.find_file_by_id(&id).await?;
-        GridFsDownloadStream::new(file, self.chunks()).await
+        GridFsDownloadStream::new(file, self.chunks(), None).await
+    }
+
+    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read the bytes
+    /// within `range` of the stored file specified by `id`. Only the chunks containing those bytes
+    /// are fetched from the server.
+    ///
+    /// This method returns an error if the range is decreasing or extends past the end of the
+    /// file.
+    ///
+    /// ```rust
+    /// # use mongodb::{bson::Bson, error::Result, gridfs::GridFsBucket};
+    /// # async fn range_example(bucket: GridFsBucket, id: Bson) -> Result<()> {
+    /// use futures_util::io::AsyncReadExt;
+    ///
+    /// // Read bytes 1024 through 2047 of the file.
+    /// let mut buf = Vec::new();
+    /// let mut download_stream = bucket.open_download_stream_range(id, 1024..2048).await?;
+    /// download_stream.read_to_end(&mut buf).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open_download_stream_range(
+        &self,
+        id: Bson,
+        range: Range<u64>,
+    ) -> Result<GridFsDownloadStream> {
+        let file = self.find_file_by_id(&id).await?;
+        GridFsDownloadStream::new(file, self.chunks(), Some(range)).await
     }
 
     /// Opens and returns a [`GridFsDownloadStream`] from which the application can read
@@ -419,6 +614,6 @@ impl GridFsBucket {
         let file = self
             .find_file_by_name(filename.as_ref(), options.into())
             .await?;
-        GridFsDownloadStream::new(file, self.chunks()).await
+        GridFsDownloadStream::new(file, self.chunks(), None).await
     }
 }
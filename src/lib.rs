@@ -49,21 +49,24 @@
 //!
 //! ### All Feature flags
 //!
-//! | Feature              | Description                                                                                                                           | Extra dependencies                  | Default |
-//! |:---------------------|:--------------------------------------------------------------------------------------------------------------------------------------|:------------------------------------|:--------|
-//! | `tokio-runtime`      | Enable support for the `tokio` async runtime                                                                                          | `tokio` 1.0 with the `full` feature | yes     |
-//! | `async-std-runtime`  | Enable support for the `async-std` runtime                                                                                            | `async-std` 1.0                     | no      |
-//! | `sync`               | Expose the synchronous API (`mongodb::sync`), using an async-std backend. Cannot be used with the `tokio-runtime` feature flag.       | `async-std` 1.0                     | no      |
-//! | `tokio-sync`         | Expose the synchronous API (`mongodb::sync`), using a tokio backend. Cannot be used with the `async-std-runtime` feature flag.        | `tokio` 1.0 with the `full` feature | no      |
-//! | `aws-auth`           | Enable support for the MONGODB-AWS authentication mechanism.                                                                          | `reqwest` 0.11                      | no      |
-//! | `bson-uuid-0_8`      | Enable support for v0.8 of the [`uuid`](docs.rs/uuid/0.8) crate in the public API of the re-exported `bson` crate.                    | n/a                                 | no      |
-//! | `bson-uuid-1`        | Enable support for v1.x of the [`uuid`](docs.rs/uuid/1.0) crate in the public API of the re-exported `bson` crate.                    | n/a                                 | no      |
-//! | `bson-chrono-0_4`    | Enable support for v0.4 of the [`chrono`](docs.rs/chrono/0.4) crate in the public API of the re-exported `bson` crate.                | n/a                                 | no      |
-//! | `bson-serde_with`    | Enable support for the [`serde_with`](docs.rs/serde_with/latest) crate in the public API of the re-exported `bson` crate.             | `serde_with` 1.0                    | no      |
-//! | `zlib-compression`   | Enable support for compressing messages with [`zlib`](https://zlib.net/)                                                              | `flate2` 1.0                        | no      |
-//! | `zstd-compression`   | Enable support for compressing messages with [`zstd`](http://facebook.github.io/zstd/).  This flag requires Rust version 1.54.        | `zstd` 0.9.0                        | no      |
-//! | `snappy-compression` | Enable support for compressing messages with [`snappy`](http://google.github.io/snappy/)                                              | `snap` 1.0.5                        | no      |
-//! | `openssl-tls`        | Switch TLS connection handling to use ['openssl'](https://docs.rs/openssl/0.10.38/).                                                  | `openssl` 0.10.38                   | no      |
+//! | Feature                  | Description                                                                                                                           | Extra dependencies                  | Default |
+//! |:-------------------------|:--------------------------------------------------------------------------------------------------------------------------------------|:------------------------------------|:--------|
+//! | `tokio-runtime`          | Enable support for the `tokio` async runtime                                                                                          | `tokio` 1.0 with the `full` feature | yes     |
+//! | `async-std-runtime`      | Enable support for the `async-std` runtime                                                                                            | `async-std` 1.0                     | no      |
+//! | `sync`                   | Expose the synchronous API (`mongodb::sync`), using an async-std backend. Cannot be used with the `tokio-runtime` feature flag.       | `async-std` 1.0                     | no      |
+//! | `tokio-sync`             | Expose the synchronous API (`mongodb::sync`), using a tokio backend. Cannot be used with the `async-std-runtime` feature flag.        | `tokio` 1.0 with the `full` feature | no      |
+//! | `aws-auth`               | Enable support for the MONGODB-AWS authentication mechanism.                                                                          | `reqwest` 0.11                      | no      |
+//! | `gssapi-auth`            | Enable support for the GSSAPI (Kerberos) authentication mechanism.                                                                    | `cross-krb5` 0.3                    | no      |
+//! | `bson-uuid-0_8`          | Enable support for v0.8 of the [`uuid`](docs.rs/uuid/0.8) crate in the public API of the re-exported `bson` crate.                    | n/a                                 | no      |
+//! | `bson-uuid-1`            | Enable support for v1.x of the [`uuid`](docs.rs/uuid/1.0) crate in the public API of the re-exported `bson` crate.                    | n/a                                 | no      |
+//! | `bson-chrono-0_4`        | Enable support for v0.4 of the [`chrono`](docs.rs/chrono/0.4) crate in the public API of the re-exported `bson` crate.                | n/a                                 | no      |
+//! | `bson-serde_with`        | Enable support for the [`serde_with`](docs.rs/serde_with/latest) crate in the public API of the re-exported `bson` crate.             | `serde_with` 1.0                    | no      |
+//! | `zlib-compression`       | Enable support for compressing messages with [`zlib`](https://zlib.net/)                                                              | `flate2` 1.0                        | no      |
+//! | `zstd-compression`       | Enable support for compressing messages with [`zstd`](http://facebook.github.io/zstd/).  This flag requires Rust version 1.54.        | `zstd` 0.9.0                        | no      |
+//! | `snappy-compression`     | Enable support for compressing messages with [`snappy`](http://google.github.io/snappy/)                                              | `snap` 1.0.5                        | no      |
+//! | `openssl-tls`            | Switch TLS connection handling to use ['openssl'](https://docs.rs/openssl/0.10.46/).                                                  | `openssl` 0.10.46                   | no      |
+//! | `encrypted-private-keys` | Enable support for encrypted PKCS #8 private keys with the default rustls TLS backend. Always supported with `openssl-tls`.           | `pkcs8` 0.9                         | no      |
+//! | `test-util`              | Expose the [`test_util`] module, which contains an in-process mock server for testing applications without a deployment.              | n/a                                 | no      |
 //!
 //! # Example Usage
 //!
@@ -333,6 +336,7 @@ mod operation;
 pub mod results;
 pub(crate) mod runtime;
 mod sdam;
+mod search_index;
 mod selection_criteria;
 mod srv;
 #[cfg(any(feature = "sync", feature = "tokio-sync", docsrs))]
@@ -340,6 +344,9 @@ mod srv;
 pub mod sync;
 #[cfg(test)]
 mod test;
+#[cfg(any(feature = "test-util", test, docsrs))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-util")))]
+pub mod test_util;
 #[cfg(feature = "tracing-unstable")]
 mod trace;
 
@@ -347,7 +354,7 @@ mod trace;
 pub use crate::client::csfle::client_encryption;
 pub use crate::{
     client::{session::ClientSession, Client},
-    coll::Collection,
+    coll::{Collection, CollectionExplain},
     cursor::{
         session::{SessionCursor, SessionCursorStream},
         Cursor,
@@ -358,7 +365,14 @@ pub use crate::{
 #[cfg(feature = "in-use-encryption-unstable")]
 pub use ::mongocrypt;
 
-pub use {client::session::ClusterTime, coll::Namespace, index::IndexModel, sdam::public::*};
+pub use {
+    client::session::ClusterTime,
+    cmap::ConnectionPoolStatus,
+    coll::Namespace,
+    index::IndexModel,
+    sdam::public::*,
+    search_index::SearchIndexModel,
+};
 
 #[cfg(all(feature = "tokio-runtime", feature = "sync",))]
 compile_error!(
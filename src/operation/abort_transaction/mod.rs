@@ -72,6 +72,10 @@ impl OperationWithDefaults for AbortTransaction {
         }
     }
 
+    fn is_cleanup(&self) -> bool {
+        true
+    }
+
     fn write_concern(&self) -> Option<&WriteConcern> {
         self.write_concern.as_ref()
     }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{
     bson::{doc, Document},
     change_stream::{event::ResumeToken, ChangeStreamData, WatchArgs},
@@ -125,6 +127,10 @@ impl OperationWithDefaults for ChangeStreamAggregate {
         self.inner.write_concern()
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout()
+    }
+
     fn retryability(&self) -> Retryability {
         self.inner.retryability()
     }
@@ -3,6 +3,8 @@ mod change_stream;
 #[cfg(test)]
 mod test;
 
+use std::time::Duration;
+
 use crate::{
     bson::{doc, Bson, Document},
     bson_util,
@@ -145,6 +147,10 @@ impl OperationWithDefaults for Aggregate {
             .and_then(|opts| opts.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
         if self.is_out_or_merge() {
             Retryability::None
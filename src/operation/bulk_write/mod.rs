@@ -0,0 +1,318 @@
+#[cfg(test)]
+mod test;
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{
+    bson::{doc, Bson, Document},
+    bson_util,
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::{BulkWriteError, ErrorKind, Result, WriteConcernError, RETRYABLE_WRITE_ERROR},
+    operation::{
+        append_options,
+        delete::delete_statement,
+        remove_empty_write_concern,
+        update::{update_statement, UpdateBody},
+        OperationWithDefaults,
+        Retryability,
+        WriteResponseBody,
+    },
+    options::{BulkWriteOptions, UpdateModifications, WriteConcern, WriteModel},
+    Namespace,
+};
+
+/// The kind of server command used to execute a batch of non-insert write models.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BatchKind {
+    Update,
+    Delete,
+}
+
+impl BatchKind {
+    /// Gets the kind of batch the given model belongs to, or `None` if the model is an insert.
+    pub(crate) fn of<T>(model: &WriteModel<T>) -> Option<Self> {
+        match model {
+            WriteModel::InsertOne(_) => None,
+            WriteModel::UpdateOne(_) | WriteModel::UpdateMany(_) | WriteModel::ReplaceOne(_) => {
+                Some(Self::Update)
+            }
+            WriteModel::DeleteOne(_) | WriteModel::DeleteMany(_) => Some(Self::Delete),
+        }
+    }
+
+    fn command_name(self) -> &'static str {
+        match self {
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+
+    fn statements_key(self) -> &'static str {
+        match self {
+            Self::Update => "updates",
+            Self::Delete => "deletes",
+        }
+    }
+}
+
+/// Constructs the update or delete statement corresponding to the given model, using the same
+/// statement format as the single-statement [`Update`](super::Update) and
+/// [`Delete`](super::Delete) operations.
+pub(crate) fn write_statement<T: Serialize>(model: &WriteModel<T>) -> Result<Document> {
+    match model {
+        WriteModel::InsertOne(_) => Err(ErrorKind::Internal {
+            message: "insert models cannot be converted to write statements".to_string(),
+        }
+        .into()),
+        WriteModel::UpdateOne(model) => {
+            if let UpdateModifications::Document(ref d) = model.update {
+                bson_util::update_document_check(d)?;
+            }
+            update_statement(
+                &model.filter,
+                model.update.to_bson(),
+                false,
+                model.upsert,
+                model.array_filters.as_ref(),
+                model.hint.as_ref(),
+                model.collation.as_ref(),
+            )
+        }
+        WriteModel::UpdateMany(model) => {
+            if let UpdateModifications::Document(ref d) = model.update {
+                bson_util::update_document_check(d)?;
+            }
+            update_statement(
+                &model.filter,
+                model.update.to_bson(),
+                true,
+                model.upsert,
+                model.array_filters.as_ref(),
+                model.hint.as_ref(),
+                model.collation.as_ref(),
+            )
+        }
+        WriteModel::ReplaceOne(model) => {
+            let replacement = bson::to_document(&model.replacement)?;
+            bson_util::replacement_document_check(&replacement)?;
+            update_statement(
+                &model.filter,
+                Bson::Document(replacement),
+                false,
+                model.upsert,
+                None,
+                model.hint.as_ref(),
+                model.collation.as_ref(),
+            )
+        }
+        WriteModel::DeleteOne(model) => delete_statement(
+            &model.filter,
+            1,
+            model.collation.as_ref(),
+            model.hint.as_ref(),
+        ),
+        WriteModel::DeleteMany(model) => delete_statement(
+            &model.filter,
+            0,
+            model.collation.as_ref(),
+            model.hint.as_ref(),
+        ),
+    }
+}
+
+/// A single `update` or `delete` command executing as many of the given statements as fit within
+/// the limits of the selected server.
+#[derive(Debug)]
+pub(crate) struct BulkWrite<'a> {
+    ns: Namespace,
+    kind: BatchKind,
+    statements: &'a [Document],
+    options: Option<BulkWriteOptions>,
+    n_attempted: usize,
+}
+
+impl<'a> BulkWrite<'a> {
+    pub(crate) fn new(
+        ns: Namespace,
+        kind: BatchKind,
+        statements: &'a [Document],
+        options: Option<BulkWriteOptions>,
+    ) -> Self {
+        Self {
+            ns,
+            kind,
+            statements,
+            options,
+            n_attempted: 0,
+        }
+    }
+
+    fn is_ordered(&self) -> bool {
+        self.options
+            .as_ref()
+            .and_then(|o| o.ordered)
+            .unwrap_or(true)
+    }
+
+    fn batch_result<T>(
+        &self,
+        response: &WriteResponseBody<T>,
+        n_modified: u64,
+        upserted: Vec<(usize, Bson)>,
+    ) -> Result<BatchResult> {
+        // Write concern errors that the server has labeled as retryable are surfaced as errors so
+        // that the executor can retry the batch.
+        if response.write_concern_error.is_some()
+            && response.labels.as_ref().map_or(false, |labels| {
+                labels.iter().any(|l| l == RETRYABLE_WRITE_ERROR)
+            })
+        {
+            response.validate()?;
+        }
+
+        Ok(BatchResult {
+            n_attempted: self.n_attempted,
+            n: response.n,
+            n_modified,
+            upserted,
+            write_errors: response.write_errors.clone(),
+            write_concern_error: response.write_concern_error.clone(),
+            labels: response.labels.clone(),
+        })
+    }
+
+    /// Whether every statement in this batch affects at most one document.
+    fn is_single_document(&self) -> bool {
+        self.statements.iter().all(|s| match self.kind {
+            BatchKind::Update => !matches!(s.get_bool("multi"), Ok(true)),
+            BatchKind::Delete => s.get("limit").and_then(bson_util::get_int) == Some(1),
+        })
+    }
+}
+
+impl<'a> OperationWithDefaults for BulkWrite<'a> {
+    type O = BatchResult;
+    type Command = Document;
+
+    const NAME: &'static str = "bulkWrite";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command> {
+        let mut size_limit = description.max_bson_object_size as u64;
+        if description.max_message_size_bytes > 0 {
+            size_limit = size_limit.min(description.max_message_size_bytes as u64);
+        }
+
+        let mut statements = Vec::new();
+        let mut size = 0;
+        for (i, statement) in self
+            .statements
+            .iter()
+            .take(description.max_write_batch_size as usize)
+            .enumerate()
+        {
+            let statement_size =
+                bson_util::array_entry_size_bytes(i, bson::to_vec(statement)?.len());
+            if size + statement_size > size_limit {
+                break;
+            }
+            statements.push(Bson::Document(statement.clone()));
+            size += statement_size;
+        }
+
+        if statements.is_empty() {
+            return Err(ErrorKind::InvalidArgument {
+                message: format!(
+                    "{} statement exceeds maxBsonObjectSize",
+                    self.kind.command_name()
+                ),
+            }
+            .into());
+        }
+        self.n_attempted = statements.len();
+
+        let mut body = doc! {
+            self.kind.command_name(): self.ns.coll.clone(),
+            self.kind.statements_key(): statements,
+        };
+
+        let mut options = self.options.clone().unwrap_or_default();
+        options.ordered = Some(self.is_ordered());
+        if self.kind == BatchKind::Delete {
+            options.bypass_document_validation = None;
+        }
+        remove_empty_write_concern!(Some(&mut options));
+        append_options(&mut body, Some(&options))?;
+
+        Ok(Command::new(
+            self.kind.command_name().to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        raw_response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        match self.kind {
+            BatchKind::Update => {
+                let response: WriteResponseBody<UpdateBody> = raw_response.body_utf8_lossy()?;
+                let upserted = response
+                    .body
+                    .upserted
+                    .iter()
+                    .flatten()
+                    .map(|u| (u.index, u.id.clone()))
+                    .collect();
+                self.batch_result(&response, response.body.n_modified, upserted)
+            }
+            BatchKind::Delete => {
+                let response: WriteResponseBody = raw_response.body_utf8_lossy()?;
+                self.batch_result(&response, 0, Vec::new())
+            }
+        }
+    }
+
+    fn write_concern(&self) -> Option<&WriteConcern> {
+        self.options.as_ref().and_then(|o| o.write_concern.as_ref())
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
+    fn retryability(&self) -> Retryability {
+        if self.is_single_document() {
+            Retryability::Write
+        } else {
+            Retryability::None
+        }
+    }
+
+    fn name(&self) -> &str {
+        self.kind.command_name()
+    }
+}
+
+/// The outcome of a single `update` or `delete` batch. Indexes are relative to the start of the
+/// batch.
+#[derive(Debug)]
+pub(crate) struct BatchResult {
+    /// The number of statements that were sent to the server in this batch.
+    pub(crate) n_attempted: usize,
+
+    pub(crate) n: u64,
+
+    pub(crate) n_modified: u64,
+
+    pub(crate) upserted: Vec<(usize, Bson)>,
+
+    pub(crate) write_errors: Option<Vec<BulkWriteError>>,
+
+    pub(crate) write_concern_error: Option<WriteConcernError>,
+
+    pub(crate) labels: Option<Vec<String>>,
+}
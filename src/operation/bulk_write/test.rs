@@ -0,0 +1,223 @@
+use pretty_assertions::assert_eq;
+
+use crate::{
+    bson::{doc, Bson, Document},
+    bson_util,
+    cmap::StreamDescription,
+    concern::{Acknowledgment, WriteConcern},
+    operation::{
+        test::handle_response_test,
+        write_statement,
+        BatchKind,
+        BulkWrite,
+        Operation,
+        Retryability,
+    },
+    options::{
+        BulkWriteOptions,
+        DeleteManyModel,
+        DeleteOneModel,
+        ReplaceOneModel,
+        UpdateManyModel,
+        UpdateOneModel,
+        WriteModel,
+    },
+    Namespace,
+};
+
+fn ns() -> Namespace {
+    Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    }
+}
+
+#[test]
+fn build_update_batch() {
+    let models: Vec<WriteModel<Document>> = vec![
+        UpdateOneModel::builder()
+            .filter(doc! { "x": 1 })
+            .update(doc! { "$inc": { "x": 1 } })
+            .upsert(true)
+            .build()
+            .into(),
+        UpdateManyModel::builder()
+            .filter(doc! { "y": 1 })
+            .update(vec![doc! { "$set": { "y": 2 } }])
+            .build()
+            .into(),
+        ReplaceOneModel::builder()
+            .filter(doc! { "z": 1 })
+            .replacement(doc! { "z": 2 })
+            .build()
+            .into(),
+    ];
+    let statements = models
+        .iter()
+        .map(write_statement)
+        .collect::<crate::error::Result<Vec<_>>>()
+        .unwrap();
+
+    let options = BulkWriteOptions::builder()
+        .ordered(false)
+        .bypass_document_validation(true)
+        .write_concern(WriteConcern {
+            w: Some(Acknowledgment::Majority),
+            ..Default::default()
+        })
+        .build();
+    let mut op = BulkWrite::new(ns(), BatchKind::Update, &statements, Some(options));
+
+    let mut cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name.as_str(), "update");
+    assert_eq!(cmd.target_db.as_str(), "test_db");
+
+    let mut expected_body = doc! {
+        "update": "test_coll",
+        "updates": [
+            { "q": { "x": 1 }, "u": { "$inc": { "x": 1 } }, "multi": false, "upsert": true },
+            { "q": { "y": 1 }, "u": [{ "$set": { "y": 2 } }], "multi": true },
+            { "q": { "z": 1 }, "u": { "z": 2 }, "multi": false },
+        ],
+        "ordered": false,
+        "bypassDocumentValidation": true,
+        "writeConcern": { "w": "majority" },
+    };
+
+    bson_util::sort_document(&mut cmd.body);
+    bson_util::sort_document(&mut expected_body);
+    assert_eq!(cmd.body, expected_body);
+
+    assert_eq!(op.retryability(), Retryability::None);
+}
+
+#[test]
+fn build_delete_batch() {
+    let models: Vec<WriteModel<Document>> = vec![
+        DeleteOneModel::builder()
+            .filter(doc! { "x": 1 })
+            .build()
+            .into(),
+        DeleteOneModel::builder()
+            .filter(doc! { "x": 2 })
+            .build()
+            .into(),
+    ];
+    let statements = models
+        .iter()
+        .map(write_statement)
+        .collect::<crate::error::Result<Vec<_>>>()
+        .unwrap();
+
+    let options = BulkWriteOptions::builder()
+        .bypass_document_validation(true)
+        .build();
+    let mut op = BulkWrite::new(ns(), BatchKind::Delete, &statements, Some(options));
+
+    let mut cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name.as_str(), "delete");
+
+    let mut expected_body = doc! {
+        "delete": "test_coll",
+        "deletes": [
+            { "q": { "x": 1 }, "limit": 1 },
+            { "q": { "x": 2 }, "limit": 1 },
+        ],
+        "ordered": true,
+    };
+
+    bson_util::sort_document(&mut cmd.body);
+    bson_util::sort_document(&mut expected_body);
+    assert_eq!(cmd.body, expected_body);
+
+    assert_eq!(op.retryability(), Retryability::Write);
+
+    let models: Vec<WriteModel<Document>> =
+        vec![DeleteManyModel::builder().filter(doc! {}).build().into()];
+    let statements = models
+        .iter()
+        .map(write_statement)
+        .collect::<crate::error::Result<Vec<_>>>()
+        .unwrap();
+    let op = BulkWrite::new(ns(), BatchKind::Delete, &statements, None);
+    assert_eq!(op.retryability(), Retryability::None);
+}
+
+#[test]
+fn build_splits_on_max_write_batch_size() {
+    let statements: Vec<Document> = (0..5)
+        .map(|i| doc! { "q": { "x": i }, "limit": 1 })
+        .collect();
+    let mut op = BulkWrite::new(ns(), BatchKind::Delete, &statements, None);
+
+    let mut description = StreamDescription::new_testing();
+    description.max_write_batch_size = 2;
+    let cmd = op.build(&description).unwrap();
+
+    assert_eq!(cmd.body.get_array("deletes").unwrap().len(), 2);
+
+    let result = handle_response_test(&op, doc! { "ok": 1, "n": 2 }).unwrap();
+    assert_eq!(result.n_attempted, 2);
+    assert_eq!(result.n, 2);
+}
+
+#[test]
+fn handle_upserts_and_write_errors() {
+    let statements: Vec<Document> = (0..3)
+        .map(|i| doc! { "q": { "x": i }, "u": { "$set": { "y": 1 } }, "upsert": true })
+        .collect();
+    let mut op = BulkWrite::new(ns(), BatchKind::Update, &statements, None);
+    op.build(&StreamDescription::new_testing()).unwrap();
+
+    let result = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1,
+            "n": 2,
+            "nModified": 1,
+            "upserted": [{ "index": 2, "_id": 5 }],
+            "writeErrors": [{ "index": 1, "code": 11000, "errmsg": "duplicate key" }],
+        },
+    )
+    .unwrap();
+
+    assert_eq!(result.n_attempted, 3);
+    assert_eq!(result.n, 2);
+    assert_eq!(result.n_modified, 1);
+    assert_eq!(result.upserted, vec![(2, Bson::Int32(5))]);
+
+    let write_errors = result.write_errors.unwrap();
+    assert_eq!(write_errors.len(), 1);
+    assert_eq!(write_errors[0].index, 1);
+    assert_eq!(write_errors[0].code, 11000);
+}
+
+#[test]
+fn handle_retryable_write_concern_error() {
+    let statements = vec![doc! { "q": { "x": 1 }, "limit": 1 }];
+    let mut op = BulkWrite::new(ns(), BatchKind::Delete, &statements, None);
+    op.build(&StreamDescription::new_testing()).unwrap();
+
+    let error = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1,
+            "n": 1,
+            "writeConcernError": { "code": 91, "errmsg": "shutting down" },
+            "errorLabels": ["RetryableWriteError"],
+        },
+    )
+    .unwrap_err();
+    assert!(error.is_write_retryable());
+
+    let result = handle_response_test(
+        &op,
+        doc! {
+            "ok": 1,
+            "n": 1,
+            "writeConcernError": { "code": 64, "errmsg": "waiting for replication timed out" },
+        },
+    )
+    .unwrap();
+    assert_eq!(result.write_concern_error.unwrap().code, 64);
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test;
 
+use std::time::Duration;
+
 use bson::Document;
 use serde::Deserialize;
 
@@ -80,6 +82,10 @@ impl OperationWithDefaults for Count {
         None
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn supports_read_concern(&self, _description: &StreamDescription) -> bool {
         true
     }
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test;
 
-use std::convert::TryInto;
+use std::{convert::TryInto, time::Duration};
 
 use serde::Deserialize;
 
@@ -63,6 +63,7 @@ impl CountDocuments {
             AggregateOptions::builder()
                 .hint(opts.hint)
                 .max_time(opts.max_time)
+                .timeout(opts.timeout)
                 .collation(opts.collation)
                 .selection_criteria(opts.selection_criteria)
                 .read_concern(opts.read_concern)
@@ -103,6 +104,10 @@ impl OperationWithDefaults for CountDocuments {
         self.aggregate.selection_criteria()
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.aggregate.timeout()
+    }
+
     fn retryability(&self) -> Retryability {
         Retryability::Read
     }
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test;
 
+use std::time::Duration;
+
 use crate::{
     bson::{doc, Document},
     cmap::{Command, RawCommandResponse, StreamDescription},
@@ -66,18 +68,12 @@ impl OperationWithDefaults for Delete {
     const NAME: &'static str = "delete";
 
     fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
-        let mut delete = doc! {
-            "q": self.filter.clone(),
-            "limit": self.limit,
-        };
-
-        if let Some(ref collation) = self.collation {
-            delete.insert("collation", bson::to_bson(&collation)?);
-        }
-
-        if let Some(ref hint) = self.hint {
-            delete.insert("hint", bson::to_bson(&hint)?);
-        }
+        let delete = delete_statement(
+            &self.filter,
+            self.limit,
+            self.collation.as_ref(),
+            self.hint.as_ref(),
+        )?;
 
         let mut body = doc! {
             Self::NAME: self.ns.coll.clone(),
@@ -114,6 +110,10 @@ impl OperationWithDefaults for Delete {
             .and_then(|opts| opts.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
         if self.limit == 1 {
             Retryability::Write
@@ -122,3 +122,26 @@ impl OperationWithDefaults for Delete {
         }
     }
 }
+
+/// Constructs a single entry of the `deletes` array of a `delete` command.
+pub(crate) fn delete_statement(
+    filter: &Document,
+    limit: u32,
+    collation: Option<&Collation>,
+    hint: Option<&Hint>,
+) -> Result<Document> {
+    let mut statement = doc! {
+        "q": filter.clone(),
+        "limit": limit,
+    };
+
+    if let Some(collation) = collation {
+        statement.insert("collation", bson::to_bson(collation)?);
+    }
+
+    if let Some(hint) = hint {
+        statement.insert("hint", bson::to_bson(hint)?);
+    }
+
+    Ok(statement)
+}
@@ -16,6 +16,7 @@ fn build() {
                 w: Some(Acknowledgment::Custom("abc".to_string())),
                 ..Default::default()
             }),
+            timeout: None,
         }),
     };
 
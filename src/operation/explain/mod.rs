@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod test;
+
+use std::time::Duration;
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    coll::options::ExplainVerbosity,
+    error::Result,
+    operation::{Operation, OperationWithDefaults},
+    results::ExplainResult,
+    selection_criteria::SelectionCriteria,
+};
+
+/// Wraps the command built by another operation in an `explain` command.
+#[derive(Debug)]
+pub(crate) struct Explain<T> {
+    inner: T,
+    verbosity: ExplainVerbosity,
+}
+
+impl<T> Explain<T> {
+    pub(crate) fn new(inner: T, verbosity: ExplainVerbosity) -> Self {
+        Self { inner, verbosity }
+    }
+}
+
+impl<T: Operation<Command = Document>> OperationWithDefaults for Explain<T> {
+    type O = ExplainResult;
+    type Command = Document;
+    const NAME: &'static str = "explain";
+
+    fn build(&mut self, description: &StreamDescription) -> Result<Command> {
+        let inner = self.inner.build(description)?;
+
+        // The server rejects explain commands that specify a write concern on the inner command.
+        let mut body = inner.body;
+        body.remove("writeConcern");
+
+        let body = doc! {
+            Self::NAME: body,
+            "verbosity": self.verbosity.as_str(),
+        };
+
+        Ok(Command::new(Self::NAME.to_string(), inner.target_db, body))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        ExplainResult::from_document(response.body()?)
+    }
+
+    fn selection_criteria(&self) -> Option<&SelectionCriteria> {
+        self.inner.selection_criteria()
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.inner.timeout()
+    }
+}
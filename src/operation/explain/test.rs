@@ -0,0 +1,165 @@
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    coll::{options::ExplainVerbosity, Namespace},
+    operation::{test::handle_response_test, Delete, Explain, Find, Operation},
+    options::{DeleteOptions, FindOptions, WriteConcern},
+};
+
+fn ns() -> Namespace {
+    Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    }
+}
+
+#[test]
+fn build_find() {
+    let options = FindOptions::builder().limit(5).build();
+    let find = Find::new(ns(), Some(doc! { "x": 1 }), Some(options));
+    let mut op = Explain::new(find, ExplainVerbosity::ExecutionStats);
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name, "explain");
+    assert_eq!(cmd.target_db, "test_db");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "explain": {
+                "find": "test_coll",
+                "limit": 5_i64,
+                "filter": { "x": 1 },
+            },
+            "verbosity": "executionStats",
+        }
+    );
+}
+
+#[test]
+fn build_delete_strips_write_concern() {
+    let options = DeleteOptions::builder()
+        .write_concern(WriteConcern::MAJORITY)
+        .build();
+    let delete = Delete::new(ns(), doc! { "x": 1 }, Some(1), Some(options));
+    let mut op = Explain::new(delete, ExplainVerbosity::QueryPlanner);
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    let inner = cmd.body.get_document("explain").unwrap();
+    assert_eq!(inner.get_str("delete"), Ok("test_coll"));
+    assert!(!inner.contains_key("writeConcern"));
+    assert_eq!(cmd.body.get_str("verbosity"), Ok("queryPlanner"));
+}
+
+#[test]
+fn handle_success() {
+    let find = Find::new(ns(), None, None);
+    let op = Explain::new(find, ExplainVerbosity::ExecutionStats);
+
+    let response = doc! {
+        "ok": 1,
+        "queryPlanner": {
+            "namespace": "test_db.test_coll",
+            "parsedQuery": { "x": { "$eq": 1 } },
+            "winningPlan": {
+                "stage": "FETCH",
+                "inputStage": { "stage": "IXSCAN", "indexName": "x_1" },
+            },
+            "rejectedPlans": [
+                { "stage": "FETCH", "inputStage": { "stage": "IXSCAN", "indexName": "x_-1" } },
+            ],
+        },
+        "executionStats": {
+            "executionSuccess": true,
+            "nReturned": 3,
+            "executionTimeMillis": 0,
+            "totalKeysExamined": 3,
+            "totalDocsExamined": 3,
+            "executionStages": { "stage": "FETCH" },
+        },
+    };
+
+    let result = handle_response_test(&op, response).unwrap();
+    let planner = result.query_planner.as_ref().unwrap();
+    assert_eq!(planner.namespace.as_deref(), Some("test_db.test_coll"));
+    assert_eq!(planner.rejected_plans.len(), 1);
+    let stats = result.execution_stats.as_ref().unwrap();
+    assert_eq!(stats.execution_success, Some(true));
+    assert_eq!(stats.n_returned, Some(3));
+    assert_eq!(stats.total_docs_examined, Some(3));
+    assert!(result.shards.is_empty());
+    assert_eq!(result.indexes_used(), vec!["x_1".to_string()]);
+}
+
+#[test]
+fn handle_sharded_find() {
+    let find = Find::new(ns(), None, None);
+    let op = Explain::new(find, ExplainVerbosity::ExecutionStats);
+
+    let response = doc! {
+        "ok": 1,
+        "queryPlanner": {
+            "winningPlan": {
+                "stage": "SHARD_MERGE",
+                "shards": [
+                    { "shardName": "shard0", "winningPlan": { "stage": "COLLSCAN" } },
+                    {
+                        "shardName": "shard1",
+                        "winningPlan": { "stage": "IXSCAN", "indexName": "x_1" },
+                    },
+                ],
+            },
+        },
+        "executionStats": {
+            "nReturned": 4,
+            "executionStages": {
+                "stage": "SHARD_MERGE",
+                "shards": [
+                    { "shardName": "shard0", "nReturned": 1 },
+                    { "shardName": "shard1", "nReturned": 3 },
+                ],
+            },
+        },
+    };
+
+    let result = handle_response_test(&op, response).unwrap();
+    assert_eq!(result.shards.len(), 2);
+    assert_eq!(result.shards[0].shard_name, "shard0");
+    assert_eq!(
+        result.shards[1]
+            .execution_stats
+            .as_ref()
+            .and_then(|stats| stats.n_returned),
+        Some(3)
+    );
+    assert_eq!(result.indexes_used(), vec!["x_1".to_string()]);
+}
+
+#[test]
+fn handle_sharded_aggregate() {
+    let find = Find::new(ns(), None, None);
+    let op = Explain::new(find, ExplainVerbosity::QueryPlanner);
+
+    let response = doc! {
+        "ok": 1,
+        "shards": {
+            "shard0": {
+                "stages": [
+                    { "$cursor": { "queryPlanner": { "winningPlan": { "stage": "COLLSCAN" } } } },
+                    { "$group": { "_id": "$x" } },
+                ],
+            },
+            "shard1": {
+                "queryPlanner": { "winningPlan": { "stage": "IXSCAN", "indexName": "x_1" } },
+            },
+        },
+    };
+
+    let result = handle_response_test(&op, response).unwrap();
+    assert!(result.query_planner.is_none());
+    assert_eq!(result.shards.len(), 2);
+    assert!(result
+        .shards
+        .iter()
+        .all(|shard| shard.query_planner.is_some()));
+    assert_eq!(result.indexes_used(), vec!["x_1".to_string()]);
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test;
 
+use std::time::Duration;
+
 use crate::{
     bson::{doc, Document},
     cmap::{Command, RawCommandResponse, StreamDescription},
@@ -134,13 +136,18 @@ impl OperationWithDefaults for Find {
                 .and_then(|opts| opts.comment_bson.clone())
         };
 
-        Ok(CursorSpecification::new(
+        let mut spec = CursorSpecification::new(
             response.cursor,
             description.server_address.clone(),
             self.options.as_ref().and_then(|opts| opts.batch_size),
             self.options.as_ref().and_then(|opts| opts.max_await_time),
             comment,
-        ))
+        );
+        spec.info.tailable = matches!(
+            self.options.as_ref().and_then(|opts| opts.cursor_type),
+            Some(CursorType::Tailable | CursorType::TailableAwait)
+        );
+        Ok(spec)
     }
 
     fn supports_read_concern(&self, _description: &StreamDescription) -> bool {
@@ -153,6 +160,10 @@ impl OperationWithDefaults for Find {
             .and_then(|opts| opts.selection_criteria.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
         Retryability::Read
     }
@@ -79,6 +79,10 @@ pub(super) struct FindAndModifyOptions {
 
     #[builder(default)]
     pub(crate) comment: Option<Bson>,
+
+    #[serde(skip)]
+    #[builder(default)]
+    pub(crate) timeout: Option<Duration>,
 }
 
 impl FindAndModifyOptions {
@@ -97,6 +101,7 @@ impl FindAndModifyOptions {
         modify_opts.hint = opts.hint;
         modify_opts.let_vars = opts.let_vars;
         modify_opts.comment = opts.comment;
+        modify_opts.timeout = opts.timeout;
         modify_opts
     }
 
@@ -120,6 +125,7 @@ impl FindAndModifyOptions {
         modify_opts.hint = opts.hint;
         modify_opts.let_vars = opts.let_vars;
         modify_opts.comment = opts.comment;
+        modify_opts.timeout = opts.timeout;
 
         modify_opts
     }
@@ -144,6 +150,7 @@ impl FindAndModifyOptions {
         modify_opts.hint = opts.hint;
         modify_opts.let_vars = opts.let_vars;
         modify_opts.comment = opts.comment;
+        modify_opts.timeout = opts.timeout;
 
         modify_opts
     }
@@ -27,6 +27,7 @@ pub(crate) struct GetMore<'conn> {
     max_time: Option<Duration>,
     pinned_connection: Option<&'conn PinnedConnectionHandle>,
     comment: Option<Bson>,
+    timeout: Option<Duration>,
 }
 
 impl<'conn> GetMore<'conn> {
@@ -42,6 +43,7 @@ impl<'conn> GetMore<'conn> {
             max_time: info.max_time,
             pinned_connection: pinned,
             comment: info.comment,
+            timeout: info.timeout,
         }
     }
 }
@@ -105,6 +107,10 @@ impl<'conn> OperationWithDefaults for GetMore<'conn> {
     fn pinned_connection(&self) -> Option<&PinnedConnectionHandle> {
         self.pinned_connection
     }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
 }
 
 #[derive(Debug, Deserialize)]
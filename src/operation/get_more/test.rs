@@ -20,6 +20,9 @@ fn op_selection_criteria() {
         batch_size: None,
         max_time: None,
         comment: None,
+        timeout: None,
+        deadline: None,
+        tailable: false,
     };
     let get_more = GetMore::new(info, None);
     let server_description = ServerDescription {
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test;
 
-use std::{collections::HashMap, convert::TryInto};
+use std::{collections::HashMap, convert::TryInto, time::Duration};
 
 use bson::{oid::ObjectId, Bson, RawArrayBuf, RawDocumentBuf};
 use serde::Serialize;
@@ -188,6 +188,7 @@ impl<'a, T: Serialize> OperationWithDefaults for Insert<'a, T> {
                     write_errors: response.write_errors,
                     write_concern_error: response.write_concern_error,
                     inserted_ids: map,
+                    partial_result: None,
                 }),
                 response.labels,
             ));
@@ -200,6 +201,10 @@ impl<'a, T: Serialize> OperationWithDefaults for Insert<'a, T> {
         self.options.as_ref().and_then(|o| o.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
         Retryability::Write
     }
@@ -1,5 +1,6 @@
 mod abort_transaction;
 mod aggregate;
+mod bulk_write;
 mod commit_transaction;
 mod count;
 mod count_documents;
@@ -10,6 +11,7 @@ mod distinct;
 mod drop_collection;
 mod drop_database;
 mod drop_indexes;
+mod explain;
 mod find;
 mod find_and_modify;
 mod get_more;
@@ -18,13 +20,15 @@ mod list_collections;
 mod list_databases;
 mod list_indexes;
 mod raw_output;
+mod rename_collection;
 mod run_command;
+mod search_index;
 mod update;
 
 #[cfg(test)]
 mod test;
 
-use std::{collections::VecDeque, fmt::Debug, ops::Deref};
+use std::{collections::VecDeque, fmt::Debug, ops::Deref, time::Duration};
 
 use bson::{RawBsonRef, RawDocument, RawDocumentBuf, Timestamp};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -51,6 +55,7 @@ use crate::{
 
 pub(crate) use abort_transaction::AbortTransaction;
 pub(crate) use aggregate::{Aggregate, AggregateTarget, ChangeStreamAggregate};
+pub(crate) use bulk_write::{write_statement, BatchKind, BulkWrite};
 pub(crate) use commit_transaction::CommitTransaction;
 pub(crate) use count::Count;
 pub(crate) use count_documents::CountDocuments;
@@ -61,6 +66,7 @@ pub(crate) use distinct::Distinct;
 pub(crate) use drop_collection::DropCollection;
 pub(crate) use drop_database::DropDatabase;
 pub(crate) use drop_indexes::DropIndexes;
+pub(crate) use explain::Explain;
 pub(crate) use find::Find;
 pub(crate) use find_and_modify::FindAndModify;
 pub(crate) use get_more::GetMore;
@@ -70,7 +76,9 @@ pub(crate) use list_databases::ListDatabases;
 pub(crate) use list_indexes::ListIndexes;
 #[cfg(feature = "in-use-encryption-unstable")]
 pub(crate) use raw_output::RawOutput;
+pub(crate) use rename_collection::RenameCollection;
 pub(crate) use run_command::RunCommand;
+pub(crate) use search_index::{CreateSearchIndexes, DropSearchIndex, UpdateSearchIndex};
 pub(crate) use update::Update;
 
 const SERVER_4_2_0_WIRE_VERSION: i32 = 8;
@@ -122,6 +130,9 @@ pub(crate) trait Operation {
     /// The write concern to use for this operation, if any.
     fn write_concern(&self) -> Option<&WriteConcern>;
 
+    /// The client-side timeout for this operation, if any.
+    fn timeout(&self) -> Option<Duration>;
+
     /// Returns whether or not this command supports the `readConcern` field.
     fn supports_read_concern(&self, _description: &StreamDescription) -> bool;
 
@@ -136,6 +147,10 @@ pub(crate) trait Operation {
 
     fn pinned_connection(&self) -> Option<&PinnedConnectionHandle>;
 
+    /// Whether this operation only releases resources on the server, e.g. `killCursors` or
+    /// `endSessions`.
+    fn is_cleanup(&self) -> bool;
+
     fn name(&self) -> &str;
 }
 
@@ -298,6 +313,7 @@ impl<T> WriteResponseBody<T> {
             write_errors: self.write_errors.clone(),
             write_concern_error: self.write_concern_error.clone(),
             inserted_ids: Default::default(),
+            partial_result: None,
         };
 
         Err(Error::new(
@@ -451,6 +467,11 @@ pub(crate) trait OperationWithDefaults {
         None
     }
 
+    /// The client-side timeout for this operation, if any.
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
     /// Returns whether or not this command supports the `readConcern` field.
     fn supports_read_concern(&self, _description: &StreamDescription) -> bool {
         false
@@ -473,6 +494,12 @@ pub(crate) trait OperationWithDefaults {
         None
     }
 
+    /// Whether this operation only releases resources on the server, e.g. `killCursors` or
+    /// `endSessions`.
+    fn is_cleanup(&self) -> bool {
+        false
+    }
+
     fn name(&self) -> &str {
         Self::NAME
     }
@@ -510,6 +537,9 @@ impl<T: OperationWithDefaults> Operation for T {
     fn write_concern(&self) -> Option<&WriteConcern> {
         self.write_concern()
     }
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout()
+    }
     fn supports_read_concern(&self, description: &StreamDescription) -> bool {
         self.supports_read_concern(description)
     }
@@ -525,6 +555,9 @@ impl<T: OperationWithDefaults> Operation for T {
     fn pinned_connection(&self) -> Option<&PinnedConnectionHandle> {
         self.pinned_connection()
     }
+    fn is_cleanup(&self) -> bool {
+        self.is_cleanup()
+    }
     fn name(&self) -> &str {
         self.name()
     }
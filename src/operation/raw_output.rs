@@ -54,6 +54,10 @@ impl<Op: Operation> Operation for RawOutput<Op> {
         self.0.write_concern()
     }
 
+    fn timeout(&self) -> Option<std::time::Duration> {
+        self.0.timeout()
+    }
+
     fn supports_read_concern(&self, description: &StreamDescription) -> bool {
         self.0.supports_read_concern(description)
     }
@@ -74,6 +78,10 @@ impl<Op: Operation> Operation for RawOutput<Op> {
         self.0.pinned_connection()
     }
 
+    fn is_cleanup(&self) -> bool {
+        self.0.is_cleanup()
+    }
+
     fn name(&self) -> &str {
         self.0.name()
     }
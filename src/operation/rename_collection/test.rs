@@ -0,0 +1,86 @@
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    coll::Namespace,
+    error::{ErrorKind, WriteFailure},
+    operation::{test::handle_response_test, Operation, RenameCollection},
+    options::{RenameCollectionOptions, WriteConcern},
+};
+
+fn op(options: Option<RenameCollectionOptions>) -> RenameCollection {
+    RenameCollection::new(
+        Namespace {
+            db: "db1".to_string(),
+            coll: "from".to_string(),
+        },
+        Namespace {
+            db: "db2".to_string(),
+            coll: "to".to_string(),
+        },
+        options,
+    )
+}
+
+#[test]
+fn build() {
+    let options = RenameCollectionOptions::builder()
+        .drop_target(true)
+        .write_concern(WriteConcern::MAJORITY)
+        .comment(bson::Bson::from("hello"))
+        .build();
+    let mut op = op(Some(options));
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(cmd.name, "renameCollection");
+    assert_eq!(cmd.target_db, "admin");
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "renameCollection": "db1.from",
+            "to": "db2.to",
+            "dropTarget": true,
+            "writeConcern": { "w": "majority" },
+            "comment": "hello",
+        }
+    );
+}
+
+#[test]
+fn build_empty_write_concern() {
+    let options = RenameCollectionOptions::builder()
+        .write_concern(WriteConcern::default())
+        .build();
+    let mut op = op(Some(options));
+
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert!(!cmd.body.contains_key("writeConcern"));
+}
+
+#[test]
+fn handle_success() {
+    let op = op(None);
+
+    handle_response_test(&op, doc! { "ok": 1.0 }).unwrap();
+}
+
+#[test]
+fn handle_write_concern_error() {
+    let op = op(None);
+
+    let response = doc! {
+        "writeConcernError": {
+            "code": 100,
+            "codeName": "hello world",
+            "errmsg": "12345"
+        },
+        "ok": 1
+    };
+
+    let err = handle_response_test(&op, response).unwrap_err();
+    match *err.kind {
+        ErrorKind::Write(WriteFailure::WriteConcernError(ref wc_err)) => {
+            assert_eq!(wc_err.code, 100);
+        }
+        ref e => panic!("expected write concern error, got {:?}", e),
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test;
 
-use std::convert::TryInto;
+use std::{convert::TryInto, time::Duration};
 
 use bson::{RawBsonRef, RawDocumentBuf};
 
@@ -15,6 +15,9 @@ use crate::{
     selection_criteria::SelectionCriteria,
 };
 
+/// The commands the driver runs via `RunCommand` to release server-side resources, in lowercase.
+const CLEANUP_COMMANDS: &[&str] = &["killcursors", "endsessions"];
+
 #[derive(Debug, Clone)]
 pub(crate) struct RunCommand<'conn> {
     db: String,
@@ -22,6 +25,7 @@ pub(crate) struct RunCommand<'conn> {
     selection_criteria: Option<SelectionCriteria>,
     write_concern: Option<WriteConcern>,
     pinned_connection: Option<&'conn PinnedConnectionHandle>,
+    timeout: Option<Duration>,
 }
 
 impl<'conn> RunCommand<'conn> {
@@ -42,6 +46,7 @@ impl<'conn> RunCommand<'conn> {
             selection_criteria,
             write_concern,
             pinned_connection,
+            timeout: None,
         })
     }
 
@@ -64,9 +69,16 @@ impl<'conn> RunCommand<'conn> {
             selection_criteria,
             write_concern,
             pinned_connection,
+            timeout: None,
         })
     }
 
+    /// Sets the client-side timeout to use when executing this command.
+    pub(crate) fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
     fn command_name(&self) -> Option<&str> {
         self.command
             .into_iter()
@@ -133,6 +145,16 @@ impl<'conn> OperationWithDefaults for RunCommand<'conn> {
             .unwrap_or(false)
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    fn is_cleanup(&self) -> bool {
+        self.command_name()
+            .map(|command_name| CLEANUP_COMMANDS.contains(&command_name.to_lowercase().as_str()))
+            .unwrap_or(false)
+    }
+
     fn pinned_connection(&self) -> Option<&PinnedConnectionHandle> {
         self.pinned_connection
     }
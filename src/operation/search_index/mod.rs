@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod test;
+
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::{
+    bson::{doc, Document},
+    cmap::{Command, RawCommandResponse, StreamDescription},
+    error::{Error, Result},
+    operation::OperationWithDefaults,
+    options::{CreateSearchIndexOptions, DropSearchIndexOptions, UpdateSearchIndexOptions},
+    Namespace,
+    SearchIndexModel,
+};
+
+#[derive(Debug)]
+pub(crate) struct CreateSearchIndexes {
+    ns: Namespace,
+    indexes: Vec<SearchIndexModel>,
+    options: Option<CreateSearchIndexOptions>,
+}
+
+impl CreateSearchIndexes {
+    pub(crate) fn new(
+        ns: Namespace,
+        indexes: Vec<SearchIndexModel>,
+        options: Option<CreateSearchIndexOptions>,
+    ) -> Self {
+        Self {
+            ns,
+            indexes,
+            options,
+        }
+    }
+}
+
+impl OperationWithDefaults for CreateSearchIndexes {
+    type O = Vec<String>;
+    type Command = Document;
+    const NAME: &'static str = "createSearchIndexes";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "indexes": bson::to_bson(&self.indexes)?,
+        };
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Response {
+            indexes_created: Vec<CreatedIndex>,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct CreatedIndex {
+            name: String,
+        }
+
+        let response: Response = response.body()?;
+        Ok(response
+            .indexes_created
+            .into_iter()
+            .map(|index| index.name)
+            .collect())
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct UpdateSearchIndex {
+    ns: Namespace,
+    name: String,
+    definition: Document,
+    options: Option<UpdateSearchIndexOptions>,
+}
+
+impl UpdateSearchIndex {
+    pub(crate) fn new(
+        ns: Namespace,
+        name: String,
+        definition: Document,
+        options: Option<UpdateSearchIndexOptions>,
+    ) -> Self {
+        Self {
+            ns,
+            name,
+            definition,
+            options,
+        }
+    }
+}
+
+impl OperationWithDefaults for UpdateSearchIndex {
+    type O = ();
+    type Command = Document;
+    const NAME: &'static str = "updateSearchIndex";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "name": self.name.clone(),
+            "definition": self.definition.clone(),
+        };
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        _response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        Ok(())
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct DropSearchIndex {
+    ns: Namespace,
+    name: String,
+    options: Option<DropSearchIndexOptions>,
+}
+
+impl DropSearchIndex {
+    pub(crate) fn new(
+        ns: Namespace,
+        name: String,
+        options: Option<DropSearchIndexOptions>,
+    ) -> Self {
+        Self { ns, name, options }
+    }
+}
+
+impl OperationWithDefaults for DropSearchIndex {
+    type O = ();
+    type Command = Document;
+    const NAME: &'static str = "dropSearchIndex";
+
+    fn build(&mut self, _description: &StreamDescription) -> Result<Command> {
+        let body = doc! {
+            Self::NAME: self.ns.coll.clone(),
+            "name": self.name.clone(),
+        };
+
+        Ok(Command::new(
+            Self::NAME.to_string(),
+            self.ns.db.clone(),
+            body,
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        _response: RawCommandResponse,
+        _description: &StreamDescription,
+    ) -> Result<Self::O> {
+        Ok(())
+    }
+
+    fn handle_error(&self, error: Error) -> Result<Self::O> {
+        // Dropping an index on a collection that does not exist is not an error.
+        if error.is_ns_not_found() {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    bson::doc,
+    cmap::StreamDescription,
+    coll::Namespace,
+    operation::{
+        test::handle_response_test,
+        CreateSearchIndexes,
+        DropSearchIndex,
+        Operation,
+        UpdateSearchIndex,
+    },
+    SearchIndexModel,
+};
+
+fn ns() -> Namespace {
+    Namespace {
+        db: "test_db".to_string(),
+        coll: "test_coll".to_string(),
+    }
+}
+
+#[test]
+fn build_create() {
+    let indexes = vec![
+        SearchIndexModel::builder()
+            .definition(doc! { "mappings": { "dynamic": true } })
+            .name("foo".to_string())
+            .build(),
+        SearchIndexModel::builder()
+            .definition(doc! { "mappings": { "dynamic": false } })
+            .build(),
+    ];
+    let mut op = CreateSearchIndexes::new(ns(), indexes, None);
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "createSearchIndexes": "test_coll",
+            "indexes": [
+                { "definition": { "mappings": { "dynamic": true } }, "name": "foo" },
+                { "definition": { "mappings": { "dynamic": false } } },
+            ],
+        }
+    );
+}
+
+#[test]
+fn handle_create_success() {
+    let op = CreateSearchIndexes::new(ns(), Vec::new(), None);
+    let response = doc! {
+        "ok": 1,
+        "indexesCreated": [
+            { "id": "1", "name": "foo" },
+            { "id": "2", "name": "default" },
+        ],
+    };
+    let names = handle_response_test(&op, response).unwrap();
+    assert_eq!(names, ["foo", "default"]);
+}
+
+#[test]
+fn build_update() {
+    let mut op = UpdateSearchIndex::new(
+        ns(),
+        "foo".to_string(),
+        doc! { "mappings": { "dynamic": true } },
+        None,
+    );
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "updateSearchIndex": "test_coll",
+            "name": "foo",
+            "definition": { "mappings": { "dynamic": true } },
+        }
+    );
+}
+
+#[test]
+fn build_drop() {
+    let mut op = DropSearchIndex::new(ns(), "foo".to_string(), None);
+    let cmd = op.build(&StreamDescription::new_testing()).unwrap();
+    assert_eq!(
+        cmd.body,
+        doc! {
+            "dropSearchIndex": "test_coll",
+            "name": "foo",
+        }
+    );
+}
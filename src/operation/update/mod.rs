@@ -1,15 +1,18 @@
 #[cfg(test)]
 mod test;
 
+use std::time::Duration;
+
 use serde::Deserialize;
 
 use crate::{
     bson::{doc, Bson, Document},
     bson_util,
     cmap::{Command, RawCommandResponse, StreamDescription},
+    collation::Collation,
     error::{convert_bulk_errors, Result},
     operation::{OperationWithDefaults, Retryability, WriteResponseBody},
-    options::{UpdateModifications, UpdateOptions, WriteConcern},
+    options::{Hint, UpdateModifications, UpdateOptions, WriteConcern},
     results::UpdateResult,
     Namespace,
 };
@@ -66,28 +69,18 @@ impl OperationWithDefaults for Update {
             Self::NAME: self.ns.coll.clone(),
         };
 
-        let mut update = doc! {
-            "q": self.filter.clone(),
-            "u": self.update.to_bson(),
-        };
-
-        if let Some(ref options) = self.options {
-            if let Some(upsert) = options.upsert {
-                update.insert("upsert", upsert);
-            }
-
-            if let Some(ref array_filters) = options.array_filters {
-                update.insert("arrayFilters", bson_util::to_bson_array(array_filters));
-            }
-
-            if let Some(ref hint) = options.hint {
-                update.insert("hint", hint.to_bson());
-            }
-
-            if let Some(ref collation) = options.collation {
-                update.insert("collation", bson::to_bson(collation)?);
-            }
-
+        let options = self.options.as_ref();
+        let update = update_statement(
+            &self.filter,
+            self.update.to_bson(),
+            self.multi == Some(true),
+            options.and_then(|opts| opts.upsert),
+            options.and_then(|opts| opts.array_filters.as_ref()),
+            options.and_then(|opts| opts.hint.as_ref()),
+            options.and_then(|opts| opts.collation.as_ref()),
+        )?;
+
+        if let Some(options) = options {
             if let Some(bypass_doc_validation) = options.bypass_document_validation {
                 body.insert("bypassDocumentValidation", bypass_doc_validation);
             }
@@ -107,10 +100,6 @@ impl OperationWithDefaults for Update {
             }
         };
 
-        if let Some(multi) = self.multi {
-            update.insert("multi", multi);
-        }
-
         body.insert("updates", vec![Bson::Document(update)]);
         body.insert("ordered", true); // command monitoring tests expect this (SPEC-1130)
 
@@ -134,8 +123,7 @@ impl OperationWithDefaults for Update {
             .upserted
             .as_ref()
             .and_then(|v| v.first())
-            .and_then(|doc| doc.get("_id"))
-            .map(Clone::clone);
+            .map(|upserted| upserted.id.clone());
 
         let matched_count = if upserted_id.is_some() { 0 } else { response.n };
 
@@ -152,6 +140,10 @@ impl OperationWithDefaults for Update {
             .and_then(|opts| opts.write_concern.as_ref())
     }
 
+    fn timeout(&self) -> Option<Duration> {
+        self.options.as_ref().and_then(|opts| opts.timeout)
+    }
+
     fn retryability(&self) -> Retryability {
         if self.multi != Some(true) {
             Retryability::Write
@@ -161,9 +153,54 @@ impl OperationWithDefaults for Update {
     }
 }
 
+/// Constructs a single entry of the `updates` array of an `update` command.
+pub(crate) fn update_statement(
+    filter: &Document,
+    update: Bson,
+    multi: bool,
+    upsert: Option<bool>,
+    array_filters: Option<&Vec<Document>>,
+    hint: Option<&Hint>,
+    collation: Option<&Collation>,
+) -> Result<Document> {
+    let mut statement = doc! {
+        "q": filter.clone(),
+        "u": update,
+        "multi": multi,
+    };
+
+    if let Some(upsert) = upsert {
+        statement.insert("upsert", upsert);
+    }
+
+    if let Some(array_filters) = array_filters {
+        statement.insert("arrayFilters", bson_util::to_bson_array(array_filters));
+    }
+
+    if let Some(hint) = hint {
+        statement.insert("hint", hint.to_bson());
+    }
+
+    if let Some(collation) = collation {
+        statement.insert("collation", bson::to_bson(collation)?);
+    }
+
+    Ok(statement)
+}
+
 #[derive(Deserialize)]
 pub(crate) struct UpdateBody {
     #[serde(rename = "nModified")]
-    n_modified: u64,
-    upserted: Option<Vec<Document>>,
+    pub(crate) n_modified: u64,
+    pub(crate) upserted: Option<Vec<Upserted>>,
+}
+
+/// A document upserted by one of the statements of an `update` command.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Upserted {
+    /// The index of the statement within the command's `updates` array.
+    pub(crate) index: usize,
+
+    #[serde(rename = "_id")]
+    pub(crate) id: Bson,
 }
@@ -45,6 +45,7 @@ fn build() {
             {
                 "q": filter,
                 "u": update.to_bson(),
+                "multi": false,
                 "upsert": false,
             }
         ],
@@ -95,6 +96,7 @@ fn build_hint() {
             {
                 "q": filter,
                 "u": update.to_bson(),
+                "multi": false,
                 "upsert": false,
                 "hint": {
                     "x": 1,
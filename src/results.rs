@@ -7,6 +7,7 @@ use crate::{
     bson_util,
     change_stream::event::ResumeToken,
     db::options::CreateCollectionOptions,
+    error::Result,
 };
 
 use bson::{Binary, RawDocumentBuf};
@@ -77,6 +78,39 @@ pub struct DeleteResult {
     pub deleted_count: u64,
 }
 
+/// The result of a [`Collection::bulk_write`](../struct.Collection.html#method.bulk_write)
+/// operation.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BulkWriteResult {
+    /// The number of documents inserted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub inserted_count: u64,
+
+    /// The number of documents that matched the filters of update and replace models.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub matched_count: u64,
+
+    /// The number of documents modified by update and replace models.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub modified_count: u64,
+
+    /// The number of documents deleted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub deleted_count: u64,
+
+    /// The number of documents upserted.
+    #[serde(serialize_with = "crate::bson::serde_helpers::serialize_u64_as_i64")]
+    pub upserted_count: u64,
+
+    /// The `_id` fields of the inserted documents, keyed by the index of the corresponding model.
+    pub inserted_ids: HashMap<usize, Bson>,
+
+    /// The `_id` fields of the upserted documents, keyed by the index of the corresponding model.
+    pub upserted_ids: HashMap<usize, Bson>,
+}
+
 /// Information about the index created as a result of a
 /// [`Collection::create_index`](../struct.Collection.html#method.create_index).
 #[derive(Debug, Clone, PartialEq)]
@@ -193,3 +227,571 @@ pub struct DatabaseSpecification {
     /// is `None`.
     pub shards: Option<Document>,
 }
+
+/// The result of an explained operation, as returned by the methods on
+/// [`CollectionExplain`](crate::CollectionExplain).
+///
+/// The format of explain output varies between server versions, query engines and deployment
+/// topologies; the typed fields expose the commonly used parts of the output, while the complete
+/// output is available via [`ExplainResult::raw`].
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ExplainResult {
+    /// Information about the plan selected by the query optimizer.
+    pub query_planner: Option<QueryPlanner>,
+
+    /// Statistics describing the execution of the winning plan. This is only present if the
+    /// operation was explained with
+    /// [`ExplainVerbosity::ExecutionStats`](crate::options::ExplainVerbosity::ExecutionStats) or
+    /// [`ExplainVerbosity::AllPlansExecution`](crate::options::ExplainVerbosity::AllPlansExecution).
+    pub execution_stats: Option<ExecutionStats>,
+
+    /// For aggregations, the explain output of each stage of the pipeline.
+    pub stages: Option<Vec<Document>>,
+
+    /// The explain output of each shard targeted by the operation. This is empty if the
+    /// operation was not run against a sharded cluster.
+    #[serde(skip)]
+    pub shards: Vec<ShardExplain>,
+
+    /// The complete explain output returned by the server.
+    #[serde(skip)]
+    pub raw: Document,
+}
+
+impl ExplainResult {
+    pub(crate) fn from_document(raw: Document) -> Result<Self> {
+        let mut result: Self = bson::from_document(raw.clone())?;
+
+        // Aggregations report the plan of the initial query as part of their first stage.
+        if result.query_planner.is_none() {
+            if let Some(cursor) = result
+                .stages
+                .as_ref()
+                .and_then(|stages| stages.first())
+                .and_then(|stage| stage.get_document("$cursor").ok())
+            {
+                let cursor: Self = bson::from_document(cursor.clone())?;
+                result.query_planner = cursor.query_planner;
+                result.execution_stats = result.execution_stats.or(cursor.execution_stats);
+            }
+        }
+
+        result.shards = Self::collect_shards(&raw)?;
+        result.raw = raw;
+        Ok(result)
+    }
+
+    fn collect_shards(raw: &Document) -> Result<Vec<ShardExplain>> {
+        let mut shards: Vec<ShardExplain> = Vec::new();
+
+        // Sharded aggregations report the output of each shard separately, keyed by name.
+        if let Ok(by_name) = raw.get_document("shards") {
+            for (name, shard) in by_name {
+                if let Some(shard) = shard.as_document() {
+                    let explain = Self::from_document(shard.clone())?;
+                    shards.push(ShardExplain {
+                        shard_name: name.clone(),
+                        query_planner: explain.query_planner,
+                        execution_stats: explain.execution_stats,
+                    });
+                }
+            }
+            return Ok(shards);
+        }
+
+        // Other operations nest the plan and statistics of each shard within the top-level ones.
+        if let Ok(plans) = raw
+            .get_document("queryPlanner")
+            .and_then(|planner| planner.get_document("winningPlan"))
+            .and_then(|plan| plan.get_array("shards"))
+        {
+            for plan in plans.iter().filter_map(Bson::as_document) {
+                shards.push(ShardExplain {
+                    shard_name: plan.get_str("shardName").unwrap_or_default().to_string(),
+                    query_planner: Some(bson::from_document(plan.clone())?),
+                    execution_stats: None,
+                });
+            }
+        }
+        if let Ok(stats) = raw
+            .get_document("executionStats")
+            .and_then(|stats| stats.get_document("executionStages"))
+            .and_then(|stages| stages.get_array("shards"))
+        {
+            for stats in stats.iter().filter_map(Bson::as_document) {
+                let shard_name = stats.get_str("shardName").unwrap_or_default();
+                let execution_stats = Some(bson::from_document(stats.clone())?);
+                match shards.iter_mut().find(|s| s.shard_name == shard_name) {
+                    Some(shard) => shard.execution_stats = execution_stats,
+                    None => shards.push(ShardExplain {
+                        shard_name: shard_name.to_string(),
+                        query_planner: None,
+                        execution_stats,
+                    }),
+                }
+            }
+        }
+
+        Ok(shards)
+    }
+
+    /// Returns the names of the indexes used by the winning plan, including the winning plans of
+    /// each shard and of any aggregation stages. Returns an empty list if the operation was
+    /// satisfied without using an index, e.g. via a collection scan.
+    pub fn indexes_used(&self) -> Vec<String> {
+        fn collect(value: &Bson, in_winning_plan: bool, names: &mut Vec<String>) {
+            match value {
+                Bson::Document(doc) => {
+                    for (key, value) in doc {
+                        match (key.as_str(), value) {
+                            ("indexName", Bson::String(name)) if in_winning_plan => {
+                                if !names.contains(name) {
+                                    names.push(name.clone());
+                                }
+                            }
+                            ("rejectedPlans", _) => {}
+                            ("winningPlan", _) => collect(value, true, names),
+                            _ => collect(value, in_winning_plan, names),
+                        }
+                    }
+                }
+                Bson::Array(values) => {
+                    for value in values {
+                        collect(value, in_winning_plan, names);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut names = Vec::new();
+        for (key, value) in &self.raw {
+            collect(value, key == "winningPlan", &mut names);
+        }
+        names
+    }
+}
+
+/// The plan selected by the query optimizer for an explained operation.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct QueryPlanner {
+    /// The namespace the operation was run against.
+    pub namespace: Option<String>,
+
+    /// The query as parsed by the server.
+    pub parsed_query: Option<Document>,
+
+    /// The tree of stages making up the plan selected by the query optimizer.
+    pub winning_plan: Option<Document>,
+
+    /// The candidate plans that were considered and rejected by the query optimizer.
+    #[serde(default)]
+    pub rejected_plans: Vec<Document>,
+}
+
+/// Statistics describing the execution of the winning plan of an explained operation.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ExecutionStats {
+    /// Whether the plan executed successfully.
+    pub execution_success: Option<bool>,
+
+    /// The number of documents returned by the plan.
+    pub n_returned: Option<i64>,
+
+    /// The total amount of time in milliseconds spent executing the plan.
+    pub execution_time_millis: Option<i64>,
+
+    /// The number of index entries scanned.
+    pub total_keys_examined: Option<i64>,
+
+    /// The number of documents examined.
+    pub total_docs_examined: Option<i64>,
+
+    /// The tree of stages that were executed, along with statistics for each stage.
+    pub execution_stages: Option<Document>,
+}
+
+/// The explain output of a single shard.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct ShardExplain {
+    /// The name of the shard.
+    pub shard_name: String,
+
+    /// The plan selected by the query optimizer on this shard.
+    pub query_planner: Option<QueryPlanner>,
+
+    /// Statistics describing the execution of the plan on this shard.
+    pub execution_stats: Option<ExecutionStats>,
+}
+
+/// Statistics about a collection, as returned by
+/// [`Collection::stats`](crate::Collection::stats).
+///
+/// When run against a sharded cluster, the server reports statistics for each shard separately;
+/// the sizes and counts in this struct are the totals across all shards, and the per-shard
+/// statistics are available via [`CollectionStats::shards`].
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CollectionStats {
+    /// The namespace of the collection.
+    #[serde(rename = "ns")]
+    pub namespace: String,
+
+    /// The number of documents in the collection. This is only present if
+    /// [`CollectionStatsOptions::count`](crate::options::CollectionStatsOptions::count) or
+    /// [`CollectionStatsOptions::storage_stats`](crate::options::CollectionStatsOptions::storage_stats)
+    /// was enabled.
+    #[serde(default, deserialize_with = "deserialize_count")]
+    pub count: Option<u64>,
+
+    /// Storage statistics for the collection.
+    pub storage_stats: Option<StorageStats>,
+
+    /// Latency statistics for operations on the collection.
+    pub latency_stats: Option<LatencyStats>,
+
+    /// The statistics reported by each shard, keyed by shard name. This is empty if the
+    /// collection is not sharded.
+    #[serde(skip)]
+    pub shards: HashMap<String, CollectionStats>,
+}
+
+impl CollectionStats {
+    pub(crate) fn from_documents(docs: Vec<Document>) -> Result<Self> {
+        let mut shards = docs
+            .into_iter()
+            .map(|doc| {
+                let shard = doc.get_str("shard").ok().map(String::from);
+                let mut stats: Self = bson::from_document(doc)?;
+                if stats.count.is_none() {
+                    stats.count = stats.storage_stats.as_ref().map(|s| s.count);
+                }
+                Ok((shard, stats))
+            })
+            .collect::<Result<Vec<(Option<String>, Self)>>>()?;
+
+        if shards.len() == 1 && shards[0].0.is_none() {
+            return Ok(shards.remove(0).1);
+        }
+
+        let mut total = Self::default();
+        for (name, stats) in shards {
+            total.namespace = stats.namespace.clone();
+            total.count = add_option(total.count, stats.count);
+            if let Some(ref storage) = stats.storage_stats {
+                total
+                    .storage_stats
+                    .get_or_insert_with(Default::default)
+                    .add(storage);
+            }
+            if let Some(ref latency) = stats.latency_stats {
+                total
+                    .latency_stats
+                    .get_or_insert_with(Default::default)
+                    .add(latency);
+            }
+            let name = name.unwrap_or_else(|| total.shards.len().to_string());
+            total.shards.insert(name, stats);
+        }
+        Ok(total)
+    }
+}
+
+/// Deserializes the `count` field of a `$collStats` result, which is a document of the form
+/// `{ count: <number> }`.
+fn deserialize_count<'de, D>(deserializer: D) -> std::result::Result<Option<u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Count {
+        #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+        count: u64,
+    }
+
+    Ok(Option::<Count>::deserialize(deserializer)?.map(|c| c.count))
+}
+
+/// Storage statistics for a collection.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct StorageStats {
+    /// The total uncompressed size in memory of all documents in the collection.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub size: u64,
+
+    /// The number of documents in the collection.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub count: u64,
+
+    /// The average size of a document in the collection. The server omits this for empty
+    /// collections, in which case it is 0.
+    #[serde(
+        default,
+        deserialize_with = "bson_util::deserialize_f64_from_bson_number"
+    )]
+    pub avg_obj_size: f64,
+
+    /// The total amount of storage allocated to the collection for document storage.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub storage_size: u64,
+
+    /// The total size of all indexes on the collection.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub total_index_size: u64,
+
+    /// The size of each index on the collection, keyed by index name.
+    #[serde(deserialize_with = "deserialize_index_sizes")]
+    pub index_sizes: HashMap<String, u64>,
+
+    /// A summary of the WiredTiger storage engine statistics for the collection, if it uses
+    /// WiredTiger.
+    pub wired_tiger: Option<WiredTigerStats>,
+}
+
+impl StorageStats {
+    fn add(&mut self, other: &Self) {
+        self.size += other.size;
+        self.count += other.count;
+        self.avg_obj_size = if self.count > 0 {
+            self.size as f64 / self.count as f64
+        } else {
+            0.0
+        };
+        self.storage_size += other.storage_size;
+        self.total_index_size += other.total_index_size;
+        for (name, size) in &other.index_sizes {
+            *self.index_sizes.entry(name.clone()).or_default() += size;
+        }
+        if let Some(ref wired_tiger) = other.wired_tiger {
+            self.wired_tiger
+                .get_or_insert_with(Default::default)
+                .add(wired_tiger);
+        }
+    }
+}
+
+fn deserialize_index_sizes<'de, D>(
+    deserializer: D,
+) -> std::result::Result<HashMap<String, u64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    HashMap::<String, Bson>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, size)| match bson_util::get_u64(&size) {
+            Some(size) => Ok((name, size)),
+            None => Err(D::Error::custom(format!(
+                "could not deserialize u64 from {:?}",
+                size
+            ))),
+        })
+        .collect()
+}
+
+/// A summary of the WiredTiger storage engine statistics for a collection.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(from = "WiredTigerDocument")]
+#[non_exhaustive]
+pub struct WiredTigerStats {
+    /// The size of the collection's data file on disk.
+    pub file_size: Option<u64>,
+
+    /// The number of bytes in the data file that are free and can be reused.
+    pub file_bytes_available_for_reuse: Option<u64>,
+
+    /// The number of bytes of the collection's data currently held in the WiredTiger cache.
+    pub bytes_in_cache: Option<u64>,
+}
+
+impl WiredTigerStats {
+    fn add(&mut self, other: &Self) {
+        self.file_size = add_option(self.file_size, other.file_size);
+        self.file_bytes_available_for_reuse = add_option(
+            self.file_bytes_available_for_reuse,
+            other.file_bytes_available_for_reuse,
+        );
+        self.bytes_in_cache = add_option(self.bytes_in_cache, other.bytes_in_cache);
+    }
+}
+
+/// The subset of the `wiredTiger` section of the storage statistics reported in
+/// [`WiredTigerStats`].
+#[derive(Deserialize)]
+struct WiredTigerDocument {
+    #[serde(rename = "block-manager", default)]
+    block_manager: Option<BlockManagerDocument>,
+
+    #[serde(default)]
+    cache: Option<CacheDocument>,
+}
+
+#[derive(Deserialize)]
+struct BlockManagerDocument {
+    #[serde(
+        rename = "file size in bytes",
+        default,
+        deserialize_with = "bson_util::deserialize_u64_option_from_bson_number"
+    )]
+    file_size: Option<u64>,
+
+    #[serde(
+        rename = "file bytes available for reuse",
+        default,
+        deserialize_with = "bson_util::deserialize_u64_option_from_bson_number"
+    )]
+    file_bytes_available_for_reuse: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct CacheDocument {
+    #[serde(
+        rename = "bytes currently in the cache",
+        default,
+        deserialize_with = "bson_util::deserialize_u64_option_from_bson_number"
+    )]
+    bytes_in_cache: Option<u64>,
+}
+
+impl From<WiredTigerDocument> for WiredTigerStats {
+    fn from(doc: WiredTigerDocument) -> Self {
+        Self {
+            file_size: doc.block_manager.as_ref().and_then(|bm| bm.file_size),
+            file_bytes_available_for_reuse: doc
+                .block_manager
+                .as_ref()
+                .and_then(|bm| bm.file_bytes_available_for_reuse),
+            bytes_in_cache: doc.cache.and_then(|cache| cache.bytes_in_cache),
+        }
+    }
+}
+
+/// Latency statistics for operations on a collection.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[non_exhaustive]
+pub struct LatencyStats {
+    /// Statistics for read operations.
+    pub reads: OperationLatency,
+
+    /// Statistics for write operations.
+    pub writes: OperationLatency,
+
+    /// Statistics for database commands.
+    pub commands: OperationLatency,
+
+    /// Statistics for transactions. Only reported by server versions 4.2+.
+    #[serde(default)]
+    pub transactions: OperationLatency,
+}
+
+impl LatencyStats {
+    fn add(&mut self, other: &Self) {
+        self.reads.add(&other.reads);
+        self.writes.add(&other.writes);
+        self.commands.add(&other.commands);
+        self.transactions.add(&other.transactions);
+    }
+}
+
+/// The cumulative latency of a type of operation.
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[non_exhaustive]
+pub struct OperationLatency {
+    /// The total latency of the operations, in microseconds.
+    #[serde(
+        rename = "latency",
+        deserialize_with = "bson_util::deserialize_u64_from_bson_number"
+    )]
+    pub latency_micros: u64,
+
+    /// The number of operations performed.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub ops: u64,
+}
+
+impl OperationLatency {
+    fn add(&mut self, other: &Self) {
+        self.latency_micros += other.latency_micros;
+        self.ops += other.ops;
+    }
+}
+
+/// Statistics about a database, as returned by [`Database::stats`](crate::Database::stats).
+///
+/// The sizes are reported in bytes unless
+/// [`DatabaseStatsOptions::scale`](crate::options::DatabaseStatsOptions::scale) is set, in which
+/// case they are divided by the scale factor and may be fractional.
+///
+/// When run against a sharded cluster, the totals across all shards are reported, and the
+/// statistics of each shard are available via [`DatabaseStats::shards`].
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DatabaseStats {
+    /// The name of the database.
+    #[serde(default)]
+    pub db: String,
+
+    /// The number of collections in the database.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub collections: u64,
+
+    /// The number of views in the database.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub views: u64,
+
+    /// The number of documents in the database.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub objects: u64,
+
+    /// The average size of a document in the database, in bytes. This is not affected by the
+    /// scale factor.
+    #[serde(deserialize_with = "bson_util::deserialize_f64_from_bson_number")]
+    pub avg_obj_size: f64,
+
+    /// The total uncompressed size in memory of all documents in the database.
+    #[serde(deserialize_with = "bson_util::deserialize_f64_from_bson_number")]
+    pub data_size: f64,
+
+    /// The total amount of storage allocated to collections in the database for document storage.
+    #[serde(deserialize_with = "bson_util::deserialize_f64_from_bson_number")]
+    pub storage_size: f64,
+
+    /// The number of indexes in the database.
+    #[serde(deserialize_with = "bson_util::deserialize_u64_from_bson_number")]
+    pub indexes: u64,
+
+    /// The total size of all indexes in the database.
+    #[serde(deserialize_with = "bson_util::deserialize_f64_from_bson_number")]
+    pub index_size: f64,
+
+    /// The sum of `storage_size` and `index_size`. Only reported by server versions 4.4+.
+    #[serde(
+        default,
+        deserialize_with = "bson_util::deserialize_f64_option_from_bson_number"
+    )]
+    pub total_size: Option<f64>,
+
+    /// The statistics reported by each shard, keyed by shard name. This is empty if the database
+    /// is not on a sharded cluster.
+    #[serde(rename = "raw", default)]
+    pub shards: HashMap<String, DatabaseStats>,
+}
+
+fn add_option(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a + b),
+        (a, b) => a.or(b),
+    }
+}
@@ -6,11 +6,14 @@ mod join_handle;
 #[cfg(feature = "in-use-encryption-unstable")]
 mod process;
 mod resolver;
+mod socks5;
 pub(crate) mod stream;
 mod sync_read_ext;
 #[cfg(feature = "openssl-tls")]
 mod tls_openssl;
 #[cfg_attr(feature = "openssl-tls", allow(unused))]
+mod tls_revocation;
+#[cfg_attr(feature = "openssl-tls", allow(unused))]
 mod tls_rustls;
 mod worker_handle;
 
@@ -22,6 +25,7 @@ pub(crate) use self::{
     acknowledged_message::AcknowledgedMessage,
     join_handle::AsyncJoinHandle,
     resolver::AsyncResolver,
+    socks5::{Socks5Proxy, DEFAULT_PROXY_PORT},
     stream::AsyncStream,
     sync_read_ext::SyncLittleEndianRead,
     worker_handle::{WorkerHandle, WorkerHandleListener},
@@ -167,7 +171,8 @@ pub(crate) async fn resolve_address(
 
     #[cfg(feature = "async-std-runtime")]
     {
-        let host = (address.host(), address.port().unwrap_or(27017));
+        let host = address.host();
+        let host = (host.as_ref(), address.port().unwrap_or(27017));
         let socket_addrs = async_std::net::ToSocketAddrs::to_socket_addrs(&host).await?;
         Ok(socket_addrs)
     }
@@ -1,11 +1,17 @@
-use trust_dns_resolver::{
-    config::ResolverConfig,
-    error::ResolveErrorKind,
-    lookup::{SrvLookup, TxtLookup},
-    IntoName,
-};
+#[cfg(feature = "gssapi-auth")]
+use std::net::IpAddr;
+use std::time::Duration;
+
+use futures_util::future::{BoxFuture, FutureExt};
+use trust_dns_proto::rr::RData;
+use trust_dns_resolver::{config::ResolverConfig, error::ResolveErrorKind};
+#[cfg(feature = "gssapi-auth")]
+use trust_dns_resolver::{lookup::ReverseLookup, lookup_ip::LookupIp, IntoName, TryParseIp};
 
-use crate::error::{Error, Result};
+use crate::{
+    error::{Error, Result},
+    options::{SrvRecord, SrvResolver},
+};
 
 /// An async runtime agnostic DNS resolver.
 pub(crate) struct AsyncResolver {
@@ -42,24 +48,70 @@ impl AsyncResolver {
     }
 }
 
+impl SrvResolver for AsyncResolver {
+    fn lookup_srv<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<SrvRecord>>> {
+        async move {
+            let lookup = self
+                .resolver
+                .srv_lookup(name)
+                .await
+                .map_err(Error::from_resolve_error)?;
+            Ok(lookup
+                .as_lookup()
+                .record_iter()
+                .filter_map(|record| match record.data() {
+                    Some(RData::SRV(srv)) => Some(SrvRecord::new(
+                        srv.target().to_utf8(),
+                        srv.port(),
+                        Duration::from_secs(record.ttl().into()),
+                    )),
+                    _ => None,
+                })
+                .collect())
+        }
+        .boxed()
+    }
+
+    fn lookup_txt<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<String>>> {
+        async move {
+            let lookup = match self.resolver.txt_lookup(name).await {
+                Ok(lookup) => lookup,
+                Err(e) => match e.kind() {
+                    ResolveErrorKind::NoRecordsFound { .. } => return Ok(Vec::new()),
+                    _ => return Err(Error::from_resolve_error(e)),
+                },
+            };
+            Ok(lookup
+                .iter()
+                .map(|txt| {
+                    txt.txt_data()
+                        .iter()
+                        .map(|bytes| String::from_utf8_lossy(bytes.as_ref()))
+                        .collect()
+                })
+                .collect())
+        }
+        .boxed()
+    }
+}
+
+#[cfg(feature = "gssapi-auth")]
 impl AsyncResolver {
-    pub async fn srv_lookup<N: IntoName>(&self, query: N) -> Result<SrvLookup> {
+    pub async fn lookup_ip<N: IntoName + TryParseIp>(&self, query: N) -> Result<LookupIp> {
         let lookup = self
             .resolver
-            .srv_lookup(query)
+            .lookup_ip(query)
             .await
             .map_err(Error::from_resolve_error)?;
         Ok(lookup)
     }
 
-    pub async fn txt_lookup<N: IntoName>(&self, query: N) -> Result<Option<TxtLookup>> {
-        let lookup_result = self.resolver.txt_lookup(query).await;
-        match lookup_result {
-            Ok(lookup) => Ok(Some(lookup)),
-            Err(e) => match e.kind() {
-                ResolveErrorKind::NoRecordsFound { .. } => Ok(None),
-                _ => Err(Error::from_resolve_error(e)),
-            },
-        }
+    pub async fn reverse_lookup(&self, query: IpAddr) -> Result<ReverseLookup> {
+        let lookup = self
+            .resolver
+            .reverse_lookup(query)
+            .await
+            .map_err(Error::from_resolve_error)?;
+        Ok(lookup)
     }
 }
@@ -0,0 +1,211 @@
+//! A minimal SOCKS5 client as described in [RFC 1928](https://www.rfc-editor.org/rfc/rfc1928),
+//! supporting the optional username/password authentication described in
+//! [RFC 1929](https://www.rfc-editor.org/rfc/rfc1929).
+
+use std::{net::IpAddr, sync::Arc};
+
+use derivative::Derivative;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use super::stream::AsyncTcpStream;
+use crate::{
+    client::options::{ServerAddress, DEFAULT_PORT},
+    error::{Error, ErrorKind, Result},
+};
+
+const SOCKS_VERSION: u8 = 0x05;
+const AUTH_VERSION: u8 = 0x01;
+
+const METHOD_NO_AUTH: u8 = 0x00;
+const METHOD_USERNAME_PASSWORD: u8 = 0x02;
+const METHOD_NO_ACCEPTABLE: u8 = 0xff;
+
+const COMMAND_CONNECT: u8 = 0x01;
+const RESERVED: u8 = 0x00;
+
+const ADDRESS_TYPE_IPV4: u8 = 0x01;
+const ADDRESS_TYPE_DOMAIN: u8 = 0x03;
+const ADDRESS_TYPE_IPV6: u8 = 0x04;
+
+/// The port a SOCKS5 proxy is assumed to listen on if none is specified.
+pub(crate) const DEFAULT_PROXY_PORT: u16 = 1080;
+
+/// The configuration needed to tunnel connections through a SOCKS5 proxy.
+#[derive(Clone, Derivative)]
+#[derivative(Debug)]
+pub(crate) struct Socks5Proxy {
+    /// The address of the proxy itself.
+    pub(crate) address: ServerAddress,
+
+    /// The username and password to authenticate to the proxy with, if any.
+    #[derivative(Debug = "ignore")]
+    pub(crate) credentials: Option<(String, String)>,
+}
+
+impl Socks5Proxy {
+    /// Connects to the proxy and asks it to open a tunnel to `target`. The returned stream can be
+    /// used as though it were connected to `target` directly.
+    pub(crate) async fn connect(&self, target: &ServerAddress) -> Result<AsyncTcpStream> {
+        let mut stream = AsyncTcpStream::connect(&self.address).await?;
+        self.handshake(&mut stream, target).await?;
+        Ok(stream)
+    }
+
+    async fn handshake<S>(&self, stream: &mut S, target: &ServerAddress) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        // Offer username/password authentication only if credentials were provided.
+        let greeting: &[u8] = match self.credentials {
+            Some(_) => &[SOCKS_VERSION, 2, METHOD_NO_AUTH, METHOD_USERNAME_PASSWORD],
+            None => &[SOCKS_VERSION, 1, METHOD_NO_AUTH],
+        };
+        stream.write_all(greeting).await?;
+
+        let mut reply = [0u8; 2];
+        stream.read_exact(&mut reply).await?;
+        if reply[0] != SOCKS_VERSION {
+            return Err(proxy_error(format!(
+                "unexpected SOCKS version {} in reply",
+                reply[0]
+            )));
+        }
+        match (reply[1], &self.credentials) {
+            (METHOD_NO_AUTH, _) => {}
+            (METHOD_USERNAME_PASSWORD, Some((username, password))) => {
+                self.authenticate(stream, username, password).await?
+            }
+            (METHOD_NO_ACCEPTABLE, _) | (METHOD_USERNAME_PASSWORD, None) => {
+                return Err(proxy_error(
+                    "proxy did not accept any of the offered authentication methods",
+                ))
+            }
+            (method, _) => {
+                return Err(proxy_error(format!(
+                    "proxy selected unsupported authentication method {}",
+                    method
+                )))
+            }
+        }
+
+        stream.write_all(&connect_request(target)?).await?;
+
+        let mut reply = [0u8; 4];
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != 0 {
+            return Err(proxy_error(format!(
+                "proxy failed to connect to {}: {}",
+                target,
+                reply_message(reply[1])
+            )));
+        }
+
+        // The reply ends with the address the proxy bound to, which isn't needed but must be
+        // consumed before the tunnel can be used.
+        let bound_address_len = match reply[3] {
+            ADDRESS_TYPE_IPV4 => 4,
+            ADDRESS_TYPE_IPV6 => 16,
+            ADDRESS_TYPE_DOMAIN => stream.read_u8().await? as usize,
+            other => {
+                return Err(proxy_error(format!(
+                    "proxy replied with unknown address type {}",
+                    other
+                )))
+            }
+        };
+        let mut bound_address = vec![0u8; bound_address_len + 2];
+        stream.read_exact(&mut bound_address).await?;
+
+        Ok(())
+    }
+
+    async fn authenticate<S>(&self, stream: &mut S, username: &str, password: &str) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let username_len = u8::try_from(username.len())
+            .map_err(|_| proxy_error("proxy username must be at most 255 bytes"))?;
+        let password_len = u8::try_from(password.len())
+            .map_err(|_| proxy_error("proxy password must be at most 255 bytes"))?;
+
+        let mut request = Vec::with_capacity(3 + username.len() + password.len());
+        request.push(AUTH_VERSION);
+        request.push(username_len);
+        request.extend_from_slice(username.as_bytes());
+        request.push(password_len);
+        request.extend_from_slice(password.as_bytes());
+        stream.write_all(&request).await?;
+
+        let mut reply = [0u8; 2];
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != 0 {
+            return Err(proxy_error(
+                "proxy rejected the provided username and password",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Builds a CONNECT request for the given target. Hostnames are sent as-is so that they're
+/// resolved by the proxy rather than locally.
+fn connect_request(target: &ServerAddress) -> Result<Vec<u8>> {
+    let (host, port) = match target {
+        ServerAddress::Tcp { host, port } => (host.as_str(), port.unwrap_or(DEFAULT_PORT)),
+        #[cfg(unix)]
+        ServerAddress::Unix { .. } => {
+            return Err(proxy_error(
+                "Unix domain socket connections cannot be made through a proxy",
+            ))
+        }
+    };
+
+    let mut request = vec![SOCKS_VERSION, COMMAND_CONNECT, RESERVED];
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => {
+            request.push(ADDRESS_TYPE_IPV4);
+            request.extend_from_slice(&ip.octets());
+        }
+        Ok(IpAddr::V6(ip)) => {
+            request.push(ADDRESS_TYPE_IPV6);
+            request.extend_from_slice(&ip.octets());
+        }
+        Err(_) => {
+            let host_len = u8::try_from(host.len()).map_err(|_| {
+                proxy_error(format!(
+                    "hostname {} is too long to be sent to the proxy",
+                    host
+                ))
+            })?;
+            request.push(ADDRESS_TYPE_DOMAIN);
+            request.push(host_len);
+            request.extend_from_slice(host.as_bytes());
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    Ok(request)
+}
+
+fn reply_message(code: u8) -> &'static str {
+    match code {
+        0x01 => "general SOCKS server failure",
+        0x02 => "connection not allowed by ruleset",
+        0x03 => "network unreachable",
+        0x04 => "host unreachable",
+        0x05 => "connection refused",
+        0x06 => "TTL expired",
+        0x07 => "command not supported",
+        0x08 => "address type not supported",
+        _ => "unknown error",
+    }
+}
+
+/// Proxy failures are reported as I/O errors so that they're handled like any other failure to
+/// connect to a server.
+fn proxy_error(message: impl Into<String>) -> Error {
+    ErrorKind::Io(Arc::new(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        format!("SOCKS5 proxy error: {}", message.into()),
+    )))
+    .into()
+}
@@ -1,3 +1,5 @@
+#[cfg(unix)]
+use std::path::Path;
 use std::{
     net::SocketAddr,
     ops::DerefMut,
@@ -14,7 +16,7 @@ use crate::{
     runtime,
 };
 
-use super::{tls::AsyncTlsStream, TlsConfig};
+use super::{socks5::Socks5Proxy, tls::AsyncTlsStream, TlsConfig};
 
 pub(crate) const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 const KEEPALIVE_TIME: Duration = Duration::from_secs(120);
@@ -30,24 +32,40 @@ pub(crate) enum AsyncStream {
 
     /// A TLS connection over TCP.
     Tls(AsyncTlsStream),
+
+    /// A Unix domain socket connection.
+    #[cfg(unix)]
+    Unix(AsyncUnixStream),
 }
 
 impl AsyncStream {
+    /// Connects to the given address, tunnelling the connection through the SOCKS5 proxy (if
+    /// provided) before performing the TLS handshake (if configured).
     pub(crate) async fn connect(
         address: ServerAddress,
         tls_cfg: Option<&TlsConfig>,
+        proxy: Option<&Socks5Proxy>,
     ) -> Result<Self> {
-        let inner = AsyncTcpStream::connect(&address).await?;
-
-        // If there are TLS options, wrap the inner stream in an AsyncTlsStream.
-        match tls_cfg {
-            Some(cfg) => {
-                let host = address.host();
-                Ok(AsyncStream::Tls(
-                    AsyncTlsStream::connect(host, inner, cfg).await?,
-                ))
+        match &address {
+            ServerAddress::Tcp { host, .. } => {
+                let inner = match proxy {
+                    Some(proxy) => proxy.connect(&address).await?,
+                    None => AsyncTcpStream::connect(&address).await?,
+                };
+
+                // If there are TLS options, wrap the inner stream in an AsyncTlsStream.
+                match tls_cfg {
+                    Some(cfg) => Ok(AsyncStream::Tls(
+                        AsyncTlsStream::connect(host, inner, cfg).await?,
+                    )),
+                    None => Ok(AsyncStream::Tcp(inner)),
+                }
+            }
+            // TLS is not used for Unix domain sockets, as they can only be connected to locally.
+            #[cfg(unix)]
+            ServerAddress::Unix { path } => {
+                Ok(AsyncStream::Unix(AsyncUnixStream::connect(path).await?))
             }
-            None => Ok(AsyncStream::Tcp(inner)),
         }
     }
 }
@@ -144,6 +162,52 @@ impl AsyncTcpStream {
     }
 }
 
+/// A runtime-agnostic Unix domain socket stream.
+#[cfg(unix)]
+#[derive(Debug)]
+pub(crate) enum AsyncUnixStream {
+    /// Wrapper around `tokio::net::UnixStream`.
+    #[cfg(feature = "tokio-runtime")]
+    Tokio(tokio::net::UnixStream),
+
+    /// Wrapper around `async_std::os::unix::net::UnixStream`.
+    #[cfg(feature = "async-std-runtime")]
+    AsyncStd(async_std::os::unix::net::UnixStream),
+}
+
+#[cfg(all(unix, feature = "tokio-runtime"))]
+impl From<tokio::net::UnixStream> for AsyncUnixStream {
+    fn from(stream: tokio::net::UnixStream) -> Self {
+        Self::Tokio(stream)
+    }
+}
+
+#[cfg(all(unix, feature = "async-std-runtime"))]
+impl From<async_std::os::unix::net::UnixStream> for AsyncUnixStream {
+    fn from(stream: async_std::os::unix::net::UnixStream) -> Self {
+        Self::AsyncStd(stream)
+    }
+}
+
+#[cfg(unix)]
+impl AsyncUnixStream {
+    #[cfg(feature = "tokio-runtime")]
+    async fn connect(path: &Path) -> Result<Self> {
+        use tokio::net::UnixStream;
+
+        let stream = UnixStream::connect(path).await?;
+        Ok(stream.into())
+    }
+
+    #[cfg(feature = "async-std-runtime")]
+    async fn connect(path: &Path) -> Result<Self> {
+        use async_std::os::unix::net::UnixStream;
+
+        let stream = UnixStream::connect(path).await?;
+        Ok(stream.into())
+    }
+}
+
 impl tokio::io::AsyncRead for AsyncStream {
     fn poll_read(
         mut self: Pin<&mut Self>,
@@ -154,6 +218,8 @@ impl tokio::io::AsyncRead for AsyncStream {
             Self::Null => Poll::Ready(Ok(())),
             Self::Tcp(ref mut inner) => tokio::io::AsyncRead::poll_read(Pin::new(inner), cx, buf),
             Self::Tls(ref mut inner) => tokio::io::AsyncRead::poll_read(Pin::new(inner), cx, buf),
+            #[cfg(unix)]
+            Self::Unix(ref mut inner) => tokio::io::AsyncRead::poll_read(Pin::new(inner), cx, buf),
         }
     }
 }
@@ -168,6 +234,8 @@ impl AsyncWrite for AsyncStream {
             Self::Null => Poll::Ready(Ok(0)),
             Self::Tcp(ref mut inner) => AsyncWrite::poll_write(Pin::new(inner), cx, buf),
             Self::Tls(ref mut inner) => Pin::new(inner).poll_write(cx, buf),
+            #[cfg(unix)]
+            Self::Unix(ref mut inner) => AsyncWrite::poll_write(Pin::new(inner), cx, buf),
         }
     }
 
@@ -176,6 +244,8 @@ impl AsyncWrite for AsyncStream {
             Self::Null => Poll::Ready(Ok(())),
             Self::Tcp(ref mut inner) => AsyncWrite::poll_flush(Pin::new(inner), cx),
             Self::Tls(ref mut inner) => Pin::new(inner).poll_flush(cx),
+            #[cfg(unix)]
+            Self::Unix(ref mut inner) => AsyncWrite::poll_flush(Pin::new(inner), cx),
         }
     }
 
@@ -184,6 +254,8 @@ impl AsyncWrite for AsyncStream {
             Self::Null => Poll::Ready(Ok(())),
             Self::Tcp(ref mut inner) => Pin::new(inner).poll_shutdown(cx),
             Self::Tls(ref mut inner) => Pin::new(inner).poll_shutdown(cx),
+            #[cfg(unix)]
+            Self::Unix(ref mut inner) => Pin::new(inner).poll_shutdown(cx),
         }
     }
 }
@@ -255,3 +327,73 @@ impl AsyncWrite for AsyncTcpStream {
         }
     }
 }
+
+#[cfg(unix)]
+impl AsyncRead for AsyncUnixStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf,
+    ) -> Poll<tokio::io::Result<()>> {
+        match self.deref_mut() {
+            #[cfg(feature = "tokio-runtime")]
+            Self::Tokio(ref mut inner) => Pin::new(inner).poll_read(cx, buf),
+
+            #[cfg(feature = "async-std-runtime")]
+            Self::AsyncStd(ref mut inner) => {
+                use tokio_util::compat::FuturesAsyncReadCompatExt;
+
+                Pin::new(&mut inner.compat()).poll_read(cx, buf)
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+impl AsyncWrite for AsyncUnixStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<tokio::io::Result<usize>> {
+        match self.deref_mut() {
+            #[cfg(feature = "tokio-runtime")]
+            Self::Tokio(ref mut inner) => Pin::new(inner).poll_write(cx, buf),
+
+            #[cfg(feature = "async-std-runtime")]
+            Self::AsyncStd(ref mut inner) => {
+                use tokio_util::compat::FuturesAsyncReadCompatExt;
+
+                Pin::new(&mut inner.compat()).poll_write(cx, buf)
+            }
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<tokio::io::Result<()>> {
+        match self.deref_mut() {
+            #[cfg(feature = "tokio-runtime")]
+            Self::Tokio(ref mut inner) => Pin::new(inner).poll_flush(cx),
+
+            #[cfg(feature = "async-std-runtime")]
+            Self::AsyncStd(ref mut inner) => {
+                use tokio_util::compat::FuturesAsyncReadCompatExt;
+
+                Pin::new(&mut inner.compat()).poll_flush(cx)
+            }
+        }
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<tokio::io::Result<()>> {
+        match self.deref_mut() {
+            #[cfg(feature = "tokio-runtime")]
+            Self::Tokio(ref mut inner) => Pin::new(inner).poll_shutdown(cx),
+
+            #[cfg(feature = "async-std-runtime")]
+            Self::AsyncStd(ref mut inner) => {
+                use tokio_util::compat::FuturesAsyncReadCompatExt;
+
+                Pin::new(&mut inner.compat()).poll_shutdown(cx)
+            }
+        }
+    }
+}
@@ -4,20 +4,50 @@ use std::{
     task::{Context, Poll},
 };
 
+use lazy_static::lazy_static;
 use openssl::{
     error::ErrorStack,
-    ssl::{SslConnector, SslFiletype, SslMethod, SslVerifyMode},
+    ex_data::Index,
+    hash::MessageDigest,
+    ocsp::{OcspCertId, OcspCertStatus, OcspFlag, OcspResponse, OcspResponseStatus},
+    pkey::PKey,
+    ssl::{
+        Ssl,
+        SslConnector,
+        SslConnectorBuilder,
+        SslFiletype,
+        SslMethod,
+        SslRef,
+        SslVerifyMode,
+        StatusType,
+    },
+    x509::{store::X509Lookup, verify::X509VerifyFlags, X509VerifyResult, X509},
 };
 use tokio::io::{AsyncRead, AsyncWrite};
 use tokio_openssl::SslStream;
 
 use crate::{
-    client::options::TlsOptions,
+    client::options::{TlsData, TlsOptions},
     error::{Error, ErrorKind, Result},
 };
 
 use super::stream::AsyncTcpStream;
 
+/// The value of `X509_V_ERR_CERT_REVOKED`, the verification result OpenSSL reports when a
+/// certificate is listed in a CRL.
+const X509_V_ERR_CERT_REVOKED: i32 = 23;
+
+/// The number of seconds of clock skew to tolerate when checking the validity period of a stapled
+/// OCSP response.
+const OCSP_VALIDITY_LEEWAY_SECS: u32 = 5 * 60;
+
+lazy_static! {
+    /// Set on an `Ssl` when the server's stapled OCSP response reports its certificate as revoked,
+    /// so that the resulting handshake failure can be reported as such.
+    static ref OCSP_REVOKED_INDEX: Index<Ssl, ()> =
+        Ssl::new_ex_index().expect("failed to allocate OpenSSL ex data index");
+}
+
 #[derive(Debug)]
 pub(crate) struct AsyncTlsStream {
     inner: SslStream<AsyncTcpStream>,
@@ -29,6 +59,7 @@ pub(crate) struct AsyncTlsStream {
 pub(crate) struct TlsConfig {
     connector: SslConnector,
     verify_hostname: bool,
+    request_ocsp_response: bool,
 }
 
 impl TlsConfig {
@@ -40,15 +71,15 @@ impl TlsConfig {
             None => true,
         };
 
-        let connector = make_openssl_connector(options).map_err(|e| {
-            Error::from(ErrorKind::InvalidTlsConfig {
-                message: e.to_string(),
-            })
-        })?;
+        let request_ocsp_response = options.allow_invalid_certificates != Some(true)
+            && options.disable_certificate_revocation_check != Some(true);
+
+        let connector = make_openssl_connector(options)?;
 
         Ok(TlsConfig {
             connector,
             verify_hostname,
+            request_ocsp_response,
         })
     }
 }
@@ -66,17 +97,37 @@ impl AsyncTlsStream {
                 message: err.to_string(),
             })
         })?;
-        Pin::new(&mut stream).connect().await.map_err(|err| {
-            use std::io;
-            match err.into_io_error() {
-                Ok(err) => err,
-                Err(err) => io::Error::new(io::ErrorKind::Other, err),
-            }
-        })?;
+        let result = Pin::new(&mut stream).connect().await;
+        if let Err(err) = result {
+            return Err(handshake_error(stream.ssl(), err));
+        }
         Ok(AsyncTlsStream { inner: stream })
     }
 }
 
+/// Converts an error from the TLS handshake, reporting revoked certificates with their own error
+/// kind rather than as generic I/O errors.
+fn handshake_error(ssl: &SslRef, err: openssl::ssl::Error) -> Error {
+    if ssl.verify_result().as_raw() == X509_V_ERR_CERT_REVOKED {
+        return ErrorKind::CertificateRevoked {
+            message: "it is listed in the configured CRL file".into(),
+        }
+        .into();
+    }
+    if ssl.ex_data(*OCSP_REVOKED_INDEX).is_some() {
+        return ErrorKind::CertificateRevoked {
+            message: "the OCSP response stapled by the server reports it as revoked".into(),
+        }
+        .into();
+    }
+
+    let err = match err.into_io_error() {
+        Ok(err) => err,
+        Err(err) => std::io::Error::new(std::io::ErrorKind::Other, err),
+    };
+    err.into()
+}
+
 impl AsyncRead for AsyncTlsStream {
     fn poll_read(
         mut self: Pin<&mut Self>,
@@ -105,30 +156,171 @@ impl AsyncWrite for AsyncTlsStream {
     }
 }
 
-fn make_openssl_connector(cfg: TlsOptions) -> std::result::Result<SslConnector, ErrorStack> {
-    let mut builder = SslConnector::builder(SslMethod::tls_client())?;
+fn make_openssl_connector(cfg: TlsOptions) -> Result<SslConnector> {
+    let mut builder = SslConnector::builder(SslMethod::tls_client()).map_err(invalid_config)?;
 
     let TlsOptions {
         allow_invalid_certificates,
         ca_file_path,
         cert_key_file_path,
+        ca_certificates,
+        client_certificate,
+        client_key,
+        cert_key_password,
+        identity_provider: _,
+        disable_certificate_revocation_check,
+        disable_ocsp_endpoint_check: _,
+        crl_file_path,
         allow_invalid_hostnames: _,
+        #[cfg(docsrs)]
+            rustls_config: _,
+        #[cfg(docsrs)]
+            certificate_verifier: _,
     } = cfg;
 
     if let Some(true) = allow_invalid_certificates {
         builder.set_verify(SslVerifyMode::NONE);
     }
     if let Some(path) = ca_file_path {
-        builder.set_ca_file(path)?;
+        builder.set_ca_file(path).map_err(invalid_config)?;
+    }
+    if let Some(data) = ca_certificates {
+        for cert in read_certificates(&data).map_err(invalid_config)? {
+            builder
+                .cert_store_mut()
+                .add_cert(cert)
+                .map_err(invalid_config)?;
+        }
     }
+
+    if allow_invalid_certificates != Some(true)
+        && disable_certificate_revocation_check != Some(true)
+    {
+        if let Some(path) = crl_file_path {
+            let store = builder.cert_store_mut();
+            store
+                .add_lookup(X509Lookup::file())
+                .map_err(invalid_config)?
+                .load_crl_file(path, SslFiletype::PEM)
+                .map_err(invalid_config)?;
+            store
+                .set_flags(X509VerifyFlags::CRL_CHECK)
+                .map_err(invalid_config)?;
+        }
+        builder
+            .set_status_callback(check_stapled_ocsp_response)
+            .map_err(invalid_config)?;
+    }
+
+    let password = cert_key_password.as_deref();
     if let Some(path) = cert_key_file_path {
-        builder.set_certificate_file(path.clone(), SslFiletype::PEM)?;
-        builder.set_private_key_file(path, SslFiletype::PEM)?;
+        match password {
+            Some(password) => {
+                let pem = TlsData::Pem(std::fs::read(&path)?);
+                set_identity(&mut builder, &pem, &pem, Some(password)).map_err(invalid_config)?;
+            }
+            None => {
+                builder
+                    .set_certificate_file(path.clone(), SslFiletype::PEM)
+                    .map_err(invalid_config)?;
+                builder
+                    .set_private_key_file(path, SslFiletype::PEM)
+                    .map_err(invalid_config)?;
+            }
+        }
+    } else if let Some(cert) = client_certificate {
+        let key = client_key.as_ref().unwrap_or(&cert);
+        set_identity(&mut builder, &cert, key, password).map_err(invalid_config)?;
     }
 
     Ok(builder.build())
 }
 
+/// Checks the OCSP response stapled by the server, if any, failing the handshake if it reports the
+/// server's certificate as revoked or if it is invalid. Servers that don't staple a response are
+/// accepted, as the driver does not contact OCSP responders itself.
+fn check_stapled_ocsp_response(ssl: &mut SslRef) -> std::result::Result<bool, ErrorStack> {
+    let response = match ssl.ocsp_status() {
+        Some(der) => OcspResponse::from_der(der)?,
+        None => return Ok(true),
+    };
+    if response.status() != OcspResponseStatus::SUCCESSFUL {
+        return Ok(true);
+    }
+    let basic = response.basic()?;
+
+    let revoked = {
+        let (leaf, chain) = match (ssl.peer_certificate(), ssl.peer_cert_chain()) {
+            (Some(leaf), Some(chain)) => (leaf, chain),
+            _ => return Ok(true),
+        };
+        let issuer = match chain
+            .iter()
+            .find(|cert| cert.issued(&leaf) == X509VerifyResult::OK)
+        {
+            Some(issuer) => issuer,
+            None => return Ok(true),
+        };
+        basic.verify(chain, ssl.ssl_context().cert_store(), OcspFlag::empty())?;
+
+        let id = OcspCertId::from_cert(MessageDigest::sha1(), &leaf, issuer)?;
+        match basic.find_status(&id) {
+            Some(status) => {
+                status.check_validity(OCSP_VALIDITY_LEEWAY_SECS, None)?;
+                status.status == OcspCertStatus::REVOKED
+            }
+            None => false,
+        }
+    };
+
+    if revoked {
+        ssl.set_ex_data(*OCSP_REVOKED_INDEX, ());
+    }
+    Ok(!revoked)
+}
+
+fn invalid_config(error: ErrorStack) -> Error {
+    Error::from(ErrorKind::InvalidTlsConfig {
+        message: error.to_string(),
+    })
+}
+
+fn read_certificates(data: &TlsData) -> std::result::Result<Vec<X509>, ErrorStack> {
+    match data {
+        TlsData::Pem(bytes) => X509::stack_from_pem(bytes),
+        TlsData::Der(bytes) => Ok(vec![X509::from_der(bytes)?]),
+    }
+}
+
+/// Configures the certificate chain and private key that the client presents to the server.
+fn set_identity(
+    builder: &mut SslConnectorBuilder,
+    cert: &TlsData,
+    key: &TlsData,
+    password: Option<&str>,
+) -> std::result::Result<(), ErrorStack> {
+    let mut chain = read_certificates(cert)?.into_iter();
+    if let Some(leaf) = chain.next() {
+        builder.set_certificate(&leaf)?;
+    }
+    for intermediate in chain {
+        builder.add_extra_chain_cert(intermediate)?;
+    }
+
+    let key = match (key, password) {
+        (TlsData::Pem(bytes), Some(password)) => {
+            PKey::private_key_from_pem_passphrase(bytes, password.as_bytes())?
+        }
+        (TlsData::Pem(bytes), None) => PKey::private_key_from_pem(bytes)?,
+        (TlsData::Der(bytes), Some(password)) => {
+            PKey::private_key_from_pkcs8_passphrase(bytes, password.as_bytes())?
+        }
+        (TlsData::Der(bytes), None) => PKey::private_key_from_der(bytes)?,
+    };
+    builder.set_private_key(&key)?;
+    builder.check_private_key()
+}
+
 fn init_trust() {
     static ONCE: Once = Once::new();
     ONCE.call_once(openssl_probe::init_ssl_cert_env_vars);
@@ -139,11 +331,14 @@ fn make_ssl_stream(
     tcp_stream: AsyncTcpStream,
     cfg: &TlsConfig,
 ) -> std::result::Result<SslStream<AsyncTcpStream>, ErrorStack> {
-    let ssl = cfg
+    let mut config = cfg
         .connector
         .configure()?
         .use_server_name_indication(true)
-        .verify_hostname(cfg.verify_hostname)
-        .into_ssl(host)?;
+        .verify_hostname(cfg.verify_hostname);
+    if cfg.request_ocsp_response {
+        config.set_status_type(StatusType::OCSP)?;
+    }
+    let ssl = config.into_ssl(host)?;
     SslStream::new(ssl, tcp_stream)
 }
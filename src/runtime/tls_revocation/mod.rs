@@ -0,0 +1,620 @@
+//! Certificate revocation checking for the rustls TLS backend. This contains just enough of a DER
+//! reader to pull the relevant fields out of X.509 certificates and CRLs
+//! ([RFC 5280](https://www.rfc-editor.org/rfc/rfc5280)) and of stapled OCSP responses
+//! ([RFC 6960](https://www.rfc-editor.org/rfc/rfc6960)).
+
+#[cfg(test)]
+mod test;
+
+use std::{path::Path, time::SystemTime};
+
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use rustls::{
+    client::{ServerCertVerified, ServerCertVerifier, ServerName, WebPkiVerifier},
+    Certificate,
+    DigitallySignedStruct,
+    Error as TlsError,
+    RootCertStore,
+    SignatureScheme,
+};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+use crate::error::{ErrorKind, Result};
+
+const TAG_BOOLEAN: u8 = 0x01;
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_ENUMERATED: u8 = 0x0a;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_UTC_TIME: u8 = 0x17;
+const TAG_GENERALIZED_TIME: u8 = 0x18;
+const TAG_CONTEXT_0: u8 = 0xa0;
+const TAG_CONTEXT_3: u8 = 0xa3;
+/// The tags of the implicitly-tagged `issuerUniqueID` and `subjectUniqueID` certificate fields.
+const TAG_ISSUER_UNIQUE_ID: u8 = 0x81;
+const TAG_SUBJECT_UNIQUE_ID: u8 = 0x82;
+
+/// The contents of the `id-pkix-ocsp-basic` OID (1.3.6.1.5.5.7.48.1.1).
+const OID_OCSP_BASIC: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x30, 0x01, 0x01];
+/// The contents of the `id-sha1` OID (1.3.14.3.2.26).
+const OID_SHA1: &[u8] = &[0x2b, 0x0e, 0x03, 0x02, 0x1a];
+/// The contents of the `id-sha256` OID (2.16.840.1.101.3.4.2.1).
+const OID_SHA256: &[u8] = &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01];
+/// The contents of the `id-ce-extKeyUsage` OID (2.5.29.37).
+const OID_EXTENDED_KEY_USAGE: &[u8] = &[0x55, 0x1d, 0x25];
+/// The contents of the `id-kp-OCSPSigning` OID (1.3.6.1.5.5.7.3.9).
+const OID_OCSP_SIGNING: &[u8] = &[0x2b, 0x06, 0x01, 0x05, 0x05, 0x07, 0x03, 0x09];
+
+/// The contents of the `sha256WithRSAEncryption` OID (1.2.840.113549.1.1.11).
+const OID_RSA_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+/// The contents of the `sha384WithRSAEncryption` OID (1.2.840.113549.1.1.12).
+const OID_RSA_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c];
+/// The contents of the `sha512WithRSAEncryption` OID (1.2.840.113549.1.1.13).
+const OID_RSA_SHA512: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d];
+/// The contents of the `ecdsa-with-SHA256` OID (1.2.840.10045.4.3.2).
+const OID_ECDSA_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+/// The contents of the `ecdsa-with-SHA384` OID (1.2.840.10045.4.3.3).
+const OID_ECDSA_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+/// The contents of the `id-Ed25519` OID (1.3.101.112).
+const OID_ED25519: &[u8] = &[0x2b, 0x65, 0x70];
+
+/// The tag of the `revoked` choice of an OCSP `CertStatus`.
+const OCSP_STATUS_REVOKED: u8 = 0xa1;
+
+/// The number of seconds of clock skew to tolerate when checking the validity period of a stapled
+/// OCSP response.
+const OCSP_VALIDITY_LEEWAY_SECS: i64 = 5 * 60;
+
+/// Prefix of the messages of errors returned when a certificate has been revoked, used to tell
+/// them apart from other verification failures once rustls has wrapped them in an I/O error.
+const REVOKED_PREFIX: &str = "certificate revoked: ";
+
+/// A certificate verifier that performs the standard WebPKI verification and then checks whether
+/// the server's certificate has been revoked.
+pub(super) struct RevocationCheckingVerifier {
+    inner: WebPkiVerifier,
+    /// The CA certificates configured by the user, used to verify the signatures of stapled OCSP
+    /// responses for certificates that they issued directly. The default roots aren't needed here
+    /// since public CAs don't issue server certificates from their roots.
+    ca_certificates: Vec<Certificate>,
+    crls: Option<Vec<Crl>>,
+}
+
+impl RevocationCheckingVerifier {
+    pub(super) fn new(
+        roots: RootCertStore,
+        ca_certificates: Vec<Certificate>,
+        crls: Option<Vec<Crl>>,
+    ) -> Self {
+        Self {
+            inner: WebPkiVerifier::new(roots, None),
+            ca_certificates,
+            crls,
+        }
+    }
+
+    /// Finds the certificate with the given subject, which must be either one of the
+    /// intermediates sent by the server or one of the configured CA certificates.
+    fn issuer<'a>(
+        &'a self,
+        issuer: &[u8],
+        intermediates: &'a [Certificate],
+    ) -> Option<&'a Certificate> {
+        let issuer = Der::new(issuer).read(TAG_SEQUENCE)?;
+        intermediates
+            .iter()
+            .chain(self.ca_certificates.iter())
+            .find(|cert| {
+                ParsedCertificate::parse(&cert.0).map_or(false, |cert| cert.subject == issuer)
+            })
+    }
+
+    /// Whether `signed` was signed with the private key of the given certificate. Signatures are
+    /// checked the same way rustls checks the signatures made by servers during TLS 1.2
+    /// handshakes.
+    fn is_signed_by(&self, signed: &Signed, signer: &Certificate) -> bool {
+        let scheme = match signed.algorithm {
+            OID_RSA_SHA256 => SignatureScheme::RSA_PKCS1_SHA256,
+            OID_RSA_SHA384 => SignatureScheme::RSA_PKCS1_SHA384,
+            OID_RSA_SHA512 => SignatureScheme::RSA_PKCS1_SHA512,
+            OID_ECDSA_SHA256 => SignatureScheme::ECDSA_NISTP256_SHA256,
+            OID_ECDSA_SHA384 => SignatureScheme::ECDSA_NISTP384_SHA384,
+            OID_ED25519 => SignatureScheme::ED25519,
+            _ => return false,
+        };
+        let signature = DigitallySignedStruct::new(scheme, signed.signature.to_vec());
+        self.inner
+            .verify_tls12_signature(signed.data, signer, &signature)
+            .is_ok()
+    }
+}
+
+impl ServerCertVerifier for RevocationCheckingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &Certificate,
+        intermediates: &[Certificate],
+        server_name: &ServerName,
+        scts: &mut dyn Iterator<Item = &[u8]>,
+        ocsp_response: &[u8],
+        now: SystemTime,
+    ) -> std::result::Result<ServerCertVerified, TlsError> {
+        let verified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            scts,
+            ocsp_response,
+            now,
+        )?;
+
+        let cert = ParsedCertificate::parse(&end_entity.0).ok_or_else(|| {
+            TlsError::InvalidCertificateData("unable to parse server certificate".into())
+        })?;
+
+        if let Some(ref crls) = self.crls {
+            check_crls(crls, &cert, now)?;
+        }
+
+        if !ocsp_response.is_empty() {
+            if let Some(issuer) = self.issuer(cert.issuer, intermediates) {
+                check_ocsp_response(self, ocsp_response, &cert, issuer, now)?;
+            }
+        }
+
+        Ok(verified)
+    }
+}
+
+/// If the given error was returned because a certificate was revoked, returns a description of
+/// why.
+pub(super) fn revocation_message(error: &TlsError) -> Option<&str> {
+    match error {
+        TlsError::InvalidCertificateData(message) => message.strip_prefix(REVOKED_PREFIX),
+        _ => None,
+    }
+}
+
+fn revoked(message: &str) -> TlsError {
+    TlsError::InvalidCertificateData(format!("{}{}", REVOKED_PREFIX, message))
+}
+
+/// A value signed with the `SIGNED{}` construction shared by certificates and OCSP responses: a
+/// sequence of the signed value, the signature algorithm and the signature.
+struct Signed<'a> {
+    /// The DER encoding of the signed value.
+    data: &'a [u8],
+    /// The contents of the signature algorithm's OID.
+    algorithm: &'a [u8],
+    signature: &'a [u8],
+    /// The fields following the signature, if any.
+    rest: Der<'a>,
+}
+
+impl<'a> Signed<'a> {
+    fn parse(der: &'a [u8]) -> Option<Self> {
+        let mut signed = Der::new(der).enter(TAG_SEQUENCE)?;
+        let data = signed.read_raw(TAG_SEQUENCE)?;
+        let algorithm = signed.enter(TAG_SEQUENCE)?.read(TAG_OID)?;
+        let signature = signed.read_bit_string()?;
+        Some(Self {
+            data,
+            algorithm,
+            signature,
+            rest: signed,
+        })
+    }
+}
+
+/// The fields of a certificate needed to look it up in a CRL or OCSP response, or to check that it
+/// may sign OCSP responses.
+struct ParsedCertificate<'a> {
+    signed: Signed<'a>,
+    serial: &'a [u8],
+    /// The DER encoding of the issuer's distinguished name.
+    issuer: &'a [u8],
+    not_before: DateTime<Utc>,
+    not_after: DateTime<Utc>,
+    /// The contents of the subject's distinguished name.
+    subject: &'a [u8],
+    /// The value of the `subjectPublicKey` bit string, which OCSP responses identify issuers by.
+    public_key: &'a [u8],
+    /// Whether the extended key usage extension allows the certificate to sign OCSP responses.
+    ocsp_signing: bool,
+}
+
+impl<'a> ParsedCertificate<'a> {
+    fn parse(der: &'a [u8]) -> Option<Self> {
+        let signed = Signed::parse(der)?;
+        let mut tbs = Der::new(signed.data).enter(TAG_SEQUENCE)?;
+        tbs.optional(TAG_CONTEXT_0)?; // version
+        let serial = tbs.read(TAG_INTEGER)?;
+        tbs.skip()?; // signature algorithm
+        let issuer = tbs.read_raw(TAG_SEQUENCE)?;
+        let mut validity = tbs.enter(TAG_SEQUENCE)?;
+        let not_before = validity.read_time()?;
+        let not_after = validity.read_time()?;
+        let subject = tbs.read(TAG_SEQUENCE)?;
+        let mut spki = tbs.enter(TAG_SEQUENCE)?;
+        spki.skip()?; // algorithm
+        let public_key = spki.read_bit_string()?;
+        tbs.optional(TAG_ISSUER_UNIQUE_ID)?;
+        tbs.optional(TAG_SUBJECT_UNIQUE_ID)?;
+
+        let mut ocsp_signing = false;
+        if let Some(mut extensions) = tbs.optional(TAG_CONTEXT_3)? {
+            let mut extensions = extensions.enter(TAG_SEQUENCE)?;
+            while !extensions.is_empty() {
+                let mut extension = extensions.enter(TAG_SEQUENCE)?;
+                if extension.read(TAG_OID)? != OID_EXTENDED_KEY_USAGE {
+                    continue;
+                }
+                extension.optional(TAG_BOOLEAN)?; // critical
+                let mut purposes =
+                    Der::new(extension.read(TAG_OCTET_STRING)?).enter(TAG_SEQUENCE)?;
+                while !purposes.is_empty() {
+                    ocsp_signing |= purposes.read(TAG_OID)? == OID_OCSP_SIGNING;
+                }
+            }
+        }
+
+        Some(Self {
+            signed,
+            serial,
+            issuer,
+            not_before,
+            not_after,
+            subject,
+            public_key,
+            ocsp_signing,
+        })
+    }
+}
+
+/// A parsed certificate revocation list.
+pub(super) struct Crl {
+    /// The DER encoding of the issuer's distinguished name.
+    issuer: Vec<u8>,
+    next_update: Option<DateTime<Utc>>,
+    revoked_serials: Vec<Vec<u8>>,
+}
+
+impl Crl {
+    fn parse(der: &[u8]) -> Option<Self> {
+        let mut tbs = Der::new(der).enter(TAG_SEQUENCE)?.enter(TAG_SEQUENCE)?;
+        tbs.optional(TAG_INTEGER)?; // version
+        tbs.skip()?; // signature algorithm
+        let issuer = tbs.read_raw(TAG_SEQUENCE)?.to_vec();
+        tbs.read_time()?; // thisUpdate
+        let next_update = match tbs.peek_tag() {
+            Some(TAG_UTC_TIME) | Some(TAG_GENERALIZED_TIME) => Some(tbs.read_time()?),
+            _ => None,
+        };
+
+        let mut revoked_serials = Vec::new();
+        if let Some(mut entries) = tbs.optional(TAG_SEQUENCE)? {
+            while !entries.is_empty() {
+                let mut entry = entries.enter(TAG_SEQUENCE)?;
+                revoked_serials.push(entry.read(TAG_INTEGER)?.to_vec());
+            }
+        }
+
+        Some(Self {
+            issuer,
+            next_update,
+            revoked_serials,
+        })
+    }
+}
+
+/// Reads all of the PEM-encoded CRLs in the file at `path`.
+pub(super) fn read_crl_file(path: &Path) -> Result<Vec<Crl>> {
+    const BEGIN: &str = "-----BEGIN X509 CRL-----";
+    const END: &str = "-----END X509 CRL-----";
+
+    let invalid = |message: &str| ErrorKind::InvalidTlsConfig {
+        message: format!("{} in {}", message, path.display()),
+    };
+
+    let contents = std::fs::read_to_string(path)?;
+    let mut crls = Vec::new();
+    let mut rest = contents.as_str();
+    while let Some(start) = rest.find(BEGIN) {
+        rest = &rest[start + BEGIN.len()..];
+        let end = rest
+            .find(END)
+            .ok_or_else(|| invalid("Unterminated PEM-encoded CRL"))?;
+        let body: String = rest[..end].split_whitespace().collect();
+        let der = base64::decode(body).map_err(|_| invalid("Invalid PEM-encoded CRL"))?;
+        crls.push(Crl::parse(&der).ok_or_else(|| invalid("Unable to parse CRL"))?);
+        rest = &rest[end + END.len()..];
+    }
+
+    if crls.is_empty() {
+        return Err(invalid("No PEM-encoded CRLs").into());
+    }
+    Ok(crls)
+}
+
+/// Checks the certificate against the CRLs from its issuer, requiring that at least one current
+/// CRL from the issuer is present.
+fn check_crls(
+    crls: &[Crl],
+    cert: &ParsedCertificate,
+    now: SystemTime,
+) -> std::result::Result<(), TlsError> {
+    let now = DateTime::<Utc>::from(now);
+    let mut found_current_crl = false;
+    for crl in crls.iter().filter(|crl| crl.issuer == cert.issuer) {
+        if crl
+            .revoked_serials
+            .iter()
+            .any(|serial| serial.as_slice() == cert.serial)
+        {
+            return Err(revoked("it is listed in the configured CRL file"));
+        }
+        found_current_crl |= crl.next_update.map_or(true, |next| next > now);
+    }
+
+    if !found_current_crl {
+        return Err(TlsError::InvalidCertificateData(
+            "the configured CRL file does not contain a current CRL from the server certificate's \
+             issuer"
+                .into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Checks the given DER-encoded OCSP response, failing if it reports the certificate as revoked or
+/// if it isn't signed by the certificate's issuer or by a responder that the issuer has delegated
+/// to. Responses that can't be parsed or that weren't successful are ignored, as are signed
+/// responses that don't mention the certificate or whose validity period doesn't include `now`.
+fn check_ocsp_response(
+    verifier: &RevocationCheckingVerifier,
+    response: &[u8],
+    cert: &ParsedCertificate,
+    issuer: &Certificate,
+    now: SystemTime,
+) -> std::result::Result<(), TlsError> {
+    let response = match BasicOcspResponse::parse(response) {
+        Some(response) => response,
+        None => return Ok(()),
+    };
+    let issuer_key = match ParsedCertificate::parse(&issuer.0) {
+        Some(parsed) => parsed.public_key,
+        None => return Ok(()),
+    };
+
+    let now = DateTime::<Utc>::from(now);
+    let signed_by_delegate = || {
+        response.certs.iter().any(|der| {
+            let responder = match ParsedCertificate::parse(der) {
+                Some(responder) => responder,
+                None => return false,
+            };
+            responder.issuer == cert.issuer
+                && responder.ocsp_signing
+                && responder.not_before <= now
+                && now <= responder.not_after
+                && verifier.is_signed_by(&responder.signed, issuer)
+                && verifier.is_signed_by(&response.signed, &Certificate(der.to_vec()))
+        })
+    };
+    if !verifier.is_signed_by(&response.signed, issuer) && !signed_by_delegate() {
+        return Err(TlsError::InvalidCertificateData(
+            "the OCSP response stapled by the server is not signed by the certificate's issuer or \
+             an authorized responder"
+                .into(),
+        ));
+    }
+
+    if response.status(cert, issuer_key, now) == Some(OCSP_STATUS_REVOKED) {
+        return Err(revoked(
+            "the OCSP response stapled by the server reports it as revoked",
+        ));
+    }
+    Ok(())
+}
+
+/// The fields of a successful, basic OCSP response.
+struct BasicOcspResponse<'a> {
+    signed: Signed<'a>,
+    /// The contents of the sequence of single responses.
+    responses: &'a [u8],
+    /// The DER encodings of the certificates included to help verify the signature.
+    certs: Vec<&'a [u8]>,
+}
+
+impl<'a> BasicOcspResponse<'a> {
+    fn parse(response: &'a [u8]) -> Option<Self> {
+        let mut response = Der::new(response).enter(TAG_SEQUENCE)?;
+        if response.read(TAG_ENUMERATED)? != &[0][..] {
+            // The responder didn't return a successful response.
+            return None;
+        }
+        let mut response_bytes = response.enter(TAG_CONTEXT_0)?.enter(TAG_SEQUENCE)?;
+        if response_bytes.read(TAG_OID)? != OID_OCSP_BASIC {
+            return None;
+        }
+        let mut signed = Signed::parse(response_bytes.read(TAG_OCTET_STRING)?)?;
+
+        let mut data = Der::new(signed.data).enter(TAG_SEQUENCE)?;
+        data.optional(TAG_CONTEXT_0)?; // version
+        data.skip()?; // responderID
+        data.skip()?; // producedAt
+        let responses = data.read(TAG_SEQUENCE)?;
+
+        let mut certs = Vec::new();
+        if let Some(mut list) = signed.rest.optional(TAG_CONTEXT_0)? {
+            let mut list = list.enter(TAG_SEQUENCE)?;
+            while !list.is_empty() {
+                certs.push(list.read_raw(TAG_SEQUENCE)?);
+            }
+        }
+
+        Some(Self {
+            signed,
+            responses,
+            certs,
+        })
+    }
+
+    /// Returns the tag of the status that the response reports for the certificate, if any.
+    /// `issuer_key` is the issuer's public key, and single responses that aren't valid at `now`
+    /// are ignored.
+    fn status(
+        &self,
+        cert: &ParsedCertificate,
+        issuer_key: &[u8],
+        now: DateTime<Utc>,
+    ) -> Option<u8> {
+        let leeway = chrono::Duration::seconds(OCSP_VALIDITY_LEEWAY_SECS);
+        let mut responses = Der::new(self.responses);
+        while !responses.is_empty() {
+            let mut single = responses.enter(TAG_SEQUENCE)?;
+            let mut cert_id = single.enter(TAG_SEQUENCE)?;
+            let hash_algorithm = cert_id.enter(TAG_SEQUENCE)?.read(TAG_OID)?;
+            let issuer_name_hash = cert_id.read(TAG_OCTET_STRING)?;
+            let issuer_key_hash = cert_id.read(TAG_OCTET_STRING)?;
+            let serial = cert_id.read(TAG_INTEGER)?;
+
+            let status = single.peek_tag()?;
+            single.skip()?;
+            let this_update = single.read_time()?;
+            let next_update = match single.optional(TAG_CONTEXT_0)? {
+                Some(mut next_update) => Some(next_update.read_time()?),
+                None => None,
+            };
+
+            let (expected_name_hash, expected_key_hash) = match hash_algorithm {
+                OID_SHA1 => (
+                    Sha1::digest(cert.issuer).to_vec(),
+                    Sha1::digest(issuer_key).to_vec(),
+                ),
+                OID_SHA256 => (
+                    Sha256::digest(cert.issuer).to_vec(),
+                    Sha256::digest(issuer_key).to_vec(),
+                ),
+                _ => continue,
+            };
+            if serial != cert.serial
+                || issuer_name_hash != expected_name_hash.as_slice()
+                || issuer_key_hash != expected_key_hash.as_slice()
+            {
+                continue;
+            }
+            if this_update > now + leeway || next_update.map_or(false, |next| next < now - leeway) {
+                continue;
+            }
+            return Some(status);
+        }
+        None
+    }
+}
+
+/// A reader over a sequence of DER-encoded values. Only single-byte tags are supported, which is
+/// all that the structures above use.
+struct Der<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Der<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        Self { input }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.input.is_empty()
+    }
+
+    fn peek_tag(&self) -> Option<u8> {
+        self.input.first().copied()
+    }
+
+    /// Reads the next value, returning its tag, contents and full encoding.
+    fn next(&mut self) -> Option<(u8, &'a [u8], &'a [u8])> {
+        let tag = *self.input.first()?;
+        let first_len_byte = *self.input.get(1)?;
+        let (len, header_len) = if first_len_byte < 0x80 {
+            (first_len_byte as usize, 2)
+        } else {
+            let len_bytes = (first_len_byte & 0x7f) as usize;
+            if len_bytes == 0 || len_bytes > 4 {
+                return None;
+            }
+            let len = self
+                .input
+                .get(2..2 + len_bytes)?
+                .iter()
+                .fold(0usize, |len, byte| (len << 8) | *byte as usize);
+            (len, 2 + len_bytes)
+        };
+        let end = header_len.checked_add(len)?;
+        let raw = self.input.get(..end)?;
+        self.input = &self.input[end..];
+        Some((tag, &raw[header_len..], raw))
+    }
+
+    /// Reads the contents of the next value, which must have the given tag.
+    fn read(&mut self, tag: u8) -> Option<&'a [u8]> {
+        match self.next()? {
+            (actual, contents, _) if actual == tag => Some(contents),
+            _ => None,
+        }
+    }
+
+    /// Reads the full encoding of the next value, which must have the given tag.
+    fn read_raw(&mut self, tag: u8) -> Option<&'a [u8]> {
+        match self.next()? {
+            (actual, _, raw) if actual == tag => Some(raw),
+            _ => None,
+        }
+    }
+
+    /// Returns a reader over the contents of the next value, which must have the given tag.
+    fn enter(&mut self, tag: u8) -> Option<Der<'a>> {
+        self.read(tag).map(Der::new)
+    }
+
+    /// Like `enter`, but returns `Some(None)` without consuming anything if the next value has a
+    /// different tag.
+    fn optional(&mut self, tag: u8) -> Option<Option<Der<'a>>> {
+        if self.peek_tag() == Some(tag) {
+            self.enter(tag).map(Some)
+        } else {
+            Some(None)
+        }
+    }
+
+    /// Reads the contents of the next value, which must be a bit string with no unused bits.
+    fn read_bit_string(&mut self) -> Option<&'a [u8]> {
+        match self.read(TAG_BIT_STRING)?.split_first()? {
+            (&0, bits) => Some(bits),
+            _ => None,
+        }
+    }
+
+    fn skip(&mut self) -> Option<()> {
+        self.next().map(|_| ())
+    }
+
+    fn read_time(&mut self) -> Option<DateTime<Utc>> {
+        let (tag, contents, _) = self.next()?;
+        let text = std::str::from_utf8(contents).ok()?;
+        let text = match tag {
+            // UTCTime has a two-digit year, which RFC 5280 says to interpret as 1950-2049.
+            TAG_UTC_TIME => {
+                let century = if text.get(..2)? >= "50" { "19" } else { "20" };
+                format!("{}{}", century, text)
+            }
+            TAG_GENERALIZED_TIME => text.to_string(),
+            _ => return None,
+        };
+        let time = NaiveDateTime::parse_from_str(&text, "%Y%m%d%H%M%SZ").ok()?;
+        Some(Utc.from_utc_datetime(&time))
+    }
+}
@@ -0,0 +1,149 @@
+use std::{path::PathBuf, time::SystemTime};
+
+use chrono::{DateTime, Utc};
+
+use rustls::{
+    client::{ServerCertVerifier, ServerName},
+    Certificate,
+    Error as TlsError,
+    RootCertStore,
+};
+
+use super::{
+    read_crl_file,
+    revocation_message,
+    BasicOcspResponse,
+    ParsedCertificate,
+    RevocationCheckingVerifier,
+    OCSP_STATUS_REVOKED,
+};
+
+// The fixtures were generated with the openssl CLI. The test CA issued the "good" and "revoked"
+// server certificates, lists the latter as revoked in its CRL, and signed the OCSP responses,
+// except for the delegated one, which was signed by a responder certificate that the CA issued
+// with the OCSP signing extended key usage, and the forged one, which was signed by an unrelated
+// self-signed certificate with the same subject as the CA.
+const CA: &[u8] = include_bytes!("testdata/ca.pem");
+const GOOD: &[u8] = include_bytes!("testdata/good.pem");
+const REVOKED: &[u8] = include_bytes!("testdata/revoked.pem");
+const OCSP_GOOD: &[u8] = include_bytes!("testdata/ocsp-good.der");
+const OCSP_REVOKED: &[u8] = include_bytes!("testdata/ocsp-revoked.der");
+const OCSP_REVOKED_DELEGATED: &[u8] = include_bytes!("testdata/ocsp-revoked-delegated.der");
+const OCSP_REVOKED_FORGED: &[u8] = include_bytes!("testdata/ocsp-revoked-forged.der");
+
+fn certificate(pem: &[u8]) -> Certificate {
+    let mut certs = rustls_pemfile::certs(&mut &*pem).unwrap();
+    assert_eq!(certs.len(), 1);
+    Certificate(certs.remove(0))
+}
+
+fn crl_path() -> PathBuf {
+    [
+        env!("CARGO_MANIFEST_DIR"),
+        "src",
+        "runtime",
+        "tls_revocation",
+        "testdata",
+        "crl.pem",
+    ]
+    .iter()
+    .collect()
+}
+
+fn verify(cert: &[u8], ocsp_response: &[u8], use_crl: bool) -> Result<(), TlsError> {
+    let ca = certificate(CA);
+    let mut roots = RootCertStore::empty();
+    roots.add(&ca).unwrap();
+    let crls = if use_crl {
+        Some(read_crl_file(&crl_path()).unwrap())
+    } else {
+        None
+    };
+
+    RevocationCheckingVerifier::new(roots, vec![ca], crls)
+        .verify_server_cert(
+            &certificate(cert),
+            &[],
+            &ServerName::try_from("localhost").unwrap(),
+            &mut std::iter::empty(),
+            ocsp_response,
+            SystemTime::now(),
+        )
+        .map(|_| ())
+}
+
+fn time(rfc3339: &str) -> DateTime<Utc> {
+    rfc3339.parse().unwrap()
+}
+
+fn assert_revoked(result: Result<(), TlsError>) {
+    let error = result.unwrap_err();
+    assert!(revocation_message(&error).is_some(), "{:?}", error);
+}
+
+#[test]
+fn no_revocation_information() {
+    verify(GOOD, &[], false).unwrap();
+    verify(REVOKED, &[], false).unwrap();
+}
+
+#[test]
+fn crl() {
+    let crls = read_crl_file(&crl_path()).unwrap();
+    assert_eq!(crls.len(), 1);
+    assert_eq!(crls[0].revoked_serials, vec![vec![0x10, 0x02]]);
+
+    verify(GOOD, &[], true).unwrap();
+    assert_revoked(verify(REVOKED, &[], true));
+}
+
+#[test]
+fn stapled_ocsp_response() {
+    verify(GOOD, OCSP_GOOD, false).unwrap();
+    assert_revoked(verify(REVOKED, OCSP_REVOKED, false));
+
+    // A response about a different certificate says nothing about this one.
+    verify(GOOD, OCSP_REVOKED, false).unwrap();
+}
+
+#[test]
+fn stapled_ocsp_response_from_delegated_responder() {
+    assert_revoked(verify(REVOKED, OCSP_REVOKED_DELEGATED, false));
+}
+
+#[test]
+fn stapled_ocsp_response_with_invalid_signature() {
+    let error = verify(REVOKED, OCSP_REVOKED_FORGED, false).unwrap_err();
+    assert!(revocation_message(&error).is_none());
+    assert!(matches!(error, TlsError::InvalidCertificateData(_)));
+
+    verify(GOOD, OCSP_REVOKED_FORGED, false).unwrap_err();
+}
+
+#[test]
+fn stapled_ocsp_response_validity() {
+    let ca = certificate(CA);
+    let ca = ParsedCertificate::parse(&ca.0).unwrap();
+    let revoked = certificate(REVOKED);
+    let revoked = ParsedCertificate::parse(&revoked.0).unwrap();
+    let response = BasicOcspResponse::parse(OCSP_REVOKED).unwrap();
+
+    // The response's thisUpdate is 2026-10-18T10:20:09Z and its nextUpdate is 2126-09-24T10:20:09Z.
+    let status = |issuer_key: &[u8], now| response.status(&revoked, issuer_key, now);
+    assert_eq!(
+        status(ca.public_key, time("2030-01-01T00:00:00Z")),
+        Some(OCSP_STATUS_REVOKED)
+    );
+    assert_eq!(
+        status(ca.public_key, time("2026-10-18T10:16:00Z")),
+        Some(OCSP_STATUS_REVOKED)
+    );
+    assert_eq!(status(ca.public_key, time("2026-10-18T10:00:00Z")), None);
+    assert_eq!(status(ca.public_key, time("2126-09-25T00:00:00Z")), None);
+
+    // A response identifying the issuer by a different key doesn't apply.
+    assert_eq!(
+        status(revoked.public_key, time("2030-01-01T00:00:00Z")),
+        None
+    );
+}
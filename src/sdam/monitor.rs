@@ -36,7 +36,7 @@ pub(crate) struct Monitor {
     connection_establisher: ConnectionEstablisher,
     topology_updater: TopologyUpdater,
     topology_watcher: TopologyWatcher,
-    sdam_event_emitter: Option<SdamEventEmitter>,
+    sdam_event_emitter: SdamEventEmitter,
     client_options: ClientOptions,
 
     /// The most recent topology version returned by the server in a hello response.
@@ -59,7 +59,7 @@ impl Monitor {
         address: ServerAddress,
         topology_updater: TopologyUpdater,
         topology_watcher: TopologyWatcher,
-        sdam_event_emitter: Option<SdamEventEmitter>,
+        sdam_event_emitter: SdamEventEmitter,
         manager_receiver: MonitorRequestReceiver,
         client_options: ClientOptions,
         connection_establisher: ConnectionEstablisher,
@@ -301,10 +301,10 @@ impl Monitor {
     where
         F: FnOnce() -> SdamEvent,
     {
-        if let Some(ref emitter) = self.sdam_event_emitter {
+        if self.sdam_event_emitter.is_active() {
             // We don't care about ordering or waiting for the event to have been received.
             #[allow(clippy::let_underscore_future)]
-            let _ = emitter.emit(event());
+            let _ = self.sdam_event_emitter.emit(event());
         }
     }
 
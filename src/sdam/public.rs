@@ -6,9 +6,10 @@ pub use crate::sdam::description::{server::ServerType, topology::TopologyType};
 use crate::{
     bson::DateTime,
     error::Error,
+    event::sdam::TopologyDescription,
     hello::HelloCommandResponse,
     options::ServerAddress,
-    sdam::ServerDescription,
+    sdam::{ServerDescription, TopologyWatcher},
     selection_criteria::TagSet,
 };
 
@@ -192,3 +193,55 @@ impl<'a> fmt::Display for ServerInfo<'a> {
         write!(f, " }}")
     }
 }
+
+/// A handle used to observe the topology that a [`Client`](crate::Client) is connected to as it
+/// changes over time. This can be obtained via
+/// [`Client::subscribe_to_topology`](crate::Client::subscribe_to_topology).
+///
+/// ```rust
+/// # use mongodb::{Client, error::Result};
+/// # async fn func() -> Result<()> {
+/// let client = Client::with_uri_str("mongodb://example.com").await?;
+/// let mut subscriber = client.subscribe_to_topology();
+/// while let Some(description) = subscriber.changed().await {
+///     println!("topology changed to {:?}", description.topology_type());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TopologySubscriber {
+    watcher: TopologyWatcher,
+    last_seen: crate::sdam::TopologyDescription,
+}
+
+impl TopologySubscriber {
+    pub(crate) fn new(mut watcher: TopologyWatcher) -> Self {
+        let last_seen = watcher.observe_latest().description;
+        Self { watcher, last_seen }
+    }
+
+    /// Gets the most up-to-date description of the topology.
+    pub fn description(&self) -> TopologyDescription {
+        self.watcher.peek_latest().description.clone().into()
+    }
+
+    /// Waits for the topology to change from the description last returned by this method (or
+    /// from the one at the time of subscription, if this method hasn't been called yet) and
+    /// returns the new description.
+    ///
+    /// Updates that don't affect server selection, such as new round trip time samples, are not
+    /// considered changes. Returns `None` once the `Client` has been shut down or dropped.
+    pub async fn changed(&mut self) -> Option<TopologyDescription> {
+        loop {
+            let latest = self.watcher.observe_latest().description;
+            if latest != self.last_seen {
+                self.last_seen = latest.clone();
+                return Some(latest.into());
+            }
+            if !self.watcher.wait_for_update(None).await {
+                return None;
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test;
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use super::{
     description::topology::TopologyType,
@@ -12,15 +12,15 @@ use super::{
 use crate::{
     error::{Error, Result},
     options::ClientOptions,
-    runtime,
-    srv::{LookupHosts, SrvResolver},
+    runtime::{self, AsyncJoinHandle},
+    srv::{LookupHosts, SeedlistResolver},
 };
 
 const MIN_RESCAN_SRV_INTERVAL: Duration = Duration::from_secs(60);
 
 pub(crate) struct SrvPollingMonitor {
     initial_hostname: String,
-    resolver: Option<SrvResolver>,
+    resolver: Option<SeedlistResolver>,
     topology_updater: TopologyUpdater,
     topology_watcher: TopologyWatcher,
     rescan_interval: Duration,
@@ -51,14 +51,16 @@ impl SrvPollingMonitor {
     /// Starts a monitoring task that periodically performs SRV record lookups to determine if the
     /// set of mongos in the cluster have changed. A weak reference is used to ensure that the
     /// monitoring task doesn't keep the topology alive after the client has been dropped.
+    ///
+    /// Returns a handle that can be awaited to wait for the task to exit once the topology has
+    /// closed, or `None` if no monitoring is needed.
     pub(super) fn start(
         topology: TopologyUpdater,
         topology_watcher: TopologyWatcher,
         client_options: ClientOptions,
-    ) {
-        if let Some(monitor) = Self::new(topology, topology_watcher, client_options) {
-            runtime::execute(monitor.execute());
-        }
+    ) -> Option<AsyncJoinHandle<()>> {
+        Self::new(topology, topology_watcher, client_options)
+            .map(|monitor| runtime::spawn(monitor.execute()))
     }
 
     fn rescan_interval(&self) -> Duration {
@@ -71,7 +73,10 @@ impl SrvPollingMonitor {
         }
 
         while self.topology_watcher.is_alive() {
-            runtime::delay_for(self.rescan_interval()).await;
+            self.wait_for_rescan().await;
+            if !self.topology_watcher.is_alive() {
+                break;
+            }
 
             if should_poll(self.topology_watcher.topology_type()) {
                 let hosts = self.lookup_hosts().await;
@@ -85,6 +90,18 @@ impl SrvPollingMonitor {
         }
     }
 
+    /// Waits until the rescan interval has elapsed, returning early if the topology is closed.
+    async fn wait_for_rescan(&mut self) {
+        let deadline = Instant::now() + self.rescan_interval();
+        while self.topology_watcher.is_alive() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining == Duration::ZERO {
+                return;
+            }
+            self.topology_watcher.wait_for_update(remaining).await;
+        }
+    }
+
     async fn update_hosts(&mut self, lookup: Result<LookupHosts>) {
         let lookup = match lookup {
             Ok(LookupHosts { hosts, .. }) if hosts.is_empty() => {
@@ -123,13 +140,16 @@ impl SrvPollingMonitor {
         resolver.get_srv_hosts(initial_hostname.as_str()).await
     }
 
-    async fn get_or_create_srv_resolver(&mut self) -> Result<&SrvResolver> {
+    async fn get_or_create_srv_resolver(&mut self) -> Result<&SeedlistResolver> {
         if let Some(ref resolver) = self.resolver {
             return Ok(resolver);
         }
 
-        let resolver =
-            SrvResolver::new(self.client_options.resolver_config.clone().map(|c| c.inner)).await?;
+        let resolver = SeedlistResolver::new(
+            self.client_options.srv_resolver.clone(),
+            self.client_options.resolver_config.clone().map(|c| c.inner),
+        )
+        .await?;
 
         // Since the connection was not `Some` above, this will always insert the new connection and
         // return a reference to it.
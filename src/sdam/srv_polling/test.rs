@@ -1,4 +1,4 @@
-use std::{collections::HashSet, time::Duration};
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
 use pretty_assertions::assert_eq;
 use tokio::sync::RwLockReadGuard;
@@ -6,10 +6,10 @@ use tokio::sync::RwLockReadGuard;
 use super::{LookupHosts, SrvPollingMonitor};
 use crate::{
     error::Result,
-    options::{ClientOptions, ServerAddress},
+    options::{ClientOptions, ServerAddress, SrvRecord},
     runtime,
     sdam::Topology,
-    test::{log_uncaptured, CLIENT_OPTIONS, LOCK},
+    test::{log_uncaptured, util::StaticSrvResolver, CLIENT_OPTIONS, LOCK},
 };
 
 fn localhost_test_build_10gen(port: u16) -> ServerAddress {
@@ -141,3 +141,53 @@ async fn load_balanced_no_srv_polling() {
         topology.server_addresses()
     );
 }
+
+// A custom resolver set on the client options should be used to poll for new hosts.
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn custom_srv_resolver() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let resolver = Arc::new(StaticSrvResolver::new(
+        vec![
+            SrvRecord::new(
+                "localhost.test.build.10gen.cc",
+                27017,
+                Duration::from_secs(90),
+            ),
+            SrvRecord::new(
+                "localhost.test.build.10gen.cc",
+                27019,
+                Duration::from_secs(90),
+            ),
+        ],
+        Vec::new(),
+    ));
+
+    let mut options = ClientOptions::new_srv();
+    options.hosts = DEFAULT_HOSTS.clone();
+    options.srv_resolver = Some(resolver.clone());
+    options.test_options_mut().disable_monitoring_threads = true;
+    let mut topology = Topology::new(options.clone()).unwrap();
+    topology.watch().wait_until_initialized().await;
+    let mut monitor =
+        SrvPollingMonitor::new(topology.clone_updater(), topology.watch(), options).unwrap();
+
+    let lookup = monitor.lookup_hosts().await;
+    monitor.update_hosts(lookup).await;
+
+    assert_eq!(
+        resolver.srv_lookups(),
+        vec!["_mongodb._tcp.localhost.test.test.build.10gen.cc".to_string()]
+    );
+    assert_eq!(
+        vec![
+            localhost_test_build_10gen(27017),
+            localhost_test_build_10gen(27019)
+        ]
+        .into_iter()
+        .collect::<HashSet<_>>(),
+        topology.server_addresses()
+    );
+    assert_eq!(monitor.rescan_interval, Duration::from_secs(90));
+}
@@ -25,17 +25,28 @@ use crate::{
         PoolGeneration,
     },
     error::{load_balanced_mode_mismatch, Error, Result},
-    event::sdam::{
-        handle_sdam_event,
-        SdamEvent,
-        ServerClosedEvent,
-        ServerDescriptionChangedEvent,
-        ServerOpeningEvent,
-        TopologyClosedEvent,
-        TopologyDescriptionChangedEvent,
-        TopologyOpeningEvent,
+    event::{
+        sdam::{
+            handle_sdam_event,
+            SdamEvent,
+            SdamEventHandler,
+            ServerClosedEvent,
+            ServerDescriptionChangedEvent,
+            ServerOpeningEvent,
+            TopologyClosedEvent,
+            TopologyDescriptionChangedEvent,
+            TopologyOpeningEvent,
+        },
+        EventStreamSenders,
+    },
+    runtime::{
+        self,
+        AcknowledgedMessage,
+        AsyncJoinHandle,
+        HttpClient,
+        WorkerHandle,
+        WorkerHandleListener,
     },
-    runtime::{self, AcknowledgedMessage, HttpClient, WorkerHandle, WorkerHandleListener},
     selection_criteria::SelectionCriteria,
     ClusterTime,
     ServerInfo,
@@ -69,21 +80,10 @@ impl Topology {
     pub(crate) fn new(options: ClientOptions) -> Result<Topology> {
         let description = TopologyDescription::default();
 
-        let event_emitter = options.sdam_event_handler.as_ref().map(|handler| {
-            let (tx, mut rx) = mpsc::unbounded_channel::<AcknowledgedMessage<SdamEvent>>();
-
-            // Spin up a task to handle events so that a user's event handling code can't block the
-            // TopologyWorker.
-            let handler = handler.clone();
-            runtime::execute(async move {
-                while let Some(event) = rx.recv().await {
-                    let (event, ack) = event.into_parts();
-                    handle_sdam_event(handler.as_ref(), event);
-                    ack.acknowledge(());
-                }
-            });
-            SdamEventEmitter { sender: tx }
-        });
+        let event_emitter = SdamEventEmitter::new(
+            options.sdam_event_handler.clone(),
+            options.event_streams.sdam.clone(),
+        );
 
         let (updater, update_receiver) = TopologyUpdater::channel();
         let (worker_handle, handle_listener) = WorkerHandleListener::channel();
@@ -112,6 +112,7 @@ impl Topology {
             handle_listener,
             event_emitter,
             connection_establisher,
+            srv_polling_handle: None,
         };
 
         worker.start();
@@ -221,6 +222,12 @@ impl Topology {
     pub(crate) async fn sync_workers(&self) {
         self.updater.sync_workers().await;
     }
+
+    /// Close the topology regardless of any outstanding handles to it, waiting until its monitors
+    /// have stopped and its connection pools have been closed.
+    pub(crate) async fn shutdown(&self) {
+        self.updater.shutdown().await;
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -257,6 +264,7 @@ pub(crate) enum UpdateMessage {
         error: Error,
         phase: HandshakePhase,
     },
+    Shutdown,
     #[cfg(test)]
     SyncWorkers,
 }
@@ -285,12 +293,15 @@ struct TopologyWorker {
 
     connection_establisher: ConnectionEstablisher,
 
-    event_emitter: Option<SdamEventEmitter>,
+    event_emitter: SdamEventEmitter,
     options: ClientOptions,
 
     // the following fields stored here for creating new server monitors
     topology_watcher: TopologyWatcher,
     topology_updater: TopologyUpdater,
+
+    /// Handle to the SRV polling task, if one was started.
+    srv_polling_handle: Option<AsyncJoinHandle<()>>,
 }
 
 impl TopologyWorker {
@@ -321,7 +332,7 @@ impl TopologyWorker {
         }
 
         if self.monitoring_enabled() {
-            SrvPollingMonitor::start(
+            self.srv_polling_handle = SrvPollingMonitor::start(
                 self.topology_updater.clone(),
                 self.topology_watcher.clone(),
                 self.options.clone(),
@@ -335,6 +346,7 @@ impl TopologyWorker {
     fn start(mut self) {
         runtime::execute(async move {
             self.initialize().await;
+            let mut shutdown_ack = None;
 
             loop {
                 tokio::select! {
@@ -357,6 +369,10 @@ impl TopologyWorker {
                                 error,
                                 phase,
                             } => self.handle_application_error(address, error, phase).await,
+                            UpdateMessage::Shutdown => {
+                                shutdown_ack = Some(ack);
+                                break;
+                            }
                             #[cfg(test)]
                             UpdateMessage::SyncWorkers => {
                                 let rxen: FuturesUnordered<_> = self
@@ -379,6 +395,21 @@ impl TopologyWorker {
             // indicate to the topology watchers that the topology is no longer alive
             drop(self.publisher);
 
+            // any updates still queued will not be processed, so drop them to unblock their
+            // senders (e.g. the SRV polling monitor).
+            drop(self.update_receiver);
+
+            // if the topology is being shut down, close the connection pools even if operations
+            // still hold references to their servers.
+            if shutdown_ack.is_some() {
+                let mut pool_futures = self
+                    .servers
+                    .values()
+                    .map(|server| server.pool.shutdown())
+                    .collect::<FuturesUnordered<_>>();
+                while pool_futures.next().await.is_some() {}
+            }
+
             // close all the monitors.
             let mut close_futures = self
                 .servers
@@ -390,13 +421,21 @@ impl TopologyWorker {
                 .collect::<FuturesUnordered<_>>();
             while close_futures.next().await.is_some() {}
 
-            if let Some(emitter) = self.event_emitter {
-                emitter
+            if let Some(srv_polling_handle) = self.srv_polling_handle {
+                srv_polling_handle.await;
+            }
+
+            if self.event_emitter.is_active() {
+                self.event_emitter
                     .emit(SdamEvent::TopologyClosed(TopologyClosedEvent {
                         topology_id: self.id,
                     }))
                     .await;
             }
+
+            if let Some(ack) = shutdown_ack {
+                ack.acknowledge(false);
+            }
         });
     }
 
@@ -448,9 +487,7 @@ impl TopologyWorker {
             #[cfg(test)]
             let changed_servers = {
                 let mut servers = diff.changed_servers.into_iter().collect::<Vec<_>>();
-                servers.sort_by_key(|(addr, _)| match addr {
-                    ServerAddress::Tcp { host, port } => (host, port),
-                });
+                servers.sort_by_key(|(addr, _)| (addr.host().into_owned(), addr.port()));
                 servers
             };
 
@@ -479,9 +516,7 @@ impl TopologyWorker {
             #[cfg(test)]
             let removed_addresses = {
                 let mut addresses = diff.removed_addresses.into_iter().collect::<Vec<_>>();
-                addresses.sort_by_key(|addr| match addr {
-                    ServerAddress::Tcp { host, port } => (host, port),
-                });
+                addresses.sort_by_key(|addr| (addr.host().into_owned(), addr.port()));
                 addresses
             };
 
@@ -515,9 +550,7 @@ impl TopologyWorker {
             #[cfg(test)]
             let added_addresses = {
                 let mut addresses = diff.added_addresses.into_iter().collect::<Vec<_>>();
-                addresses.sort_by_key(|addr| match addr {
-                    ServerAddress::Tcp { host, port } => (host, port),
-                });
+                addresses.sort_by_key(|addr| (addr.host().into_owned(), addr.port()));
                 addresses
             };
 
@@ -718,9 +751,9 @@ impl TopologyWorker {
     }
 
     fn emit_event(&self, make_event: impl FnOnce() -> SdamEvent) {
-        if let Some(ref emitter) = self.event_emitter {
+        if self.event_emitter.is_active() {
             #[allow(clippy::let_underscore_future)]
-            let _ = emitter.emit(make_event());
+            let _ = self.event_emitter.emit(make_event());
         }
     }
 
@@ -812,6 +845,10 @@ impl TopologyUpdater {
         self.send_message(UpdateMessage::SyncHosts(hosts)).await;
     }
 
+    pub(crate) async fn shutdown(&self) {
+        self.send_message(UpdateMessage::Shutdown).await;
+    }
+
     #[cfg(test)]
     pub(crate) async fn sync_workers(&self) {
         self.send_message(UpdateMessage::SyncWorkers).await;
@@ -992,9 +1029,45 @@ impl TopologyPublisher {
 #[derive(Clone)]
 pub(crate) struct SdamEventEmitter {
     sender: UnboundedSender<AcknowledgedMessage<SdamEvent>>,
+    has_handler: bool,
+    event_streams: Arc<EventStreamSenders<SdamEvent>>,
 }
 
 impl SdamEventEmitter {
+    fn new(
+        handler: Option<Arc<dyn SdamEventHandler>>,
+        event_streams: Arc<EventStreamSenders<SdamEvent>>,
+    ) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<AcknowledgedMessage<SdamEvent>>();
+        let has_handler = handler.is_some();
+
+        // Spin up a task to handle events so that a user's event handling code can't block the
+        // TopologyWorker.
+        let task_event_streams = event_streams.clone();
+        runtime::execute(async move {
+            while let Some(event) = rx.recv().await {
+                let (event, ack) = event.into_parts();
+                task_event_streams.send(&event);
+                if let Some(ref handler) = handler {
+                    handle_sdam_event(handler.as_ref(), event);
+                }
+                ack.acknowledge(());
+            }
+        });
+
+        Self {
+            sender: tx,
+            has_handler,
+            event_streams,
+        }
+    }
+
+    /// Whether anything is listening for events. Used to avoid generating events that nobody will
+    /// receive.
+    pub(crate) fn is_active(&self) -> bool {
+        self.has_handler || self.event_streams.is_active()
+    }
+
     /// Emit an SDAM event.
     ///
     /// This method returns a future that can be awaited until the event has been actually emitted.
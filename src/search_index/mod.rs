@@ -0,0 +1,24 @@
+pub mod options;
+
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+use crate::bson::Document;
+
+/// Specifies the name and definition of an Atlas Search index. For more information, see the [documentation](https://www.mongodb.com/docs/atlas/atlas-search/create-index/).
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder, Serialize)]
+#[builder(field_defaults(setter(into)))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SearchIndexModel {
+    /// The definition of the index. When returned from
+    /// [`Collection::list_search_indexes`](crate::Collection::list_search_indexes), this is the
+    /// latest definition of the index.
+    #[serde(alias = "latestDefinition")]
+    pub definition: Document,
+
+    /// The name of the index. If not specified, the server will name the index "default".
+    #[builder(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
@@ -0,0 +1,82 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use typed_builder::TypedBuilder;
+
+use crate::bson_util;
+
+/// Specifies the options to a
+/// [`Collection::create_search_index`](crate::Collection::create_search_index) or
+/// [`Collection::create_search_indexes`](crate::Collection::create_search_indexes) operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CreateSearchIndexOptions {
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
+/// Specifies the options to a
+/// [`Collection::update_search_index`](crate::Collection::update_search_index) operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct UpdateSearchIndexOptions {
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
+/// Specifies the options to a
+/// [`Collection::drop_search_index`](crate::Collection::drop_search_index) operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DropSearchIndexOptions {
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
+
+/// Specifies the options to a
+/// [`Collection::list_search_indexes`](crate::Collection::list_search_indexes) operation.
+#[derive(Clone, Debug, Default, Deserialize, TypedBuilder)]
+#[builder(field_defaults(default, setter(into)))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ListSearchIndexOptions {
+    /// The number of indexes the server should return per cursor batch.
+    pub batch_size: Option<u32>,
+
+    /// The client-side timeout for this operation. If none is specified, the timeout defined on
+    /// the object executing this operation will be used. See
+    /// [`ClientOptions::timeout`](crate::options::ClientOptions::timeout) for more information.
+    #[serde(
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis",
+        default
+    )]
+    pub timeout: Option<Duration>,
+}
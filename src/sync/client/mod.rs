@@ -1,21 +1,35 @@
 pub mod session;
 
+use std::{collections::HashMap, time::Duration};
+
+use futures_util::stream::StreamExt;
+
 use super::{ChangeStream, ClientSession, Database, SessionChangeStream};
 use crate::{
     bson::Document,
     change_stream::{event::ChangeStreamEvent, options::ChangeStreamOptions},
     concern::{ReadConcern, WriteConcern},
     error::Result,
+    event::{
+        cmap::CmapEvent,
+        command::CommandEvent,
+        sdam::{SdamEvent, TopologyDescription},
+        EventStream as AsyncEventStream,
+        EventStreamOptions,
+    },
     options::{
         ClientOptions,
         DatabaseOptions,
         ListDatabasesOptions,
         SelectionCriteria,
+        ServerAddress,
         SessionOptions,
     },
     results::DatabaseSpecification,
     runtime,
     Client as AsyncClient,
+    ConnectionPoolStatus,
+    TopologySubscriber as AsyncTopologySubscriber,
 };
 
 /// This is the main entry point for the synchronous API. A `Client` is used to connect to a MongoDB
@@ -100,6 +114,11 @@ impl Client {
         self.async_client.write_concern()
     }
 
+    /// Gets the default client-side timeout the `Client` uses for operations.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.async_client.timeout()
+    }
+
     /// Gets a handle to a database specified by `name` in the cluster the `Client` is connected to.
     /// The `Database` options (e.g. read preference and write concern) will default to those of the
     /// `Client`.
@@ -198,4 +217,125 @@ impl Client {
         ))
         .map(SessionChangeStream::new)
     }
+
+    /// Eagerly establishes `min_pool_size` connections to every selectable server in the
+    /// topology, returning once they are all ready for use. See
+    /// [`crate::Client::warm_connection_pool`] for more details.
+    pub fn warm_connection_pool(&self) -> Result<()> {
+        runtime::block_on(self.async_client.warm_connection_pool())
+    }
+
+    /// Returns the current connection counts of the connection pool for each server in the
+    /// topology.
+    pub fn pool_status(&self) -> HashMap<ServerAddress, ConnectionPoolStatus> {
+        self.async_client.pool_status()
+    }
+
+    /// Gets the most up-to-date description of the topology this `Client` is connected to. See
+    /// [`crate::Client::topology_description`] for more details.
+    pub fn topology_description(&self) -> TopologyDescription {
+        self.async_client.topology_description()
+    }
+
+    /// Returns a [`TopologySubscriber`] that can be used to wait for changes to the topology this
+    /// `Client` is connected to.
+    pub fn subscribe_to_topology(&self) -> TopologySubscriber {
+        TopologySubscriber {
+            async_subscriber: self.async_client.subscribe_to_topology(),
+        }
+    }
+
+    /// Returns an iterator over the command events emitted by this `Client` from now on. See
+    /// [`crate::Client::command_events`] for more details.
+    pub fn command_events(
+        &self,
+        options: impl Into<Option<EventStreamOptions>>,
+    ) -> EventStream<CommandEvent> {
+        EventStream {
+            async_stream: self.async_client.command_events(options),
+        }
+    }
+
+    /// Returns an iterator over the connection monitoring and pooling events emitted by this
+    /// `Client` from now on. See [`crate::Client::cmap_events`] for more details.
+    pub fn cmap_events(
+        &self,
+        options: impl Into<Option<EventStreamOptions>>,
+    ) -> EventStream<CmapEvent> {
+        EventStream {
+            async_stream: self.async_client.cmap_events(options),
+        }
+    }
+
+    /// Returns an iterator over the server discovery and monitoring events emitted by this
+    /// `Client` from now on. See [`crate::Client::sdam_events`] for more details.
+    pub fn sdam_events(
+        &self,
+        options: impl Into<Option<EventStreamOptions>>,
+    ) -> EventStream<SdamEvent> {
+        EventStream {
+            async_stream: self.async_client.sdam_events(options),
+        }
+    }
+
+    /// Shuts down this `Client`, ending its pooled server sessions, stopping its background
+    /// monitoring tasks, and closing its connection pools.
+    ///
+    /// Before shutting down, this waits for any `Cursor`s, `SessionCursor`s, and `ClientSession`s
+    /// created from this `Client` to be dropped. See
+    /// [`crate::Client::shutdown`] for more details.
+    pub fn shutdown(self) {
+        runtime::block_on(self.async_client.shutdown())
+    }
+
+    /// Shuts down this `Client` without waiting for outstanding cursors and sessions to be
+    /// dropped. See [`crate::Client::shutdown_immediate`] for more details.
+    pub fn shutdown_immediate(self) {
+        runtime::block_on(self.async_client.shutdown_immediate())
+    }
+}
+
+/// A handle used to observe the topology that a [`Client`] is connected to as it changes over
+/// time. This is a wrapper around the asynchronous
+/// [`mongodb::TopologySubscriber`](../struct.TopologySubscriber.html).
+#[derive(Debug)]
+pub struct TopologySubscriber {
+    async_subscriber: AsyncTopologySubscriber,
+}
+
+impl TopologySubscriber {
+    /// Gets the most up-to-date description of the topology.
+    pub fn description(&self) -> TopologyDescription {
+        self.async_subscriber.description()
+    }
+
+    /// Blocks until the topology changes from the description last returned by this method and
+    /// returns the new description. See [`crate::TopologySubscriber::changed`] for more details.
+    pub fn changed(&mut self) -> Option<TopologyDescription> {
+        runtime::block_on(self.async_subscriber.changed())
+    }
+}
+
+/// A bounded iterator over events emitted by a [`Client`]. This is a wrapper around the
+/// asynchronous [`mongodb::event::EventStream`](../event/struct.EventStream.html); each call to
+/// `next` blocks until an event is available, and iteration ends once the `Client` has been
+/// dropped or shut down.
+#[derive(Debug)]
+pub struct EventStream<T> {
+    async_stream: AsyncEventStream<T>,
+}
+
+impl<T> EventStream<T> {
+    /// The number of events that were discarded because the stream's buffer was full.
+    pub fn dropped_events(&self) -> u64 {
+        self.async_stream.dropped_events()
+    }
+}
+
+impl<T> Iterator for EventStream<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        runtime::block_on(self.async_stream.next())
+    }
 }
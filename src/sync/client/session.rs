@@ -1,7 +1,9 @@
+use std::time::Instant;
+
 use super::Client;
 use crate::{
     bson::Document,
-    client::session::ClusterTime,
+    client::session::{with_transaction_commit_retry, ClusterTime, WithTransactionRetry},
     error::Result,
     options::{SessionOptions, TransactionOptions},
     runtime,
@@ -135,4 +137,71 @@ impl ClientSession {
     pub fn abort_transaction(&mut self) -> Result<()> {
         runtime::block_on(self.async_client_session.abort_transaction())
     }
+
+    /// Starts a transaction, runs the given callback, and commits or aborts the transaction.
+    /// Transient transaction errors will cause the callback or the commit to be retried;
+    /// other errors will cause the transaction to be aborted and the error returned to the
+    /// caller.
+    ///
+    /// Retries are attempted for up to 120 seconds after the first attempt was started. If the
+    /// callback commits or aborts the transaction itself, the result of the callback is returned
+    /// without any further action.
+    ///
+    /// ```rust
+    /// # use mongodb::{bson::{doc, Document}, error::Result, sync::{Client, ClientSession}};
+    /// #
+    /// # async fn do_stuff() -> Result<()> {
+    /// # let client = Client::with_uri_str("mongodb://example.com")?;
+    /// # let coll = client.database("foo").collection::<Document>("bar");
+    /// # let mut session = client.start_session(None)?;
+    /// session.with_transaction(
+    ///     |session| coll.insert_one_with_session(doc! { "x": 1 }, None, session),
+    ///     None,
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_transaction<R, F>(
+        &mut self,
+        mut callback: F,
+        options: impl Into<Option<TransactionOptions>>,
+    ) -> Result<R>
+    where
+        F: for<'a> FnMut(&'a mut ClientSession) -> Result<R>,
+    {
+        let options = options.into();
+        let start = Instant::now();
+
+        // The callback blocks on the async runtime itself, so it can't be driven from within
+        // `runtime::block_on`; the abort and retry decisions are shared with the async
+        // implementation instead.
+        'transaction: loop {
+            self.start_transaction(options.clone())?;
+            let ret = match callback(self) {
+                Ok(v) => v,
+                Err(e) => {
+                    if runtime::block_on(
+                        self.async_client_session
+                            .with_transaction_callback_failed(&e, start),
+                    ) {
+                        continue 'transaction;
+                    }
+                    return Err(e);
+                }
+            };
+            if !self.async_client_session.with_transaction_should_commit() {
+                return Ok(ret);
+            }
+            loop {
+                match self.commit_transaction() {
+                    Ok(()) => return Ok(ret),
+                    Err(e) => match with_transaction_commit_retry(&e, start) {
+                        Some(WithTransactionRetry::Commit) => continue,
+                        Some(WithTransactionRetry::Transaction) => continue 'transaction,
+                        None => return Err(e),
+                    },
+                }
+            }
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use std::{borrow::Borrow, fmt::Debug};
+use std::{borrow::Borrow, fmt::Debug, time::Duration};
 
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -10,12 +10,16 @@ use crate::{
     index::IndexModel,
     options::{
         AggregateOptions,
+        BulkWriteOptions,
+        CollectionStatsOptions,
         CountOptions,
         CreateIndexOptions,
+        CreateSearchIndexOptions,
         DeleteOptions,
         DistinctOptions,
         DropCollectionOptions,
         DropIndexOptions,
+        DropSearchIndexOptions,
         EstimatedDocumentCountOptions,
         FindOneAndDeleteOptions,
         FindOneAndReplaceOptions,
@@ -25,14 +29,20 @@ use crate::{
         InsertManyOptions,
         InsertOneOptions,
         ListIndexesOptions,
+        ListSearchIndexOptions,
         ReadConcern,
+        RenameCollectionOptions,
         ReplaceOptions,
         SelectionCriteria,
         UpdateModifications,
         UpdateOptions,
+        UpdateSearchIndexOptions,
         WriteConcern,
+        WriteModel,
     },
     results::{
+        BulkWriteResult,
+        CollectionStats,
         CreateIndexResult,
         CreateIndexesResult,
         DeleteResult,
@@ -43,6 +53,7 @@ use crate::{
     runtime,
     Collection as AsyncCollection,
     Namespace,
+    SearchIndexModel,
 };
 
 /// `Collection` is the client-side abstraction of a MongoDB Collection. It can be used to
@@ -126,6 +137,11 @@ impl<T> Collection<T> {
         self.async_collection.write_concern()
     }
 
+    /// Gets the client-side operation timeout of the `Collection`.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.async_collection.timeout()
+    }
+
     /// Drops the collection, deleting all data, users, and indexes stored in it.
     pub fn drop(&self, options: impl Into<Option<DropCollectionOptions>>) -> Result<()> {
         runtime::block_on(self.async_collection.drop(options.into()))
@@ -144,6 +160,49 @@ impl<T> Collection<T> {
         )
     }
 
+    /// Gets statistics for the collection using the `$collStats` aggregation stage. When run
+    /// against a sharded cluster, the statistics are totaled across all shards.
+    pub fn stats(
+        &self,
+        options: impl Into<Option<CollectionStatsOptions>>,
+    ) -> Result<CollectionStats> {
+        runtime::block_on(self.async_collection.stats(options.into()))
+    }
+
+    /// Renames the collection to `new_namespace`, which may be in a different database, and
+    /// returns a handle to the renamed collection with the same options as this one.
+    ///
+    /// If a collection already exists at `new_namespace`, this operation will fail unless
+    /// [`RenameCollectionOptions::drop_target`] is set.
+    pub fn rename(
+        &self,
+        new_namespace: Namespace,
+        options: impl Into<Option<RenameCollectionOptions>>,
+    ) -> Result<Collection<T>> {
+        runtime::block_on(self.async_collection.rename(new_namespace, options.into()))
+            .map(Collection::new)
+    }
+
+    /// Renames the collection to `new_namespace`, which may be in a different database, using the
+    /// provided `ClientSession`, and returns a handle to the renamed collection with the same
+    /// options as this one.
+    ///
+    /// If a collection already exists at `new_namespace`, this operation will fail unless
+    /// [`RenameCollectionOptions::drop_target`] is set.
+    pub fn rename_with_session(
+        &self,
+        new_namespace: Namespace,
+        options: impl Into<Option<RenameCollectionOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<Collection<T>> {
+        runtime::block_on(self.async_collection.rename_with_session(
+            new_namespace,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+        .map(Collection::new)
+    }
+
     /// Runs an aggregation operation.
     ///
     /// See the documentation [here](https://www.mongodb.com/docs/manual/aggregation/) for more
@@ -459,6 +518,58 @@ impl<T> Collection<T> {
         )
     }
 
+    /// Creates the given Atlas Search index on this collection, returning the name of the created
+    /// index.
+    pub fn create_search_index(
+        &self,
+        model: SearchIndexModel,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<String> {
+        runtime::block_on(self.async_collection.create_search_index(model, options))
+    }
+
+    /// Creates the given Atlas Search indexes on this collection, returning the names of the
+    /// created indexes.
+    pub fn create_search_indexes(
+        &self,
+        models: impl IntoIterator<Item = SearchIndexModel>,
+        options: impl Into<Option<CreateSearchIndexOptions>>,
+    ) -> Result<Vec<String>> {
+        runtime::block_on(self.async_collection.create_search_indexes(models, options))
+    }
+
+    /// Updates the definition of the Atlas Search index with the given name on this collection.
+    pub fn update_search_index(
+        &self,
+        name: impl AsRef<str>,
+        definition: Document,
+        options: impl Into<Option<UpdateSearchIndexOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(
+            self.async_collection
+                .update_search_index(name, definition, options),
+        )
+    }
+
+    /// Drops the Atlas Search index with the given name from this collection.
+    pub fn drop_search_index(
+        &self,
+        name: impl AsRef<str>,
+        options: impl Into<Option<DropSearchIndexOptions>>,
+    ) -> Result<()> {
+        runtime::block_on(self.async_collection.drop_search_index(name, options))
+    }
+
+    /// Lists the Atlas Search indexes on this collection. If `name` is specified, only the index
+    /// with that name is returned.
+    pub fn list_search_indexes(
+        &self,
+        name: impl Into<Option<&str>>,
+        options: impl Into<Option<ListSearchIndexOptions>>,
+    ) -> Result<Cursor<SearchIndexModel>> {
+        runtime::block_on(self.async_collection.list_search_indexes(name, options)).map(Cursor::new)
+    }
+
     /// Updates all documents matching `query` in the collection using the provided `ClientSession`.
     ///
     /// Both `Document` and `Vec<Document>` implement `Into<UpdateModifications>`, so either can be
@@ -885,4 +996,33 @@ where
             &mut session.async_client_session,
         ))
     }
+
+    /// Executes the given write models against the collection, grouping consecutive models of the
+    /// same kind into as few `insert`, `update` and `delete` commands as possible.
+    ///
+    /// See [`crate::Collection::bulk_write`] for more details.
+    pub fn bulk_write(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+    ) -> Result<BulkWriteResult> {
+        runtime::block_on(self.async_collection.bulk_write(models, options.into()))
+    }
+
+    /// Executes the given write models against the collection using the provided
+    /// `ClientSession`.
+    ///
+    /// See [`crate::Collection::bulk_write`] for more details.
+    pub fn bulk_write_with_session(
+        &self,
+        models: impl IntoIterator<Item = WriteModel<T>>,
+        options: impl Into<Option<BulkWriteOptions>>,
+        session: &mut ClientSession,
+    ) -> Result<BulkWriteResult> {
+        runtime::block_on(self.async_collection.bulk_write_with_session(
+            models,
+            options.into(),
+            &mut session.async_client_session,
+        ))
+    }
 }
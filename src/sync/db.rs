@@ -1,4 +1,4 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 use super::{
     gridfs::GridFsBucket,
@@ -17,6 +17,7 @@ use crate::{
         AggregateOptions,
         CollectionOptions,
         CreateCollectionOptions,
+        DatabaseStatsOptions,
         DropDatabaseOptions,
         GridFsBucketOptions,
         ListCollectionsOptions,
@@ -24,7 +25,7 @@ use crate::{
         SelectionCriteria,
         WriteConcern,
     },
-    results::CollectionSpecification,
+    results::{CollectionSpecification, DatabaseStats},
     runtime,
     Database as AsyncDatabase,
 };
@@ -91,6 +92,11 @@ impl Database {
         self.async_database.write_concern()
     }
 
+    /// Gets the client-side operation timeout of the `Database`.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.async_database.timeout()
+    }
+
     /// Gets a handle to a collection with type `T` specified by `name` of the database. The
     /// `Collection` options (e.g. read preference and write concern) will default to those of the
     /// `Database`.
@@ -219,6 +225,12 @@ impl Database {
         ))
     }
 
+    /// Gets storage statistics for the database. When run against a sharded cluster, the
+    /// statistics are totaled across all shards.
+    pub fn stats(&self, options: impl Into<Option<DatabaseStatsOptions>>) -> Result<DatabaseStats> {
+        runtime::block_on(self.async_database.stats(options.into()))
+    }
+
     /// Runs a database-level command.
     ///
     /// Note that no inspection is done on `doc`, so the command will not use the database's default
@@ -1,8 +1,11 @@
 //! Contains the functionality for GridFS operations.
 
-use std::io::{Read, Write};
+use std::{
+    io::{Read, Seek, SeekFrom, Write},
+    ops::Range,
+};
 
-use futures_util::{AsyncReadExt, AsyncWriteExt};
+use futures_util::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
 use super::Cursor;
 use crate::{
@@ -90,7 +93,7 @@ impl GridFsBucket {
 /// A stream from which a file stored in a GridFS bucket can be downloaded.
 ///
 /// # Downloading from the Stream
-/// The `GridFsDownloadStream` type implements [`std::io::Read`].
+/// The `GridFsDownloadStream` type implements [`std::io::Read`] and [`std::io::Seek`].
 ///
 /// ```rust
 /// # use mongodb::{bson::Bson, error::Result, sync::gridfs::{GridFsBucket, GridFsDownloadStream}};
@@ -113,6 +116,12 @@ impl Read for GridFsDownloadStream {
     }
 }
 
+impl Seek for GridFsDownloadStream {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        runtime::block_on(self.async_stream.seek(pos))
+    }
+}
+
 impl GridFsDownloadStream {
     fn new(async_stream: AsyncGridFsDownloadStream) -> Self {
         Self { async_stream }
@@ -127,6 +136,20 @@ impl GridFsBucket {
         runtime::block_on(self.async_bucket.open_download_stream(id)).map(GridFsDownloadStream::new)
     }
 
+    /// Opens and returns a [`GridFsDownloadStream`] from which the application can read the bytes
+    /// within `range` of the stored file specified by `id`.
+    ///
+    /// This method returns an error if the range is decreasing or extends past the end of the
+    /// file.
+    pub fn open_download_stream_range(
+        &self,
+        id: Bson,
+        range: Range<u64>,
+    ) -> Result<GridFsDownloadStream> {
+        runtime::block_on(self.async_bucket.open_download_stream_range(id, range))
+            .map(GridFsDownloadStream::new)
+    }
+
     /// Opens and returns a [`GridFsDownloadStream`] from which the application can read
     /// the contents of the stored file specified by `filename`.
     ///
@@ -11,7 +11,7 @@ pub mod gridfs;
 mod test;
 
 pub use change_stream::{ChangeStream, SessionChangeStream};
-pub use client::{session::ClientSession, Client};
+pub use client::{session::ClientSession, Client, EventStream, TopologySubscriber};
 pub use coll::Collection;
 pub use cursor::{Cursor, SessionCursor, SessionCursorIter};
 pub use db::Database;
@@ -312,6 +312,18 @@ fn transactions() {
     session
         .abort_transaction()
         .expect("abort transaction should succeed");
+
+    session
+        .with_transaction(
+            |s| coll.insert_one_with_session(doc! { "x": 2 }, None, s),
+            None,
+        )
+        .expect("with_transaction should succeed");
+    assert_eq!(
+        coll.count_documents(doc! { "x": 2 }, None)
+            .expect("count should succeed"),
+        1
+    );
 }
 
 #[test]
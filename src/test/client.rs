@@ -1,20 +1,48 @@
-use std::{borrow::Cow, collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
-use bson::Document;
+use bson::{spec::BinarySubtype, Binary, Document};
+use futures::{
+    future::{BoxFuture, FutureExt},
+    stream::StreamExt,
+};
 use serde::Deserialize;
 use tokio::sync::{RwLockReadGuard, RwLockWriteGuard};
 
 use crate::{
     bson::{doc, Bson},
-    error::{CommandError, Error, ErrorKind},
-    event::cmap::CmapEvent,
+    error::{self, CommandError, Error, ErrorKind},
+    event::{
+        cmap::CmapEvent,
+        command::{CommandEvent, CommandEventHandler},
+        metrics::{self, InMemoryMetricsSink},
+        sdam::TopologyDescription,
+        EventStream,
+        EventStreamOptions,
+        OverflowPolicy,
+    },
     hello::LEGACY_HELLO_COMMAND_NAME,
-    options::{AuthMechanism, ClientOptions, Credential, ListDatabasesOptions, ServerAddress},
+    options::{
+        AuthMechanism,
+        ClientOptions,
+        Credential,
+        CredentialProvider,
+        FindOptions,
+        ListDatabasesOptions,
+        ServerAddress,
+    },
     runtime,
     selection_criteria::{ReadPreference, ReadPreferenceOptions, SelectionCriteria},
     test::{
         log_uncaptured,
-        util::TestClient,
+        util::{Socks5Server, TestClient},
         Event,
         EventHandler,
         FailCommandOptions,
@@ -24,8 +52,11 @@ use crate::{
         CLIENT_OPTIONS,
         LOCK,
     },
+    test_util::{MockReply, MockServer, MockTopology},
     Client,
     ServerType,
+    TopologySubscriber,
+    TopologyType,
 };
 
 #[derive(Debug, Deserialize)]
@@ -845,3 +876,515 @@ async fn retry_commit_txn_check_out() {
         .await
         .expect("should see checked out event");
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn shutdown() {
+    let _guard: RwLockReadGuard<_> = LOCK.run_concurrently().await;
+
+    let mut options = CLIENT_OPTIONS.get().await.clone();
+    let handler = Arc::new(EventHandler::new());
+    options.cmap_event_handler = Some(handler.clone());
+    options.command_event_handler = Some(handler.clone());
+    let client = Client::with_options(options).unwrap();
+
+    let coll = client
+        .database(function_name!())
+        .collection::<Document>(function_name!());
+    coll.drop(None).await.unwrap();
+    coll.insert_many(vec![doc! { "x": 1 }, doc! { "x": 2 }], None)
+        .await
+        .unwrap();
+
+    {
+        let mut cursor = coll
+            .find(None, FindOptions::builder().batch_size(1).build())
+            .await
+            .unwrap();
+        assert!(cursor.advance().await.unwrap());
+    }
+
+    let mut subscriber = handler.subscribe();
+    client.clone().shutdown().await;
+
+    // The cursor was dropped before shutting down, so it should have been killed before the
+    // client's sessions were ended.
+    let command_names: Vec<_> = handler
+        .get_command_started_events(&["killCursors", "endSessions"])
+        .into_iter()
+        .map(|e| e.command_name)
+        .collect();
+    assert_eq!(command_names, vec!["killCursors", "endSessions"]);
+
+    subscriber
+        .wait_for_event(Duration::from_millis(500), |e| {
+            matches!(e, Event::Cmap(CmapEvent::PoolClosed(_)))
+        })
+        .await
+        .expect("should see pool closed event");
+
+    let error = coll.find_one(None, None).await.unwrap_err();
+    assert!(
+        matches!(*error.kind, ErrorKind::Shutdown),
+        "unexpected error: {:?}",
+        error
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn warm_connection_pool() {
+    let _guard: RwLockReadGuard<_> = LOCK.run_concurrently().await;
+
+    let mut options = CLIENT_OPTIONS.get().await.clone();
+    options.min_pool_size = Some(3);
+    let client = Client::with_options(options).unwrap();
+    client.warm_connection_pool().await.unwrap();
+
+    // Every server in the deployment has been discovered, not just the first one selected.
+    let description = client.topology_description().description;
+    assert!(
+        description
+            .servers
+            .values()
+            .all(|description| description.server_type != ServerType::Unknown),
+        "{:?}",
+        description
+    );
+
+    let status = client.pool_status();
+    let data_bearing: Vec<_> = description
+        .servers
+        .values()
+        .filter(|description| description.server_type.is_data_bearing())
+        .map(|description| description.address.clone())
+        .collect();
+    assert!(!data_bearing.is_empty());
+    for address in data_bearing {
+        let pool = status[&address];
+        assert_eq!(pool.pending_connections, 0, "{}: {:?}", address, pool);
+        assert!(pool.available_connections >= 3, "{}: {:?}", address, pool);
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn topology_subscription() {
+    let server = MockServer::start(MockTopology::ReplicaSetPrimary {
+        set_name: "mock".to_string(),
+    })
+    .await
+    .unwrap();
+    let client = Client::with_uri_str(format!("{}&heartbeatFrequencyMS=500", server.uri()))
+        .await
+        .unwrap();
+    let mut subscriber = client.subscribe_to_topology();
+
+    let description =
+        wait_for_topology_type(&mut subscriber, TopologyType::ReplicaSetWithPrimary).await;
+    assert_eq!(description.set_name().map(String::as_str), Some("mock"));
+
+    server.set_topology(MockTopology::ReplicaSetSecondary {
+        set_name: "mock".to_string(),
+    });
+    let description =
+        wait_for_topology_type(&mut subscriber, TopologyType::ReplicaSetNoPrimary).await;
+    assert_eq!(
+        description.servers()[server.address()].server_type(),
+        ServerType::RsSecondary
+    );
+
+    let current = client.topology_description();
+    assert_eq!(current.topology_type(), TopologyType::ReplicaSetNoPrimary);
+    assert_eq!(current.servers().len(), 1);
+}
+
+async fn wait_for_topology_type(
+    subscriber: &mut TopologySubscriber,
+    topology_type: TopologyType,
+) -> TopologyDescription {
+    runtime::timeout(Duration::from_secs(10), async {
+        let mut description = subscriber.description();
+        while description.topology_type() != topology_type {
+            description = subscriber
+                .changed()
+                .await
+                .expect("client should still be alive");
+        }
+        description
+    })
+    .await
+    .expect("topology should change")
+}
+
+async fn next_event<T>(stream: &mut EventStream<T>) -> T {
+    runtime::timeout(Duration::from_secs(10), stream.next())
+        .await
+        .expect("an event should be emitted")
+        .expect("the stream should not be closed")
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn command_event_streams() {
+    let server = MockServer::start(MockTopology::Standalone).await.unwrap();
+    let client = Client::with_uri_str(server.uri()).await.unwrap();
+    let mut events = client.command_events(None);
+    let mut lossy_events = client.command_events(
+        EventStreamOptions::builder()
+            .capacity(1)
+            .overflow_policy(OverflowPolicy::DropNewest)
+            .build(),
+    );
+
+    client
+        .database("db")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap();
+
+    match next_event(&mut events).await {
+        CommandEvent::Started(event) => assert_eq!(event.command_name, "ping"),
+        other => panic!("expected command started event, got {:?}", other),
+    }
+    match next_event(&mut events).await {
+        CommandEvent::Succeeded(event) => assert_eq!(event.command_name, "ping"),
+        other => panic!("expected command succeeded event, got {:?}", other),
+    }
+    assert_eq!(events.dropped_events(), 0);
+
+    assert!(matches!(
+        next_event(&mut lossy_events).await,
+        CommandEvent::Started(_)
+    ));
+    assert_eq!(lossy_events.dropped_events(), 1);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn command_event_stream_backpressure() {
+    let server = MockServer::start(MockTopology::Standalone).await.unwrap();
+    let client = Client::with_uri_str(server.uri()).await.unwrap();
+    let mut events = client.command_events(
+        EventStreamOptions::builder()
+            .capacity(1)
+            .overflow_policy(OverflowPolicy::Backpressure)
+            .build(),
+    );
+    let db = client.database("db");
+
+    // Events emitted by an operation are never dropped, even if that overfills the stream.
+    db.run_command(doc! { "ping": 1 }, None).await.unwrap();
+
+    // The next operation waits for the stream to be drained.
+    let blocked = runtime::timeout(
+        Duration::from_millis(500),
+        db.run_command(doc! { "ping": 1 }, None),
+    )
+    .await;
+    assert!(blocked.is_err(), "operation should wait for the stream");
+
+    assert!(matches!(
+        next_event(&mut events).await,
+        CommandEvent::Started(_)
+    ));
+    assert!(matches!(
+        next_event(&mut events).await,
+        CommandEvent::Succeeded(_)
+    ));
+    runtime::timeout(
+        Duration::from_secs(10),
+        db.run_command(doc! { "ping": 1 }, None),
+    )
+    .await
+    .expect("operation should proceed once the stream is drained")
+    .unwrap();
+    assert_eq!(events.dropped_events(), 0);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn max_time_ms_expired_from_timeout() {
+    let server = MockServer::start(MockTopology::Standalone).await.unwrap();
+    let mut options = ClientOptions::parse(server.uri()).await.unwrap();
+    options.timeout = Some(Duration::from_secs(10));
+    let client = Client::with_options(options).unwrap();
+    let db = client.database("db");
+    let max_time_ms_expired = || MockReply::command_error(50, "operation exceeded time limit");
+
+    // The server enforcing the maxTimeMS derived from the timeout is reported as a timeout.
+    server.add_reply("ping", max_time_ms_expired());
+    let error = db.run_command(doc! { "ping": 1 }, None).await.unwrap_err();
+    assert!(
+        matches!(*error.kind, ErrorKind::Timeout { .. }),
+        "{:?}",
+        error
+    );
+    assert!(error.is_max_time_ms_expired_error());
+
+    // A maxTimeMS specified by the user is reported as the server's error.
+    server.add_reply("ping", max_time_ms_expired());
+    let error = db
+        .run_command(doc! { "ping": 1, "maxTimeMS": 100 }, None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            *error.kind,
+            ErrorKind::Command(CommandError { code: 50, .. })
+        ),
+        "{:?}",
+        error
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn command_event_stream_backpressure_bounded_by_timeout() {
+    let server = MockServer::start(MockTopology::Standalone).await.unwrap();
+    let mut options = ClientOptions::parse(server.uri()).await.unwrap();
+    options.timeout = Some(Duration::from_millis(200));
+    let client = Client::with_options(options).unwrap();
+    let events = client.command_events(
+        EventStreamOptions::builder()
+            .capacity(1)
+            .overflow_policy(OverflowPolicy::Backpressure)
+            .build(),
+    );
+    let db = client.database("db");
+
+    // Fill the stream, which is never consumed.
+    db.run_command(doc! { "ping": 1 }, None).await.unwrap();
+
+    let error = runtime::timeout(
+        Duration::from_secs(10),
+        db.run_command(doc! { "ping": 1 }, None),
+    )
+    .await
+    .expect("operation should not wait for the stream beyond its timeout")
+    .unwrap_err();
+    assert!(
+        matches!(*error.kind, ErrorKind::Timeout { .. }),
+        "{:?}",
+        error
+    );
+
+    // Operations that release server-side resources don't wait for the stream at all.
+    runtime::timeout(
+        Duration::from_secs(10),
+        db.run_command(doc! { "killCursors": "coll", "cursors": [] }, None),
+    )
+    .await
+    .expect("cleanup operations should not wait for the stream")
+    .unwrap();
+    drop(events);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn multiple_command_event_handlers() {
+    let server = MockServer::start(MockTopology::Standalone).await.unwrap();
+    let first = EventHandler::new();
+    let second = EventHandler::new();
+    let handlers: Vec<Arc<dyn CommandEventHandler>> =
+        vec![Arc::new(first.clone()), Arc::new(second.clone())];
+
+    let mut options = ClientOptions::parse(server.uri()).await.unwrap();
+    options.command_event_handler = Some(Arc::new(handlers));
+    let client = Client::with_options(options).unwrap();
+    client
+        .database("db")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap();
+
+    assert_eq!(first.get_command_started_events(&["ping"]).len(), 1);
+    assert_eq!(second.get_command_started_events(&["ping"]).len(), 1);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn operation_metrics() {
+    let server = MockServer::start(MockTopology::ReplicaSetPrimary {
+        set_name: "mock".to_string(),
+    })
+    .await
+    .unwrap();
+    server.add_reply("insert", MockReply::CloseConnection);
+
+    let sink = InMemoryMetricsSink::new();
+    let mut options = ClientOptions::parse(server.uri()).await.unwrap();
+    options.metrics_sink = Some(Arc::new(sink.clone()));
+    let client = Client::with_options(options).unwrap();
+    client
+        .database("db")
+        .collection::<Document>("coll")
+        .insert_one(doc! { "x": 1 }, None)
+        .await
+        .unwrap();
+
+    assert_eq!(sink.counter_total(metrics::OPERATION_RETRIES), 1);
+    assert_eq!(sink.counter_total(metrics::CONNECTION_CHECKOUT_FAILURES), 0);
+    assert_eq!(
+        sink.histogram_values(metrics::CONNECTION_WAIT_TIME).len(),
+        2
+    );
+
+    let durations: Vec<_> = sink
+        .recorded()
+        .into_iter()
+        .filter(|metric| metric.name == metrics::OPERATION_DURATION)
+        .collect();
+    assert_eq!(durations.len(), 1);
+    assert_eq!(durations[0].attribute("db.operation"), Some("insert"));
+    assert_eq!(durations[0].attribute("outcome"), Some("success"));
+
+    let wait_time = sink
+        .recorded()
+        .into_iter()
+        .find(|metric| metric.name == metrics::CONNECTION_WAIT_TIME)
+        .unwrap();
+    assert_eq!(wait_time.attribute("server.address"), Some("127.0.0.1"));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn socks5_proxy() {
+    let server = MockServer::start(MockTopology::Standalone).await.unwrap();
+    let proxy = Socks5Server::start(Some(("user", "pencil"))).await.unwrap();
+
+    let mut options = ClientOptions::parse(server.uri()).await.unwrap();
+    options.proxy_host = Some(proxy.address().host().to_string());
+    options.proxy_port = proxy.address().port();
+    options.proxy_username = Some("user".to_string());
+    options.proxy_password = Some("pencil".to_string());
+    let client = Client::with_options(options.clone()).unwrap();
+    client
+        .database("admin")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap();
+
+    // Both the monitoring connection and the pooled connection should have been tunnelled.
+    let targets = proxy.targets();
+    assert!(targets.len() >= 2, "{:?}", targets);
+    assert!(targets
+        .iter()
+        .all(|target| *target == server.address().to_string()));
+
+    options.proxy_password = Some("wrong".to_string());
+    options.server_selection_timeout = Some(Duration::from_millis(500));
+    let client = Client::with_options(options).unwrap();
+    let error = client
+        .database("admin")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(*error.kind, ErrorKind::ServerSelection { .. }),
+        "{:?}",
+        error
+    );
+}
+
+/// A credential provider that returns a new password each time it is called.
+struct RotatingCredentialProvider {
+    calls: AtomicUsize,
+}
+
+impl CredentialProvider for RotatingCredentialProvider {
+    fn credential(&self) -> BoxFuture<'_, error::Result<Credential>> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+        let credential = Credential::builder()
+            .username("user".to_string())
+            .password(format!("password{}", call))
+            .mechanism(AuthMechanism::Plain)
+            .build();
+        async move { Ok(credential) }.boxed()
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn credential_provider_consulted_per_connection() {
+    let server = MockServer::start(MockTopology::Standalone).await.unwrap();
+    server.set_default_reply(
+        "saslStart",
+        doc! {
+            "ok": 1,
+            "conversationId": 1,
+            "done": true,
+            "payload": Binary { subtype: BinarySubtype::Generic, bytes: Vec::new() },
+        },
+    );
+
+    let mut options = ClientOptions::parse(server.uri()).await.unwrap();
+    options.credential_provider = Some(Arc::new(RotatingCredentialProvider {
+        calls: AtomicUsize::new(0),
+    }));
+    options.min_pool_size = Some(2);
+    let client = Client::with_options(options).unwrap();
+    client
+        .database("admin")
+        .run_command(doc! { "ping": 1 }, None)
+        .await
+        .unwrap();
+
+    // Each connection should have authenticated with the credential current at the time it was
+    // established.
+    let mut passwords = Vec::new();
+    for _ in 0..50 {
+        passwords = server
+            .received_commands()
+            .into_iter()
+            .filter(|command| command.contains_key("saslStart"))
+            .map(|command| {
+                let payload = command.get_binary_generic("payload").unwrap();
+                String::from_utf8_lossy(payload)
+                    .rsplit('\0')
+                    .next()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        if passwords.len() >= 2 {
+            break;
+        }
+        runtime::delay_for(Duration::from_millis(100)).await;
+    }
+    assert!(passwords.len() >= 2, "{:?}", passwords);
+    passwords.sort();
+    assert_eq!(&passwords[..2], ["password1", "password2"]);
+}
+
+/// A credential provider that never returns.
+struct StalledCredentialProvider;
+
+impl CredentialProvider for StalledCredentialProvider {
+    fn credential(&self) -> BoxFuture<'_, error::Result<Credential>> {
+        futures::future::pending().boxed()
+    }
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn stalled_credential_provider_bounded_by_connect_timeout() {
+    let server = MockServer::start(MockTopology::Standalone).await.unwrap();
+
+    let mut options = ClientOptions::parse(server.uri()).await.unwrap();
+    options.credential_provider = Some(Arc::new(StalledCredentialProvider));
+    options.connect_timeout = Some(Duration::from_millis(100));
+    options.server_selection_timeout = Some(Duration::from_millis(500));
+    let client = Client::with_options(options).unwrap();
+
+    let result = runtime::timeout(
+        Duration::from_secs(10),
+        client
+            .database("admin")
+            .run_command(doc! { "ping": 1 }, None),
+    )
+    .await
+    .expect("establishing a connection should be bounded by the connect timeout");
+    assert!(result.is_err());
+}
@@ -12,31 +12,48 @@ use crate::{
     options::{
         Acknowledgment,
         AggregateOptions,
+        BulkWriteOptions,
+        ClientOptions,
         CollectionOptions,
+        CollectionStatsOptions,
+        DatabaseOptions,
+        DeleteManyModel,
+        DeleteOneModel,
         DeleteOptions,
         DropCollectionOptions,
+        ExplainVerbosity,
         FindOneAndDeleteOptions,
         FindOneOptions,
         FindOptions,
         Hint,
         IndexOptions,
         InsertManyOptions,
+        InsertOneModel,
         ReadConcern,
         ReadPreference,
+        RenameCollectionOptions,
         SelectionCriteria,
+        UpdateManyModel,
+        UpdateOneModel,
         UpdateOptions,
         WriteConcern,
+        WriteModel,
     },
-    results::DeleteResult,
+    results::{CollectionStats, DeleteResult},
     runtime,
     test::{
         log_uncaptured,
         util::{drop_collection, EventClient, TestClient},
+        FailCommandOptions,
+        FailPoint,
+        FailPointMode,
         CLIENT_OPTIONS,
         LOCK,
     },
+    Client,
     Collection,
     IndexModel,
+    Namespace,
 };
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -218,6 +235,93 @@ async fn delete() {
     assert_eq!(coll.count_documents(doc! {"x": 3 }, None).await.unwrap(), 0);
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn bulk_write() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+
+    let models: Vec<WriteModel<Document>> = vec![
+        InsertOneModel::builder()
+            .document(doc! { "_id": 1, "x": 1 })
+            .build()
+            .into(),
+        InsertOneModel::builder()
+            .document(doc! { "_id": 2, "x": 1 })
+            .build()
+            .into(),
+        UpdateManyModel::builder()
+            .filter(doc! { "x": 1 })
+            .update(doc! { "$set": { "x": 2 } })
+            .build()
+            .into(),
+        UpdateOneModel::builder()
+            .filter(doc! { "_id": 3 })
+            .update(doc! { "$set": { "x": 3 } })
+            .upsert(true)
+            .build()
+            .into(),
+        DeleteOneModel::builder()
+            .filter(doc! { "_id": 1 })
+            .build()
+            .into(),
+    ];
+    let result = coll.bulk_write(models, None).await.unwrap();
+
+    assert_eq!(result.inserted_count, 2);
+    assert_eq!(result.matched_count, 2);
+    assert_eq!(result.modified_count, 2);
+    assert_eq!(result.upserted_count, 1);
+    assert_eq!(result.upserted_ids.get(&3), Some(&Bson::Int32(3)));
+    assert_eq!(result.deleted_count, 1);
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 2);
+
+    let models: Vec<WriteModel<Document>> = vec![
+        InsertOneModel::builder()
+            .document(doc! { "_id": 2 })
+            .build()
+            .into(),
+        InsertOneModel::builder()
+            .document(doc! { "_id": 4 })
+            .build()
+            .into(),
+        DeleteManyModel::builder().filter(doc! {}).build().into(),
+    ];
+    let options = BulkWriteOptions::builder().ordered(false).build();
+    let error = coll.bulk_write(models, options).await.unwrap_err();
+    match *error.kind {
+        ErrorKind::BulkWrite(failure) => {
+            let write_errors = failure.write_errors.unwrap();
+            assert_eq!(write_errors.len(), 1);
+            assert_eq!(write_errors[0].index, 0);
+            assert_eq!(write_errors[0].code, 11000);
+
+            let partial_result = failure.partial_result.unwrap();
+            assert_eq!(partial_result.inserted_count, 1);
+            assert_eq!(partial_result.inserted_ids.get(&1), Some(&Bson::Int32(4)));
+            assert_eq!(partial_result.deleted_count, 3);
+        }
+        other => panic!("expected bulk write error, got {:?}", other),
+    }
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 0);
+
+    let models: Vec<WriteModel<Document>> = vec![InsertOneModel::builder()
+        .document(doc! { "_id": 5 })
+        .build()
+        .into()];
+    let options = BulkWriteOptions::builder()
+        .let_vars(doc! { "x": 1 })
+        .build();
+    let error = coll.bulk_write(models, options).await.unwrap_err();
+    assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 0);
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[function_name::named]
@@ -1201,3 +1305,293 @@ fn assert_duplicate_key_error_with_utf8_replacement(error: &ErrorKind) {
         ),
     }
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn timeout_inherited() {
+    let options = ClientOptions::builder()
+        .hosts(CLIENT_OPTIONS.get().await.hosts.clone())
+        .timeout(Duration::from_secs(5))
+        .build();
+    let client = Client::with_options(options).unwrap();
+
+    let db = client.database("timeout_inherited");
+    assert_eq!(db.timeout(), Some(Duration::from_secs(5)));
+    assert_eq!(
+        db.collection::<Document>("coll").timeout(),
+        Some(Duration::from_secs(5))
+    );
+
+    let db = client.database_with_options(
+        "timeout_inherited",
+        DatabaseOptions::builder()
+            .timeout(Duration::from_secs(2))
+            .build(),
+    );
+    assert_eq!(db.timeout(), Some(Duration::from_secs(2)));
+    let coll = db.collection_with_options::<Document>(
+        "coll",
+        CollectionOptions::builder()
+            .timeout(Duration::from_secs(1))
+            .build(),
+    );
+    assert_eq!(coll.timeout(), Some(Duration::from_secs(1)));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn operation_timeout() {
+    let _guard: RwLockWriteGuard<()> = LOCK.run_exclusively().await;
+
+    let client = EventClient::new().await;
+    if !client.supports_block_connection() {
+        log_uncaptured("skipping operation_timeout due to blockConnection not being supported");
+        return;
+    }
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_one(doc! { "x": 1 }, None).await.unwrap();
+
+    // The remaining time budget is sent to the server as maxTimeMS.
+    let options = FindOneOptions::builder()
+        .timeout(Duration::from_secs(10))
+        .build();
+    coll.find_one(doc! {}, options).await.unwrap();
+    let events = client.get_command_started_events(&["find"]);
+    let max_time_ms = events[0].command.get_i64("maxTimeMS").unwrap();
+    assert!(max_time_ms > 0 && max_time_ms <= 10_000);
+
+    let fp_options = FailCommandOptions::builder()
+        .block_connection(Duration::from_millis(500))
+        .build();
+    let failpoint = FailPoint::fail_command(&["find"], FailPointMode::Times(1), Some(fp_options));
+    let _fp_guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let options = FindOneOptions::builder()
+        .timeout(Duration::from_millis(100))
+        .build();
+    let error = coll
+        .find_one(doc! {}, options)
+        .await
+        .expect_err("find should time out");
+    assert!(
+        matches!(*error.kind, ErrorKind::Timeout { .. }),
+        "expected timeout error, got {:?}",
+        error
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn cursor_timeout_covers_all_batches() {
+    let _guard: RwLockWriteGuard<()> = LOCK.run_exclusively().await;
+
+    let client = EventClient::new().await;
+    if !client.supports_block_connection() {
+        log_uncaptured(
+            "skipping cursor_timeout_covers_all_batches due to blockConnection not being supported",
+        );
+        return;
+    }
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many((0..3).map(|i| doc! { "x": i }), None)
+        .await
+        .unwrap();
+
+    // Each getMore fits within the timeout on its own, but together they exceed it.
+    let fp_options = FailCommandOptions::builder()
+        .block_connection(Duration::from_millis(300))
+        .build();
+    let failpoint =
+        FailPoint::fail_command(&["getMore"], FailPointMode::Times(2), Some(fp_options));
+    let _fp_guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let options = FindOptions::builder()
+        .batch_size(1)
+        .timeout(Duration::from_millis(500))
+        .build();
+    let mut cursor = coll.find(None, options).await.unwrap();
+    assert!(cursor.try_next().await.unwrap().is_some());
+    assert!(cursor.try_next().await.unwrap().is_some());
+    let error = cursor
+        .try_next()
+        .await
+        .expect_err("second getMore should time out");
+    assert!(
+        matches!(*error.kind, ErrorKind::Timeout { .. }),
+        "expected timeout error, got {:?}",
+        error
+    );
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn explain() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many((0..10).map(|i| doc! { "x": i }), None)
+        .await
+        .unwrap();
+    coll.create_index(IndexModel::builder().keys(doc! { "x": 1 }).build(), None)
+        .await
+        .unwrap();
+
+    let result = coll
+        .explain(ExplainVerbosity::ExecutionStats)
+        .find(doc! { "x": { "$gt": 7 } }, None)
+        .await
+        .unwrap();
+    assert!(result.query_planner.is_some());
+    assert_eq!(result.indexes_used(), vec!["x_1".to_string()]);
+    if result.shards.is_empty() {
+        let stats = result.execution_stats.unwrap();
+        assert_eq!(stats.n_returned, Some(2));
+    }
+
+    let result = coll
+        .explain(ExplainVerbosity::QueryPlanner)
+        .delete_many(doc! { "x": { "$lt": 5 } }, None)
+        .await
+        .unwrap();
+    assert!(result.execution_stats.is_none());
+    assert_eq!(result.indexes_used(), vec!["x_1".to_string()]);
+
+    // Explained writes do not modify any data.
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 10);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn rename() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    if client.is_sharded() {
+        log_uncaptured("skipping rename due to test configuration");
+        return;
+    }
+
+    let source = client.init_db_and_coll(function_name!(), "source").await;
+    source.insert_one(doc! { "x": 1 }, None).await.unwrap();
+    let target = client.init_db_and_coll(function_name!(), "target").await;
+    target.insert_one(doc! { "x": 2 }, None).await.unwrap();
+
+    let target_ns = target.namespace();
+    let error = source
+        .rename(target_ns.clone(), None)
+        .await
+        .expect_err("renaming to an existing collection should fail");
+    assert!(matches!(*error.kind, ErrorKind::Command(_)));
+
+    let options = RenameCollectionOptions::builder().drop_target(true).build();
+    let renamed = source.rename(target_ns.clone(), options).await.unwrap();
+    assert_eq!(renamed.namespace(), target_ns);
+    let docs: Vec<Document> = renamed
+        .find(None, None)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(docs.len(), 1);
+    assert_eq!(docs[0].get_i32("x"), Ok(1));
+
+    // Collections can also be moved into a different database.
+    let other_db = format!("{}_other", function_name!());
+    client.drop_collection(&other_db, "moved").await;
+    let moved_ns = Namespace {
+        db: other_db.clone(),
+        coll: "moved".to_string(),
+    };
+    let moved = renamed.rename(moved_ns.clone(), None).await.unwrap();
+    assert_eq!(moved.namespace(), moved_ns);
+    assert_eq!(moved.count_documents(None, None).await.unwrap(), 1);
+    client.drop_collection(&other_db, "moved").await;
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn stats() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many((0..5).map(|i| doc! { "x": i }), None)
+        .await
+        .unwrap();
+    coll.create_index(IndexModel::builder().keys(doc! { "x": 1 }).build(), None)
+        .await
+        .unwrap();
+
+    let options = CollectionStatsOptions::builder()
+        .latency_stats(true)
+        .count(true)
+        .build();
+    let stats = coll.stats(options).await.unwrap();
+    assert_eq!(stats.namespace, coll.namespace().to_string());
+    assert_eq!(stats.count, Some(5));
+    let storage = stats.storage_stats.unwrap();
+    assert_eq!(storage.count, 5);
+    assert!(storage.size > 0);
+    assert!(storage.index_sizes.contains_key("_id_"));
+    assert!(storage.index_sizes.contains_key("x_1"));
+    assert!(stats.latency_stats.unwrap().writes.ops >= 1);
+}
+
+#[test]
+fn stats_sharded() {
+    let shard = |name: &str, count: i32, size: i32, index_size: i32| {
+        doc! {
+            "ns": "db.coll",
+            "shard": name,
+            "storageStats": {
+                "size": size,
+                "count": count,
+                "avgObjSize": size / count,
+                "storageSize": 4096,
+                "totalIndexSize": index_size,
+                "indexSizes": { "_id_": index_size },
+                "wiredTiger": { "block-manager": { "file size in bytes": 8192 } },
+            },
+        }
+    };
+
+    let stats = CollectionStats::from_documents(vec![
+        shard("shard0", 2, 100, 10),
+        shard("shard1", 3, 200, 20),
+    ])
+    .unwrap();
+    assert_eq!(stats.namespace, "db.coll");
+    assert_eq!(stats.count, Some(5));
+    assert_eq!(stats.shards.len(), 2);
+    assert_eq!(stats.shards["shard1"].count, Some(3));
+
+    let storage = stats.storage_stats.unwrap();
+    assert_eq!(storage.size, 300);
+    assert_eq!(storage.count, 5);
+    assert_eq!(storage.avg_obj_size, 60.0);
+    assert_eq!(storage.storage_size, 8192);
+    assert_eq!(storage.index_sizes["_id_"], 30);
+    assert_eq!(storage.wired_tiger.unwrap().file_size, Some(16384));
+
+    let mut missing_size = shard("shard0", 2, 100, 10);
+    missing_size
+        .get_document_mut("storageStats")
+        .unwrap()
+        .remove("size");
+    assert!(CollectionStats::from_documents(vec![missing_size]).is_err());
+}
@@ -27,7 +27,7 @@ use tokio::net::TcpListener;
 
 use crate::{
     client::{auth::Credential, options::TlsOptions},
-    client_encryption::{ClientEncryption, EncryptKey, MasterKey},
+    client_encryption::{ClientEncryption, EncryptKey, MasterKey, RewrapManyDataKeyOptions},
     coll::options::{
         CollectionOptions,
         CreateIndexOptions,
@@ -2522,6 +2522,143 @@ async fn unique_index_keyaltnames_setup() -> Result<(ClientEncryption, Binary)>
     Ok((client_encryption, key))
 }
 
+// Prose test 16. Rewrap (local KMS provider only)
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn rewrap_many_data_key() -> Result<()> {
+    if !check_env("rewrap_many_data_key", false) {
+        return Ok(());
+    }
+    let _guard = LOCK.run_exclusively().await;
+
+    let client = TestClient::new().await;
+    client
+        .database("keyvault")
+        .collection::<Document>("datakeys")
+        .drop(None)
+        .await?;
+    let client_encryption = ClientEncryption::new(
+        client.into_client(),
+        KV_NAMESPACE.clone(),
+        LOCAL_KMS.clone(),
+    )?;
+    let key_id = client_encryption
+        .create_data_key(MasterKey::Local)
+        .run()
+        .await?;
+    let ciphertext = client_encryption
+        .encrypt(
+            "test",
+            EncryptKey::Id(key_id.clone()),
+            Algorithm::AeadAes256CbcHmacSha512Deterministic,
+        )
+        .run()
+        .await?;
+
+    // A filter matching no keys performs no writes.
+    let result = client_encryption
+        .rewrap_many_data_key(doc! { "keyAltNames": "nonexistent" }, None)
+        .await?;
+    assert!(result.bulk_write_result.is_none());
+
+    let result = client_encryption
+        .rewrap_many_data_key(
+            doc! {},
+            RewrapManyDataKeyOptions::builder()
+                .provider(KmsProvider::Local)
+                .build(),
+        )
+        .await?;
+    let bulk_write_result = result.bulk_write_result.unwrap();
+    assert_eq!(bulk_write_result.modified_count, 1);
+
+    let key = client_encryption.get_key(&key_id).await?.unwrap();
+    assert!(key.get_datetime("updateDate").is_ok());
+    let plaintext = client_encryption
+        .decrypt(ciphertext.as_raw_binary())
+        .await?;
+    assert_eq!(Some("test"), plaintext.as_str());
+
+    Ok(())
+}
+
+// Prose test 21. Automatic Data Encryption Keys (local KMS provider only)
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn create_encrypted_collection() -> Result<()> {
+    if !check_env("create_encrypted_collection", false) {
+        return Ok(());
+    }
+    let _guard = LOCK.run_exclusively().await;
+
+    let client = TestClient::new().await;
+    if client.server_version_lt(6, 0) {
+        log_uncaptured("skipping create_encrypted_collection: server below 6.0");
+        return Ok(());
+    }
+    if client.is_standalone() {
+        log_uncaptured("skipping create_encrypted_collection: cannot run on standalone");
+        return Ok(());
+    }
+    let db = client.database("create_encrypted_collection");
+    db.drop(None).await?;
+    let client_encryption = ClientEncryption::new(
+        client.clone().into_client(),
+        KV_NAMESPACE.clone(),
+        LOCAL_KMS.clone(),
+    )?;
+
+    // Fails without encrypted fields.
+    let (_, result) = client_encryption
+        .create_encrypted_collection(
+            &db,
+            "testing1",
+            CreateCollectionOptions::default(),
+            MasterKey::Local,
+        )
+        .await;
+    assert!(result.is_err());
+
+    let (encrypted_fields, result) = client_encryption
+        .create_encrypted_collection(
+            &db,
+            "testing1",
+            CreateCollectionOptions::builder()
+                .encrypted_fields(doc! {
+                    "fields": [{
+                        "path": "ssn",
+                        "bsonType": "string",
+                        "keyId": Bson::Null,
+                    }],
+                })
+                .build(),
+            MasterKey::Local,
+        )
+        .await;
+    result?;
+    let key_id = match encrypted_fields.get_array("fields")?[0]
+        .as_document()
+        .unwrap()
+        .get("keyId")
+    {
+        Some(Bson::Binary(key_id)) => key_id.clone(),
+        other => return Err(failure!("expected binary keyId, got {:?}", other)),
+    };
+    assert!(client_encryption.get_key(&key_id).await?.is_some());
+
+    let names = db.list_collection_names(None).await?;
+    for name in [
+        "testing1",
+        "enxcol_.testing1.esc",
+        "enxcol_.testing1.ecc",
+        "enxcol_.testing1.ecoc",
+    ] {
+        assert!(names.iter().any(|n| n == name), "missing {}", name);
+    }
+
+    Ok(())
+}
+
 // Prose test 14. Decryption Events (Case 1: Command Error)
 #[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
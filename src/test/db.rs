@@ -10,6 +10,7 @@ use crate::{
         AggregateOptions,
         Collation,
         CreateCollectionOptions,
+        DatabaseStatsOptions,
         IndexOptionDefaults,
         ValidationAction,
         ValidationLevel,
@@ -370,3 +371,32 @@ async fn index_option_defaults_test(defaults: Option<IndexOptionDefaults>, name:
     };
     assert_eq!(event_defaults, defaults);
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn db_stats() {
+    let _guard: RwLockReadGuard<()> = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+    let coll = client
+        .init_db_and_coll(function_name!(), function_name!())
+        .await;
+    coll.insert_many((0..5).map(|i| doc! { "x": i }), None)
+        .await
+        .unwrap();
+
+    let db = client.database(function_name!());
+    let stats = db.stats(None).await.unwrap();
+    assert_eq!(stats.db, function_name!());
+    assert!(stats.collections >= 1);
+    assert_eq!(stats.objects, 5);
+    assert!(stats.data_size > 0.0);
+    assert!(stats.avg_obj_size > 0.0);
+    assert!(stats.indexes >= 1);
+
+    let options = DatabaseStatsOptions::builder().scale(1024u32).build();
+    let scaled = db.stats(options).await.unwrap();
+    assert_eq!(scaled.data_size, stats.data_size / 1024.0);
+    assert_eq!(scaled.objects, stats.objects);
+}
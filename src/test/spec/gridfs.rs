@@ -1,6 +1,6 @@
-use std::time::Duration;
+use std::{io::SeekFrom, time::Duration};
 
-use futures_util::io::{AsyncReadExt, AsyncWriteExt};
+use futures_util::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
 
 use crate::{
     bson::{doc, Bson, Document},
@@ -84,6 +84,71 @@ async fn download_stream_across_buffers() {
     assert_eq!(buf, data);
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn download_stream_range_and_seek() {
+    let _guard = LOCK.run_concurrently().await;
+
+    let client = TestClient::new().await;
+
+    let options = GridFsBucketOptions::builder().chunk_size_bytes(4).build();
+    let bucket = client
+        .database("download_stream_range_and_seek")
+        .gridfs_bucket(options);
+    bucket.drop().await.unwrap();
+
+    let data: Vec<u8> = (0..30).collect();
+    let id: Bson = bucket
+        .upload_from_futures_0_3_reader("test", &data[..], None)
+        .await
+        .unwrap()
+        .into();
+
+    // ranges starting and ending partway through a chunk
+    for range in [5..18, 0..3, 8..12, 27..30, 13..13, 0..30] {
+        let mut buf = Vec::new();
+        let mut download_stream = bucket
+            .open_download_stream_range(id.clone(), range.clone())
+            .await
+            .unwrap();
+        download_stream.read_to_end(&mut buf).await.unwrap();
+        assert_eq!(buf, &data[range.start as usize..range.end as usize]);
+    }
+
+    let error = bucket
+        .open_download_stream_range(id.clone(), 10..31)
+        .await
+        .expect_err("range past the end of the file should fail");
+    assert!(matches!(*error.kind, ErrorKind::InvalidArgument { .. }));
+
+    let mut download_stream = bucket.open_download_stream(id.clone()).await.unwrap();
+    let mut buf = vec![0u8; 3];
+
+    // seek forward within the buffered chunk
+    download_stream.read_exact(&mut buf[..1]).await.unwrap();
+    assert_eq!(download_stream.seek(SeekFrom::Current(2)).await.unwrap(), 3);
+    download_stream.read_exact(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[3..6]);
+
+    // seek backward to a previous chunk
+    assert_eq!(download_stream.seek(SeekFrom::Start(1)).await.unwrap(), 1);
+    download_stream.read_exact(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[1..4]);
+
+    // seek relative to the end of the file
+    assert_eq!(download_stream.seek(SeekFrom::End(-5)).await.unwrap(), 25);
+    let mut rest = Vec::new();
+    download_stream.read_to_end(&mut rest).await.unwrap();
+    assert_eq!(rest, &data[25..]);
+
+    // seeking back after reaching the end of the file resumes reading
+    download_stream.seek(SeekFrom::Start(10)).await.unwrap();
+    download_stream.read_exact(&mut buf).await.unwrap();
+    assert_eq!(buf, &data[10..13]);
+
+    assert!(download_stream.seek(SeekFrom::Current(-20)).await.is_err());
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn upload_stream() {
@@ -357,6 +357,7 @@ fn error_redaction() {
             }]),
             write_concern_error: Some(wce_copy),
             inserted_ids: HashMap::default(),
+            partial_result: None,
         }),
         labels,
     );
@@ -509,10 +510,7 @@ async fn command_logging_unified() {
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 async fn connection_logging_unified() {
     let test_predicate = |tc: &TestCase|
-        // TODO: RUST-1096 Unskip when configurable maxConnecting is added.
-        tc.description != "maxConnecting should be included in connection pool created message when specified" &&
         // We don't support any of these options (and are unlikely to ever support them).
-        tc.description != "waitQueueTimeoutMS should be included in connection pool created message when specified" &&
         tc.description != "waitQueueSize should be included in connection pool created message when specified" &&
         tc.description != "waitQueueMultiple should be included in connection pool created message when specified";
 
@@ -1,9 +1,11 @@
+use futures::FutureExt;
 use serde::{Deserialize, Serialize};
 use tokio::sync::{RwLockReadGuard, RwLockWriteGuard};
 
 use crate::{
     bson::{doc, Document},
-    test::{log_uncaptured, TestClient, LOCK},
+    error::TRANSIENT_TRANSACTION_ERROR,
+    test::{log_uncaptured, FailCommandOptions, FailPoint, FailPointMode, TestClient, LOCK},
     Collection,
 };
 
@@ -80,3 +82,48 @@ async fn deserialize_recovery_token() {
     // Nevertheless, the recovery token should have been retrieved from the ok: 1 response.
     assert!(session.transaction.recovery_token.is_some());
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test(flavor = "multi_thread"))]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[function_name::named]
+async fn convenient_api_retries_transient_errors() {
+    let _guard: RwLockWriteGuard<()> = LOCK.run_exclusively().await;
+
+    let client = TestClient::new().await;
+    if !client.supports_transactions() || client.server_version_lt(4, 4) {
+        log_uncaptured("skipping convenient_api_retries_transient_errors due to test topology");
+        return;
+    }
+
+    let coll = client
+        .create_fresh_collection(function_name!(), function_name!(), None)
+        .await;
+
+    let options = FailCommandOptions::builder()
+        .error_code(112)
+        .error_labels(vec![TRANSIENT_TRANSACTION_ERROR.to_string()])
+        .build();
+    let failpoint = FailPoint::fail_command(&["insert"], FailPointMode::Times(1), Some(options));
+    let _fp_guard = client.enable_failpoint(failpoint, None).await.unwrap();
+
+    let mut session = client.start_session(None).await.unwrap();
+    let mut attempts = 0;
+    session
+        .with_transaction(
+            (&coll, &mut attempts),
+            |session, (coll, attempts)| {
+                async move {
+                    **attempts += 1;
+                    coll.insert_one_with_session(doc! { "x": 1 }, None, session)
+                        .await
+                }
+                .boxed()
+            },
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(attempts, 2);
+    assert_eq!(coll.count_documents(None, None).await.unwrap(), 1);
+}
@@ -525,6 +525,12 @@ pub(super) struct Find {
     collation: Option<Collation>,
     #[serde(rename = "let")]
     let_vars: Option<Document>,
+    #[serde(
+        default,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis"
+    )]
+    timeout: Option<Duration>,
 }
 
 impl Find {
@@ -567,6 +573,7 @@ impl Find {
             max_await_time: None,
             selection_criteria: None,
             let_vars: self.let_vars.clone(),
+            timeout: self.timeout,
         };
         match &self.session {
             Some(session_id) => {
@@ -2613,7 +2620,7 @@ impl TestOperation for RecordTopologyDescription {
     ) -> BoxFuture<'a, ()> {
         async {
             let client = test_runner.get_client(&self.client).await;
-            let description = client.topology_description();
+            let description = client.topology_description().description;
             test_runner.insert_entity(&self.id, description).await;
         }
         .boxed()
@@ -14,6 +14,7 @@ use super::{results_match, ExpectedEvent, ObserveEvent, Operation};
 use crate::trace;
 use crate::{
     bson::{doc, Bson, Deserializer as BsonDeserializer, Document},
+    bson_util,
     client::options::{ServerApi, ServerApiVersion, SessionOptions},
     concern::{Acknowledgment, ReadConcernLevel},
     error::Error,
@@ -360,6 +361,12 @@ pub(crate) struct CollectionOrDatabaseOptions {
     #[serde(rename = "readPreference")]
     pub(crate) selection_criteria: Option<SelectionCriteria>,
     pub(crate) write_concern: Option<WriteConcern>,
+    #[serde(
+        default,
+        rename = "timeoutMS",
+        deserialize_with = "bson_util::deserialize_duration_option_from_u64_millis"
+    )]
+    pub(crate) timeout: Option<Duration>,
 }
 
 impl CollectionOrDatabaseOptions {
@@ -368,6 +375,7 @@ impl CollectionOrDatabaseOptions {
             read_concern: self.read_concern.clone(),
             selection_criteria: self.selection_criteria.clone(),
             write_concern: self.write_concern.clone(),
+            timeout: self.timeout,
         }
     }
 
@@ -376,6 +384,7 @@ impl CollectionOrDatabaseOptions {
             read_concern: self.read_concern.clone(),
             selection_criteria: self.selection_criteria.clone(),
             write_concern: self.write_concern.clone(),
+            timeout: self.timeout,
         }
     }
 }
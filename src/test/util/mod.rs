@@ -2,6 +2,8 @@ mod event;
 mod failpoint;
 mod lock;
 mod matchable;
+mod socks5;
+mod srv_resolver;
 mod subscriber;
 #[cfg(feature = "tracing-unstable")]
 mod trace;
@@ -11,6 +13,8 @@ pub(crate) use self::{
     failpoint::{FailCommandOptions, FailPoint, FailPointGuard, FailPointMode},
     lock::TestLock,
     matchable::{assert_matches, eq_matches, is_expected_type, MatchErrExt, Matchable},
+    socks5::Socks5Server,
+    srv_resolver::StaticSrvResolver,
     subscriber::EventSubscriber,
 };
 
@@ -0,0 +1,169 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+#[cfg(not(feature = "tokio-runtime"))]
+use async_std::net::TcpListener;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+#[cfg(feature = "tokio-runtime")]
+use tokio::net::TcpListener;
+
+use crate::{
+    error::Result,
+    options::ServerAddress,
+    runtime::{self, stream::AsyncTcpStream, WorkerHandle, WorkerHandleListener},
+};
+
+/// A minimal in-process SOCKS5 proxy that supports the CONNECT command, optionally requiring
+/// username/password authentication. It records the target of every tunnel it opens.
+#[derive(Debug)]
+pub(crate) struct Socks5Server {
+    address: ServerAddress,
+    state: Arc<Socks5ServerState>,
+    _handle: WorkerHandle,
+}
+
+#[derive(Debug)]
+struct Socks5ServerState {
+    credentials: Option<(String, String)>,
+    targets: Mutex<Vec<String>>,
+    handle_listener: WorkerHandleListener,
+}
+
+impl Socks5Server {
+    pub(crate) async fn start(credentials: Option<(&str, &str)>) -> Result<Self> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).await?;
+        let address = ServerAddress::Tcp {
+            host: "127.0.0.1".to_string(),
+            port: Some(listener.local_addr()?.port()),
+        };
+
+        let (handle, handle_listener) = WorkerHandleListener::channel();
+        let state = Arc::new(Socks5ServerState {
+            credentials: credentials.map(|(u, p)| (u.to_string(), p.to_string())),
+            targets: Mutex::new(Vec::new()),
+            handle_listener,
+        });
+        runtime::execute(state.clone().accept_connections(listener));
+
+        Ok(Self {
+            address,
+            state,
+            _handle: handle,
+        })
+    }
+
+    pub(crate) fn address(&self) -> &ServerAddress {
+        &self.address
+    }
+
+    /// The "host:port" targets of all of the tunnels opened so far.
+    pub(crate) fn targets(&self) -> Vec<String> {
+        self.state.targets.lock().unwrap().clone()
+    }
+}
+
+impl Socks5ServerState {
+    async fn accept_connections(self: Arc<Self>, listener: TcpListener) {
+        loop {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = self.handle_listener.wait_for_all_handle_drops() => return,
+            };
+            match accepted {
+                Ok((stream, _)) => {
+                    let state = self.clone();
+                    runtime::execute(async move {
+                        tokio::select! {
+                            _ = state.serve(stream.into()) => {},
+                            _ = state.handle_listener.wait_for_all_handle_drops() => {},
+                        }
+                    });
+                }
+                Err(_) => return,
+            }
+        }
+    }
+
+    async fn serve(&self, mut client: AsyncTcpStream) -> std::io::Result<()> {
+        let mut header = [0u8; 2];
+        client.read_exact(&mut header).await?;
+        let mut methods = vec![0u8; header[1] as usize];
+        client.read_exact(&mut methods).await?;
+
+        let required_method = if self.credentials.is_some() {
+            0x02
+        } else {
+            0x00
+        };
+        if !methods.contains(&required_method) {
+            client.write_all(&[0x05, 0xff]).await?;
+            return Ok(());
+        }
+        client.write_all(&[0x05, required_method]).await?;
+
+        if let Some((ref username, ref password)) = self.credentials {
+            let mut version_and_len = [0u8; 2];
+            client.read_exact(&mut version_and_len).await?;
+            let mut given_username = vec![0u8; version_and_len[1] as usize];
+            client.read_exact(&mut given_username).await?;
+            let password_len = client.read_u8().await?;
+            let mut given_password = vec![0u8; password_len as usize];
+            client.read_exact(&mut given_password).await?;
+
+            if given_username != username.as_bytes() || given_password != password.as_bytes() {
+                client.write_all(&[0x01, 0x01]).await?;
+                return Ok(());
+            }
+            client.write_all(&[0x01, 0x00]).await?;
+        }
+
+        let mut request = [0u8; 4];
+        client.read_exact(&mut request).await?;
+        let host = match request[3] {
+            0x01 => {
+                let mut octets = [0u8; 4];
+                client.read_exact(&mut octets).await?;
+                std::net::Ipv4Addr::from(octets).to_string()
+            }
+            0x03 => {
+                let len = client.read_u8().await?;
+                let mut host = vec![0u8; len as usize];
+                client.read_exact(&mut host).await?;
+                String::from_utf8_lossy(&host).into_owned()
+            }
+            _ => {
+                client
+                    .write_all(&[0x05, 0x08, 0x00, 0x01, 0, 0, 0, 0, 0, 0])
+                    .await?;
+                return Ok(());
+            }
+        };
+        let port = client.read_u16().await?;
+        self.targets
+            .lock()
+            .unwrap()
+            .push(format!("{}:{}", host, port));
+
+        let target = ServerAddress::Tcp {
+            host,
+            port: Some(port),
+        };
+        let mut upstream = match AsyncTcpStream::connect(&target).await {
+            Ok(upstream) => upstream,
+            Err(_) => {
+                client
+                    .write_all(&[0x05, 0x05, 0x00, 0x01, 0, 0, 0, 0, 0, 0])
+                    .await?;
+                return Ok(());
+            }
+        };
+        client
+            .write_all(&[0x05, 0x00, 0x00, 0x01, 0, 0, 0, 0, 0, 0])
+            .await?;
+
+        tokio::io::copy_bidirectional(&mut client, &mut upstream).await?;
+        Ok(())
+    }
+}
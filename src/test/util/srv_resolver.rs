@@ -0,0 +1,49 @@
+use std::sync::Mutex;
+
+use futures_util::future::{BoxFuture, FutureExt};
+
+use crate::{
+    error::Result,
+    options::{SrvRecord, SrvResolver},
+};
+
+/// An in-memory [`SrvResolver`] that returns a fixed set of records for every name. The records
+/// can be replaced at any time to simulate DNS changes.
+#[derive(Debug, Default)]
+pub(crate) struct StaticSrvResolver {
+    srv: Mutex<Vec<SrvRecord>>,
+    txt: Mutex<Vec<String>>,
+    srv_lookups: Mutex<Vec<String>>,
+}
+
+impl StaticSrvResolver {
+    pub(crate) fn new(srv: Vec<SrvRecord>, txt: Vec<String>) -> Self {
+        Self {
+            srv: Mutex::new(srv),
+            txt: Mutex::new(txt),
+            srv_lookups: Default::default(),
+        }
+    }
+
+    pub(crate) fn set_srv_records(&self, srv: Vec<SrvRecord>) {
+        *self.srv.lock().unwrap() = srv;
+    }
+
+    /// The names passed to each SRV lookup performed so far.
+    pub(crate) fn srv_lookups(&self) -> Vec<String> {
+        self.srv_lookups.lock().unwrap().clone()
+    }
+}
+
+impl SrvResolver for StaticSrvResolver {
+    fn lookup_srv<'a>(&'a self, name: &'a str) -> BoxFuture<'a, Result<Vec<SrvRecord>>> {
+        self.srv_lookups.lock().unwrap().push(name.to_string());
+        let records = self.srv.lock().unwrap().clone();
+        async move { Ok(records) }.boxed()
+    }
+
+    fn lookup_txt<'a>(&'a self, _name: &'a str) -> BoxFuture<'a, Result<Vec<String>>> {
+        let records = self.txt.lock().unwrap().clone();
+        async move { Ok(records) }.boxed()
+    }
+}
@@ -0,0 +1,454 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+        Mutex,
+    },
+};
+
+#[cfg(not(feature = "tokio-runtime"))]
+use async_std::net::TcpListener;
+#[cfg(feature = "tokio-runtime")]
+use tokio::net::TcpListener;
+
+use crate::{
+    bson::{doc, Bson, DateTime, Document},
+    cmap::conn::wire::{Message, MessageFlags, MessageSection},
+    error::{ErrorKind, Result},
+    hello::{LEGACY_HELLO_COMMAND_NAME, LEGACY_HELLO_COMMAND_NAME_LOWERCASE},
+    options::ServerAddress,
+    runtime::{self, stream::AsyncTcpStream, WorkerHandle, WorkerHandleListener},
+    Namespace,
+};
+
+/// The maximum wire version reported by a [`MockServer`], which corresponds to MongoDB 6.0.
+const MAX_WIRE_VERSION: i32 = 17;
+
+/// The key under which replies to both `hello` and legacy hello are scripted.
+const HELLO_KEY: &str = "hello";
+
+/// An in-process server that speaks the MongoDB wire protocol.
+///
+/// The server listens on an ephemeral port on the loopback interface and answers `hello` and
+/// legacy hello according to its configured [`MockTopology`]. Every other command is answered
+/// with the next reply scripted for it via [`MockServer::add_reply`], falling back to the reply
+/// set via [`MockServer::set_default_reply`] and then to a minimal successful reply.
+///
+/// The server stops accepting connections and closes all open connections when it is dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    address: ServerAddress,
+    state: Arc<Mutex<MockServerState>>,
+    _handle: WorkerHandle,
+}
+
+/// The topology that a [`MockServer`] reports in its replies to `hello`.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum MockTopology {
+    /// A standalone mongod.
+    Standalone,
+
+    /// The primary of a single-node replica set with the given name.
+    ReplicaSetPrimary {
+        /// The name of the replica set.
+        set_name: String,
+    },
+
+    /// A secondary of a replica set with the given name. Since the server is the only member
+    /// of the set, switching a primary to this topology simulates a stepdown.
+    ReplicaSetSecondary {
+        /// The name of the replica set.
+        set_name: String,
+    },
+
+    /// A mongos.
+    Mongos,
+}
+
+/// A scripted reply to a command sent to a [`MockServer`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum MockReply {
+    /// Reply with the given document.
+    Document(Document),
+
+    /// Close the connection instead of replying, which the driver observes as a network error.
+    CloseConnection,
+}
+
+impl MockReply {
+    /// A successful reply with no other fields.
+    pub fn ok() -> Self {
+        Self::Document(doc! { "ok": 1 })
+    }
+
+    /// A reply indicating that the command failed with the given error code and message.
+    pub fn command_error(code: i32, message: impl Into<String>) -> Self {
+        Self::Document(doc! {
+            "ok": 0,
+            "code": code,
+            "errmsg": message.into(),
+        })
+    }
+
+    /// A reply containing an exhausted cursor over the given namespace whose first batch
+    /// consists of `documents`.
+    pub fn cursor(namespace: &Namespace, documents: impl IntoIterator<Item = Document>) -> Self {
+        Self::Document(cursor_reply(
+            namespace,
+            "firstBatch",
+            documents.into_iter().map(Bson::Document).collect(),
+        ))
+    }
+
+    /// Adds the given error labels to this reply. This has no effect on
+    /// [`MockReply::CloseConnection`].
+    pub fn with_error_labels(
+        mut self,
+        labels: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        if let Self::Document(ref mut document) = self {
+            let labels: Vec<String> = labels.into_iter().map(Into::into).collect();
+            document.insert("errorLabels", labels);
+        }
+        self
+    }
+}
+
+impl From<Document> for MockReply {
+    fn from(document: Document) -> Self {
+        Self::Document(document)
+    }
+}
+
+#[derive(Debug)]
+struct MockServerState {
+    topology: MockTopology,
+    replies: HashMap<String, VecDeque<MockReply>>,
+    default_replies: HashMap<String, MockReply>,
+    received: Vec<Document>,
+}
+
+impl MockServer {
+    /// Starts a new server that reports itself as part of the given topology.
+    pub async fn start(topology: MockTopology) -> Result<Self> {
+        let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).await?;
+        let address = ServerAddress::Tcp {
+            host: "127.0.0.1".to_string(),
+            port: Some(listener.local_addr()?.port()),
+        };
+        let state = Arc::new(Mutex::new(MockServerState {
+            topology,
+            replies: HashMap::new(),
+            default_replies: HashMap::new(),
+            received: Vec::new(),
+        }));
+
+        let (handle, handle_listener) = WorkerHandleListener::channel();
+        let worker = Arc::new(MockServerWorker {
+            address: address.clone(),
+            state: state.clone(),
+            handle_listener,
+            next_connection_id: AtomicI64::new(1),
+        });
+        runtime::execute(worker.accept_connections(listener));
+
+        Ok(Self {
+            address,
+            state,
+            _handle: handle,
+        })
+    }
+
+    /// The address the server is listening on.
+    pub fn address(&self) -> &ServerAddress {
+        &self.address
+    }
+
+    /// A connection string that can be used to connect a [`Client`](crate::Client) to this
+    /// server.
+    pub fn uri(&self) -> String {
+        let mut uri = format!("mongodb://{}/", self.address);
+        match self.state.lock().unwrap().topology {
+            MockTopology::ReplicaSetPrimary { ref set_name }
+            | MockTopology::ReplicaSetSecondary { ref set_name } => {
+                uri.push_str("?replicaSet=");
+                uri.push_str(set_name);
+            }
+            MockTopology::Standalone | MockTopology::Mongos => {}
+        }
+        uri
+    }
+
+    /// Changes the topology reported by the server. The driver will observe the change the next
+    /// time it checks the server, e.g. after a heartbeat or a "not writable primary" error.
+    pub fn set_topology(&self, topology: MockTopology) {
+        self.state.lock().unwrap().topology = topology;
+    }
+
+    /// Queues a reply to the next command with the given name. Replies queued for the same
+    /// command are used in the order they were added.
+    ///
+    /// Replies queued for `"hello"` are also used for legacy hello and take precedence over the
+    /// reply generated from the server's topology.
+    pub fn add_reply(&self, command_name: impl AsRef<str>, reply: impl Into<MockReply>) {
+        self.state
+            .lock()
+            .unwrap()
+            .replies
+            .entry(reply_key(command_name.as_ref()).to_string())
+            .or_default()
+            .push_back(reply.into());
+    }
+
+    /// Sets the reply used for commands with the given name once all replies queued for it via
+    /// [`MockServer::add_reply`] have been used.
+    pub fn set_default_reply(&self, command_name: impl AsRef<str>, reply: impl Into<MockReply>) {
+        self.state
+            .lock()
+            .unwrap()
+            .default_replies
+            .insert(reply_key(command_name.as_ref()).to_string(), reply.into());
+    }
+
+    /// The commands received by the server so far in the order they were received, excluding
+    /// `hello` and legacy hello.
+    pub fn received_commands(&self) -> Vec<Document> {
+        self.state.lock().unwrap().received.clone()
+    }
+}
+
+/// The background task that accepts and serves connections for a [`MockServer`].
+#[derive(Debug)]
+struct MockServerWorker {
+    address: ServerAddress,
+    state: Arc<Mutex<MockServerState>>,
+    handle_listener: WorkerHandleListener,
+    next_connection_id: AtomicI64,
+}
+
+impl MockServerWorker {
+    async fn accept_connections(self: Arc<Self>, listener: TcpListener) {
+        loop {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = self.handle_listener.wait_for_all_handle_drops() => return,
+            };
+            match accepted {
+                Ok((stream, _)) => {
+                    let worker = self.clone();
+                    runtime::execute(async move { worker.serve(stream.into()).await });
+                }
+                Err(_) => return,
+            }
+        }
+    }
+
+    async fn serve(&self, mut stream: AsyncTcpStream) {
+        let connection_id = self.next_connection_id.fetch_add(1, Ordering::SeqCst);
+        loop {
+            let message = tokio::select! {
+                message = Message::read_from(&mut stream, None) => message,
+                _ = self.handle_listener.wait_for_all_handle_drops() => return,
+            };
+            let message = match message {
+                Ok(message) => message,
+                Err(_) => return,
+            };
+            let request_id = message.request_id.unwrap_or_default();
+            let more_to_come = message.flags.contains(MessageFlags::MORE_TO_COME);
+            let command = match command_document(message) {
+                Ok(command) => command,
+                Err(_) => return,
+            };
+
+            let reply = match self.reply_to(&command, connection_id) {
+                MockReply::Document(reply) => reply,
+                MockReply::CloseConnection => return,
+            };
+            // Unacknowledged writes don't expect a reply.
+            if more_to_come {
+                continue;
+            }
+            let bytes = match bson::to_vec(&reply) {
+                Ok(bytes) => bytes,
+                Err(_) => return,
+            };
+            let response = Message {
+                response_to: request_id,
+                flags: MessageFlags::empty(),
+                sections: vec![MessageSection::Document(bytes)],
+                checksum: None,
+                request_id: None,
+            };
+            if response.write_to(&mut stream).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    fn reply_to(&self, command: &Document, connection_id: i64) -> MockReply {
+        let name = command
+            .keys()
+            .next()
+            .map(String::as_str)
+            .unwrap_or_default();
+        let key = reply_key(name);
+
+        let mut state = self.state.lock().unwrap();
+        if key != HELLO_KEY {
+            state.received.push(command.clone());
+        }
+        if let Some(reply) = state.replies.get_mut(key).and_then(VecDeque::pop_front) {
+            return reply;
+        }
+        if let Some(reply) = state.default_replies.get(key) {
+            return reply.clone();
+        }
+
+        if key == HELLO_KEY {
+            MockReply::Document(hello_reply(
+                &state.topology,
+                name,
+                &self.address,
+                connection_id,
+            ))
+        } else {
+            MockReply::Document(default_reply(name, command))
+        }
+    }
+}
+
+/// Assembles the command document sent in `message`, folding any document sequences into the
+/// body under their identifiers.
+fn command_document(message: Message) -> Result<Document> {
+    let mut command: Option<Document> = None;
+    let mut sequences = Vec::new();
+    for section in message.sections {
+        match section {
+            MessageSection::Document(bytes) => {
+                command = Some(Document::from_reader(bytes.as_slice())?);
+            }
+            MessageSection::Sequence {
+                identifier,
+                documents,
+                ..
+            } => {
+                let documents = documents
+                    .iter()
+                    .map(|bytes| Document::from_reader(bytes.as_slice()).map(Bson::Document))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                sequences.push((identifier, documents));
+            }
+        }
+    }
+
+    let mut command = command.ok_or_else(|| ErrorKind::InvalidResponse {
+        message: "OP_MSG did not contain a command document".to_string(),
+    })?;
+    for (identifier, documents) in sequences {
+        command.insert(identifier, documents);
+    }
+    Ok(command)
+}
+
+fn reply_key(command_name: &str) -> &str {
+    if command_name == LEGACY_HELLO_COMMAND_NAME
+        || command_name == LEGACY_HELLO_COMMAND_NAME_LOWERCASE
+    {
+        HELLO_KEY
+    } else {
+        command_name
+    }
+}
+
+fn hello_reply(
+    topology: &MockTopology,
+    command_name: &str,
+    address: &ServerAddress,
+    connection_id: i64,
+) -> Document {
+    let writable = !matches!(topology, MockTopology::ReplicaSetSecondary { .. });
+    let writable_key = if command_name == HELLO_KEY {
+        "isWritablePrimary"
+    } else {
+        LEGACY_HELLO_COMMAND_NAME_LOWERCASE
+    };
+
+    let mut reply = doc! {
+        "helloOk": true,
+        writable_key: writable,
+        "maxBsonObjectSize": 16 * 1024 * 1024,
+        "maxMessageSizeBytes": 48_000_000,
+        "maxWriteBatchSize": 100_000,
+        "localTime": DateTime::now(),
+        "logicalSessionTimeoutMinutes": 30,
+        "connectionId": connection_id,
+        "minWireVersion": 0,
+        "maxWireVersion": MAX_WIRE_VERSION,
+        "readOnly": false,
+    };
+    match topology {
+        MockTopology::Standalone => {}
+        MockTopology::ReplicaSetPrimary { set_name }
+        | MockTopology::ReplicaSetSecondary { set_name } => {
+            reply.insert("setName", set_name.as_str());
+            reply.insert("hosts", vec![address.to_string()]);
+            reply.insert("me", address.to_string());
+            reply.insert("secondary", !writable);
+            if writable {
+                reply.insert("primary", address.to_string());
+            }
+        }
+        MockTopology::Mongos => {
+            reply.insert("msg", "isdbgrid");
+        }
+    }
+    reply.insert("ok", 1);
+    reply
+}
+
+/// The reply used for a command that has no scripted reply. This is a minimal successful reply
+/// that the driver can parse, e.g. an empty cursor for commands that return one.
+fn default_reply(command_name: &str, command: &Document) -> Document {
+    let db = command.get_str("$db").unwrap_or("admin");
+    let namespace = |coll: &str| Namespace::new(db, coll);
+
+    match command_name {
+        "insert" => {
+            let n = command
+                .get_array("documents")
+                .map(|documents| documents.len() as i64)
+                .unwrap_or(0);
+            doc! { "ok": 1, "n": n }
+        }
+        "update" => doc! { "ok": 1, "n": 0, "nModified": 0 },
+        "delete" => doc! { "ok": 1, "n": 0 },
+        "find" | "aggregate" | "listCollections" | "listIndexes" => {
+            let ns = match command.get_str(command_name) {
+                Ok(coll) => namespace(coll),
+                Err(_) => namespace(&format!("$cmd.{}", command_name)),
+            };
+            cursor_reply(&ns, "firstBatch", Vec::new())
+        }
+        "getMore" => {
+            let coll = command.get_str("collection").unwrap_or_default();
+            cursor_reply(&namespace(coll), "nextBatch", Vec::new())
+        }
+        _ => doc! { "ok": 1 },
+    }
+}
+
+fn cursor_reply(namespace: &Namespace, batch_key: &str, batch: Vec<Bson>) -> Document {
+    doc! {
+        "ok": 1,
+        "cursor": {
+            "id": 0_i64,
+            "ns": namespace.to_string(),
+            batch_key: batch,
+        },
+    }
+}
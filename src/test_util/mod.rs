@@ -0,0 +1,40 @@
+//! Contains utilities for testing applications that use the driver without a running
+//! deployment.
+//!
+//! The centerpiece of this module is [`MockServer`], an in-process server that speaks the MongoDB
+//! wire protocol. A [`Client`](crate::Client) can be pointed at it like any other deployment,
+//! and tests can then script the replies to individual commands, simulate network errors and
+//! primary stepdowns, and inspect the commands that the driver sent.
+//!
+//! ```rust
+//! # use mongodb::{bson::doc, error::Result, Client, Namespace};
+//! # use mongodb::test_util::{MockReply, MockServer, MockTopology};
+//! # #[cfg(all(not(feature = "sync"), not(feature = "tokio-sync")))]
+//! # async fn run() -> Result<()> {
+//! let server = MockServer::start(MockTopology::Standalone).await?;
+//! server.add_reply(
+//!     "find",
+//!     MockReply::cursor(
+//!         &Namespace::new("db", "coll"),
+//!         vec![doc! { "_id": 1, "x": "mocked" }],
+//!     ),
+//! );
+//!
+//! let client = Client::with_uri_str(server.uri()).await?;
+//! let found = client
+//!     .database("db")
+//!     .collection::<mongodb::bson::Document>("coll")
+//!     .find_one(None, None)
+//!     .await?;
+//! assert_eq!(found, Some(doc! { "_id": 1, "x": "mocked" }));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! This module is only available when the `test-util` feature flag is enabled.
+
+mod mock_server;
+#[cfg(test)]
+mod test;
+
+pub use self::mock_server::{MockReply, MockServer, MockTopology};
@@ -0,0 +1,114 @@
+use pretty_assertions::assert_eq;
+
+use super::{MockReply, MockServer, MockTopology};
+use crate::{
+    bson::{doc, Bson, Document},
+    error::{ErrorKind, Result},
+    Client,
+    Namespace,
+};
+
+fn replica_set_primary() -> MockTopology {
+    MockTopology::ReplicaSetPrimary {
+        set_name: "mock".to_string(),
+    }
+}
+
+fn received_named(server: &MockServer, name: &str) -> Vec<Document> {
+    server
+        .received_commands()
+        .into_iter()
+        .filter(|command| command.contains_key(name))
+        .collect()
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn scripted_replies() -> Result<()> {
+    let server = MockServer::start(MockTopology::Standalone).await?;
+    let documents = vec![doc! { "_id": 1 }, doc! { "_id": 2 }];
+    server.add_reply(
+        "find",
+        MockReply::cursor(&Namespace::new("db", "coll"), documents.clone()),
+    );
+
+    let client = Client::with_uri_str(server.uri()).await?;
+    let coll = client.database("db").collection::<Document>("coll");
+
+    let mut cursor = coll.find(doc! { "x": 1 }, None).await?;
+    let mut found = Vec::new();
+    while cursor.advance().await? {
+        found.push(cursor.deserialize_current()?);
+    }
+    assert_eq!(found, documents);
+
+    // Unscripted commands receive a default successful reply.
+    assert_eq!(
+        coll.insert_one(doc! { "_id": 3 }, None).await?.inserted_id,
+        Bson::Int32(3)
+    );
+    assert_eq!(coll.find_one(None, None).await?, None);
+
+    let finds = received_named(&server, "find");
+    assert_eq!(finds.len(), 2);
+    assert_eq!(finds[0].get_document("filter").unwrap(), &doc! { "x": 1 });
+    assert_eq!(received_named(&server, "insert").len(), 1);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn retry_after_connection_drop() -> Result<()> {
+    let server = MockServer::start(replica_set_primary()).await?;
+    server.add_reply("insert", MockReply::CloseConnection);
+
+    let client = Client::with_uri_str(server.uri()).await?;
+    client
+        .database("db")
+        .collection::<Document>("coll")
+        .insert_one(doc! { "x": 1 }, None)
+        .await?;
+
+    let inserts = received_named(&server, "insert");
+    assert_eq!(inserts.len(), 2);
+    assert_eq!(inserts[0].get("txnNumber"), inserts[1].get("txnNumber"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn primary_stepdown() -> Result<()> {
+    let server = MockServer::start(replica_set_primary()).await?;
+    let client = Client::with_uri_str(format!(
+        "{}&retryWrites=false&serverSelectionTimeoutMS=2000",
+        server.uri()
+    ))
+    .await?;
+    let coll = client.database("db").collection::<Document>("coll");
+    coll.insert_one(doc! { "x": 1 }, None).await?;
+
+    server.set_topology(MockTopology::ReplicaSetSecondary {
+        set_name: "mock".to_string(),
+    });
+    server.add_reply(
+        "insert",
+        MockReply::command_error(10107, "not writable primary"),
+    );
+
+    let error = coll.insert_one(doc! { "x": 2 }, None).await.unwrap_err();
+    assert!(
+        matches!(*error.kind, ErrorKind::Command(ref e) if e.code == 10107),
+        "{:?}",
+        error
+    );
+
+    // The error causes the driver to check the server again and notice that it is no longer
+    // the primary.
+    let error = coll.insert_one(doc! { "x": 3 }, None).await.unwrap_err();
+    assert!(error.is_server_selection_error(), "{:?}", error);
+    assert_eq!(received_named(&server, "insert").len(), 2);
+
+    Ok(())
+}
@@ -2,11 +2,13 @@ use crate::client::options::{ServerAddress, DEFAULT_PORT};
 
 pub(crate) mod command;
 pub(crate) mod connection;
+pub(crate) mod operation;
 pub(crate) mod server_selection;
 
 pub(crate) const COMMAND_TRACING_EVENT_TARGET: &str = "mongodb::command";
 pub(crate) const CONNECTION_TRACING_EVENT_TARGET: &str = "mongodb::connection";
 pub(crate) const SERVER_SELECTION_TRACING_EVENT_TARGET: &str = "mongodb::server_selection";
+pub(crate) const OPERATION_TRACING_SPAN_TARGET: &str = "mongodb::operation";
 
 pub(crate) trait TracingRepresentation {
     type Representation;
@@ -35,8 +37,9 @@ impl ServerAddress {
     pub(crate) fn port_tracing_representation(&self) -> Option<u16> {
         match self {
             Self::Tcp { port, .. } => Some(port.unwrap_or(DEFAULT_PORT)),
-            // TODO: RUST-802 For Unix domain sockets we should return None here, as ports
-            // are not meaningful for those.
+            // Ports are not meaningful for Unix domain sockets.
+            #[cfg(unix)]
+            Self::Unix { .. } => None,
         }
     }
 }
@@ -43,7 +43,7 @@ impl CommandEventHandler for CommandTracingEventEmitter {
             requestId = event.request_id,
             driverConnectionId = event.connection.id,
             serverConnectionId = event.connection.server_id,
-            serverHost = event.connection.address.host(),
+            serverHost = event.connection.address.host().as_ref(),
             serverPort = event.connection.address.port_tracing_representation(),
             serviceId = event.service_id.map(|id| id.tracing_representation()),
             "Command started"
@@ -59,7 +59,7 @@ impl CommandEventHandler for CommandTracingEventEmitter {
             requestId = event.request_id,
             driverConnectionId = event.connection.id,
             serverConnectionId = event.connection.server_id,
-            serverHost = event.connection.address.host(),
+            serverHost = event.connection.address.host().as_ref(),
             serverPort = event.connection.address.port_tracing_representation(),
             serviceId = event.service_id.map(|id| id.tracing_representation()),
             durationMS = event.duration.as_millis(),
@@ -76,7 +76,7 @@ impl CommandEventHandler for CommandTracingEventEmitter {
             requestId = event.request_id,
             driverConnectionId = event.connection.id,
             serverConnectionId = event.connection.server_id,
-            serverHost = event.connection.address.host(),
+            serverHost = event.connection.address.host().as_ref(),
             serverPort = event.connection.address.port_tracing_representation(),
             serviceId = event.service_id.map(|id| id.tracing_representation()),
             durationMS = event.duration.as_millis(),
@@ -37,11 +37,14 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             maxIdleTimeMS = options_ref.and_then(|o| o.max_idle_time.map(|m| m.as_millis())),
             maxPoolSize = options_ref.and_then(|o| o.max_pool_size),
             minPoolSize = options_ref.and_then(|o| o.min_pool_size),
+            maxConnecting = options_ref.and_then(|o| o.max_connecting),
+            waitQueueTimeoutMS =
+                options_ref.and_then(|o| o.wait_queue_timeout.map(|w| w.as_millis())),
             "Connection pool created",
         );
     }
@@ -50,7 +53,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             "Connection pool ready",
         );
@@ -60,7 +63,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             serviceId = event.service_id.map(|id| id.tracing_representation()),
             "Connection pool cleared",
@@ -71,7 +74,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             "Connection pool closed",
         );
@@ -81,7 +84,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             driverConnectionId = event.connection_id,
             "Connection created",
@@ -92,7 +95,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             driverConnectionId = event.connection_id,
             "Connection ready",
@@ -103,7 +106,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             driverConnectionId = event.connection_id,
             reason = event.reason.tracing_representation(),
@@ -116,7 +119,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             "Connection checkout started",
         );
@@ -126,7 +129,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             reason = event.reason.tracing_representation(),
             error = event.error.map(|e| e.tracing_representation()),
@@ -138,7 +141,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             driverConnectionId = event.connection_id,
             "Connection checked out",
@@ -149,7 +152,7 @@ impl CmapEventHandler for ConnectionTracingEventEmitter {
         tracing::debug!(
             target: CONNECTION_TRACING_EVENT_TARGET,
             topologyId = self.topology_id.tracing_representation(),
-            serverHost = event.address.host(),
+            serverHost = event.address.host().as_ref(),
             serverPort = event.address.port_tracing_representation(),
             driverConnectionId = event.connection_id,
             "Connection checked in",
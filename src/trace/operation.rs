@@ -0,0 +1,42 @@
+use bson::{RawBsonRef, RawDocument};
+use tracing::{field::Empty, Span};
+
+use super::OPERATION_TRACING_SPAN_TARGET;
+use crate::client::options::ServerAddress;
+
+/// Creates the span that an operation is executed within. The fields that are not known until a
+/// server has been selected and the command has been built are recorded later on via
+/// [`record_server`] and [`record_command_target`].
+pub(crate) fn operation_span(operation_name: &str) -> Span {
+    tracing::info_span!(
+        target: OPERATION_TRACING_SPAN_TARGET,
+        "mongodb.operation",
+        db.system = "mongodb",
+        db.operation = operation_name,
+        db.name = Empty,
+        db.mongodb.collection = Empty,
+        server.address = Empty,
+        server.port = Empty,
+    )
+}
+
+/// Records the address of the server an operation is being executed against on the current
+/// operation span. If the operation is retried on a different server, the latest server is kept.
+pub(crate) fn record_server(address: &ServerAddress) {
+    let span = Span::current();
+    span.record("server.address", address.host().as_ref());
+    if let Some(port) = address.port_tracing_representation() {
+        span.record("server.port", port);
+    }
+}
+
+/// Records the database and, for commands that target one, the collection of a command on the
+/// current operation span.
+pub(crate) fn record_command_target(target_db: &str, command: &RawDocument) {
+    let span = Span::current();
+    span.record("db.name", target_db);
+    // Commands that operate on a collection specify its name as the value of the command itself.
+    if let Some(Ok((_, RawBsonRef::String(collection)))) = command.into_iter().next() {
+        span.record("db.mongodb.collection", collection);
+    }
+}